@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 fn fuel_required(module: u32) -> u32 {
     module / 3 - 2
@@ -27,6 +30,33 @@ fn part_2(contents: &str) -> u32 {
         .sum()
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 1;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -50,14 +80,3 @@ mod tests {
         assert_eq!(fuel_required_iterative(module), expected);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
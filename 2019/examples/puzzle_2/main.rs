@@ -1,6 +1,9 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_fields, parse_number, PuzzleError};
+use aoc_utils::puzzle::{run, Puzzle};
+use serde::Deserialize;
 
-fn intcode(input: &mut Vec<usize>) -> &mut Vec<usize> {
+fn intcode(input: &mut Vec<usize>) -> Result<&mut Vec<usize>, PuzzleError> {
     let mut index: usize = 0;
     loop {
         let opcode = input[index];
@@ -18,52 +21,95 @@ fn intcode(input: &mut Vec<usize>) -> &mut Vec<usize> {
         } else if opcode == 2 {
             value_1 * value_2
         } else {
-            panic!("Only 1, 2 and 99 are expected as opcodes");
+            return Err(PuzzleError::unsupported(format!(
+                "Only 1, 2 and 99 are expected as opcodes, not {opcode} at index {index}"
+            )));
         };
         let change_index = input[index + 3];
         input[change_index] = new_value;
 
         index += 4;
     }
-    input
+    Ok(input)
 }
 
-fn part_1(contents: &str) -> usize {
-    let mut input: Vec<usize> = contents
-        .split(",")
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
+fn parse_program(contents: &str) -> Result<Vec<usize>, PuzzleError> {
+    parse_fields(contents.trim_end(), ',', parse_number).map_err(|e| e.on_line(0))
+}
+
+fn part_1(contents: &str) -> Result<usize, PuzzleError> {
+    let mut input = parse_program(contents)?;
 
     // apply changes from problem description
     input[1] = 12;
     input[2] = 2;
-    let output = intcode(&mut input);
-    output[0]
+    let output = intcode(&mut input)?;
+    Ok(output[0])
 }
 
-fn part_2(contents: &str, target: usize) -> usize {
-    let raw_input: Vec<usize> = contents
-        .split(",")
-        .map(|x| x.parse::<usize>().unwrap())
-        .collect();
+fn part_2(contents: &str, target: usize) -> Result<usize, PuzzleError> {
+    let raw_input = parse_program(contents)?;
 
     for noun in 0..99 {
         for verb in 0..99 {
             let mut input = raw_input.clone();
-            input[1] = noun as usize;
-            input[2] = verb as usize;
-            let output = intcode(&mut input);
+            input[1] = noun;
+            input[2] = verb;
+            let output = intcode(&mut input)?;
             if output[0] == target {
-                return 100 * noun + verb;
+                return Ok(100 * noun + verb);
             }
         }
     }
-    panic!("Part 2 should have a solution with noun and verb between 0 and 99")
+    Err(PuzzleError::unsupported(
+        "Part 2 should have a solution with noun and verb between 0 and 99",
+    ))
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Output which the noun and verb should produce.
+    target: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { target: 19690720 }
+    }
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 2;
+    const PARSES_INPUT: bool = false;
+
+    type Params = Params;
+    type Parsed = (String, Params);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        Ok((input.to_owned(), *params))
+    }
+
+    fn part_1(&self, (input, _): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, (input, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input, params.target)?.into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::input::load_example;
     use rstest::*;
 
     #[rstest]
@@ -84,22 +130,14 @@ mod tests {
         vec![30, 1, 1, 4, 2, 5, 6, 0, 99]
     )]
     fn test_(#[case] input: Vec<usize>, #[case] expected: Vec<usize>) {
-        assert_eq!(intcode(&mut input.to_owned()), &expected);
+        assert_eq!(intcode(&mut input.to_owned()), Ok(&mut expected.to_owned()));
     }
 
     #[test]
     fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./input.txt"), 5110675), 1202);
+        let Some(input) = load_example(2019, 2, "input.txt") else {
+            return;
+        };
+        assert_eq!(part_2(&input, 5110675), Ok(1202));
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents, 19690720);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
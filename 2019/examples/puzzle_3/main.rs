@@ -1,208 +1,72 @@
-use std::time::Instant;
-
-#[derive(Debug)]
-struct VerticalLine {
-    x: i64,
-    y1: i64,
-    y2: i64,
-    steps: u64,
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, PuzzleError};
+use aoc_utils::geometry::{Crossing, Point2D, Polyline};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
+
+fn parse(contents: &str) -> Result<(Polyline, Polyline), PuzzleError> {
+    let wires = parse_lines(contents, |line| {
+        Polyline::parse_moves(Point2D::default(), line)
+    })?;
+    let count = wires.len();
+    <[Polyline; 2]>::try_from(wires)
+        .map(|[wire_1, wire_2]| (wire_1, wire_2))
+        .map_err(|_| PuzzleError::malformed(format!("Expected two wires, not {count}")))
 }
 
-#[derive(Debug)]
-struct HorizontalLine {
-    y: i64,
-    x1: i64,
-    x2: i64,
-    steps: u64,
+fn crossings((wire_1, wire_2): &(Polyline, Polyline)) -> Vec<Crossing> {
+    // the wires both start at the origin, which doesn't count
+    wire_1
+        .crossings(wire_2)
+        .into_iter()
+        .filter(|crossing| crossing.point != Point2D::default())
+        .collect()
 }
 
-#[derive(Debug)]
-struct Point {
-    x: i64,
-    y: i64,
-    steps: u64,
+fn no_crossing() -> PuzzleError {
+    PuzzleError::unsupported("The wires never cross")
 }
 
-impl Point {
-    fn manhattan_distance(&self) -> i64 {
-        self.x.abs() + self.y.abs()
-    }
-
-    fn new(x: i64, y: i64, steps: u64) -> Self {
-        Point { x, y, steps }
-    }
+fn part_1(wires: &(Polyline, Polyline)) -> Result<i64, PuzzleError> {
+    crossings(wires)
+        .iter()
+        .map(|crossing| crossing.point.manhattan_distance(&Point2D::default()))
+        .min()
+        .ok_or_else(no_crossing)
 }
 
-impl Default for Point {
-    fn default() -> Self {
-        Point::new(0, 0, 0)
-    }
+fn part_2(wires: &(Polyline, Polyline)) -> Result<u64, PuzzleError> {
+    crossings(wires)
+        .iter()
+        .map(|crossing| crossing.steps.0 + crossing.steps.1)
+        .min()
+        .ok_or_else(no_crossing)
 }
 
-impl VerticalLine {
-    fn intersection(&self, hline: &HorizontalLine) -> Option<Point> {
-        let vertical_hit = (self.y1 <= hline.y && self.y2 >= hline.y)
-            || (self.y1 >= hline.y && self.y2 <= hline.y);
-        let horizontal_hit = (hline.x1 <= self.x && hline.x2 >= self.x)
-            || (hline.x1 >= self.x && hline.x2 <= self.x);
-        if vertical_hit && horizontal_hit {
-            // lines counts steps till their end, so we need to subtract the
-            // portion of them that is not traversed, i.e. after the intersection
-            Some(Point {
-                x: self.x,
-                y: hline.y,
-                steps: self.steps - self.y2.abs_diff(hline.y) + hline.steps
-                    - hline.x2.abs_diff(self.x),
-            })
-        } else {
-            None
-        }
-    }
-}
+pub struct Solution;
 
-fn parse_lines(instructions: &str) -> (Vec<VerticalLine>, Vec<HorizontalLine>) {
-    let mut vertical_lines: Vec<VerticalLine> = vec![];
-    let mut horizontal_lines: Vec<HorizontalLine> = vec![];
-
-    let mut current_point = Point::default();
-    for command in instructions.split(",") {
-        let steps = command[1..].parse::<i64>().unwrap();
-        match command.chars().nth(0) {
-            Some('U') => {
-                let vline = VerticalLine {
-                    x: current_point.x,
-                    y1: current_point.y,
-                    y2: current_point.y + steps,
-                    steps: current_point.steps + steps as u64,
-                };
-                vertical_lines.push(vline);
-                current_point = Point {
-                    x: current_point.x,
-                    y: current_point.y + steps,
-                    steps: current_point.steps + steps as u64,
-                };
-            }
-            Some('D') => {
-                let vline = VerticalLine {
-                    x: current_point.x,
-                    y1: current_point.y,
-                    y2: current_point.y - steps,
-                    steps: current_point.steps + steps as u64,
-                };
-                vertical_lines.push(vline);
-                current_point = Point {
-                    x: current_point.x,
-                    y: current_point.y - steps,
-                    steps: current_point.steps + steps as u64,
-                };
-            }
-            Some('L') => {
-                let hline = HorizontalLine {
-                    y: current_point.y,
-                    x1: current_point.x,
-                    x2: current_point.x - steps,
-                    steps: current_point.steps + steps as u64,
-                };
-                horizontal_lines.push(hline);
-                current_point = Point {
-                    x: current_point.x - steps,
-                    y: current_point.y,
-                    steps: current_point.steps + steps as u64,
-                };
-            }
-            Some('R') => {
-                let hline = HorizontalLine {
-                    y: current_point.y,
-                    x1: current_point.x,
-                    x2: current_point.x + steps,
-                    steps: current_point.steps + steps as u64,
-                };
-                horizontal_lines.push(hline);
-                current_point = Point {
-                    x: current_point.x + steps,
-                    y: current_point.y,
-                    steps: current_point.steps + steps as u64,
-                };
-            }
-            _ => {
-                panic!("First character of command should be one of LRUD")
-            }
-        }
-    }
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 3;
 
-    (vertical_lines, horizontal_lines)
-}
+    type Params = NoParams;
+    type Parsed = (Polyline, Polyline);
 
-fn part_1(contents: &str) -> i64 {
-    let instructions: Vec<&str> = contents.split("\n").collect();
-    let wire_1 = instructions[0];
-    let wire_2 = instructions[1];
-    let (wire_1_vertical, wire_1_horizontal) = parse_lines(wire_1);
-    let (wire_2_vertical, wire_2_horizontal) = parse_lines(wire_2);
-
-    let mut minimal_distance = i64::MAX;
-    for vline in wire_1_vertical.iter() {
-        for hline in wire_2_horizontal.iter() {
-            match vline.intersection(hline) {
-                Some(p) => {
-                    let distance = p.manhattan_distance();
-                    if distance > 0 && distance < minimal_distance {
-                        minimal_distance = distance;
-                    }
-                }
-                None => {}
-            }
-        }
-    }
-    for vline in wire_2_vertical.iter() {
-        for hline in wire_1_horizontal.iter() {
-            match vline.intersection(hline) {
-                Some(p) => {
-                    let distance = p.manhattan_distance();
-                    if distance > 0 && distance < minimal_distance {
-                        minimal_distance = distance;
-                    }
-                }
-                None => {}
-            }
-        }
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<Self::Parsed, PuzzleError> {
+        parse(input)
     }
-    minimal_distance
-}
 
-fn part_2(contents: &str) -> u64 {
-    let instructions: Vec<&str> = contents.split("\n").collect();
-    let wire_1 = instructions[0];
-    let wire_2 = instructions[1];
-    let (wire_1_vertical, wire_1_horizontal) = parse_lines(wire_1);
-    let (wire_2_vertical, wire_2_horizontal) = parse_lines(wire_2);
-
-    let mut minimal_steps = u64::MAX;
-    for vline in wire_1_vertical.iter() {
-        for hline in wire_2_horizontal.iter() {
-            match vline.intersection(hline) {
-                Some(p) => {
-                    if p.steps > 0 && p.steps < minimal_steps {
-                        minimal_steps = p.steps;
-                    }
-                }
-                None => {}
-            }
-        }
+    fn part_1(&self, wires: &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(wires)?.into())
     }
-    for vline in wire_2_vertical.iter() {
-        for hline in wire_1_horizontal.iter() {
-            match vline.intersection(hline) {
-                Some(p) => {
-                    if p.steps > 0 && p.steps < minimal_steps {
-                        minimal_steps = p.steps;
-                    }
-                }
-                None => {}
-            }
-        }
+
+    fn part_2(&self, wires: &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(wires)?.into())
     }
-    minimal_steps
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
@@ -221,7 +85,7 @@ mod tests {
         135
     )]
     fn test_part_1_examples(#[case] input: &str, #[case] expected: i64) {
-        assert_eq!(part_1(input), expected);
+        assert_eq!(part_1(&parse(input).unwrap()), Ok(expected));
     }
 
     #[rstest]
@@ -235,17 +99,21 @@ mod tests {
         410
     )]
     fn test_part_2_examples(#[case] input: &str, #[case] expected: u64) {
-        assert_eq!(part_2(input), expected);
+        assert_eq!(part_2(&parse(input).unwrap()), Ok(expected));
     }
-}
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+    #[rstest]
+    #[case("R8,U5,L5,D3")]
+    #[case("R8,U5,L5,D3\nU7,R6,D4,L4\nR1")]
+    #[case("R8,U5,L5,D3\nU7,Q6")]
+    fn test_bad_input(#[case] input: &str) {
+        assert!(parse(input).is_err());
+    }
+
+    #[test]
+    fn test_no_crossing() {
+        let wires = parse("R8,U5\nL5,D3").unwrap();
+        assert_eq!(part_1(&wires), Err(no_crossing()));
+        assert_eq!(part_2(&wires), Err(no_crossing()));
+    }
 }
@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 fn check_password(password: &u64) -> bool {
     let digits: Vec<u64> = (0..=5)
@@ -47,7 +50,7 @@ fn check_password_enhanced(password: &u64) -> bool {
         index += 1;
     }
     // check if final pair of digits satisfy the condition
-    return num_repeats == 2;
+    num_repeats == 2
 }
 
 fn part_1(contents: &str) -> usize {
@@ -72,6 +75,33 @@ fn part_2(contents: &str) -> usize {
         .count()
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 4;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,14 +123,3 @@ mod tests {
         assert_eq!(check_password_enhanced(&password), expected);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
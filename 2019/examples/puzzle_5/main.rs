@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_fields, parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashMap;
-use std::time::Instant;
 
 #[derive(Debug, PartialEq, Eq)]
 enum ParameterMode {
@@ -12,7 +15,7 @@ struct IntCode {
 }
 
 impl IntCode {
-    fn parse_opcode(raw_code: i32) -> (i32, HashMap<usize, ParameterMode>) {
+    fn parse_opcode(raw_code: i32) -> Result<(i32, HashMap<usize, ParameterMode>), PuzzleError> {
         let mut parameter_modes: HashMap<usize, ParameterMode> = HashMap::new();
         let opcode = raw_code % 100;
 
@@ -24,12 +27,16 @@ impl IntCode {
             let mode = match digit {
                 0 => ParameterMode::POSITION,
                 1 => ParameterMode::IMMEDIATE,
-                _ => panic!("ParameterMode digit should only be 0 or 1"),
+                _ => {
+                    return Err(PuzzleError::unsupported(format!(
+                        "ParameterMode digit should only be 0 or 1, not {digit} in {raw_code}"
+                    )))
+                }
             };
             parameter_modes.insert(offset, mode);
             offset += 1
         }
-        (opcode, parameter_modes)
+        Ok((opcode, parameter_modes))
     }
 
     fn get_value_at_index(&self, index: usize, mode: &ParameterMode) -> i32 {
@@ -129,14 +136,14 @@ impl IntCode {
         *index += num_parameters + 1;
     }
 
-    fn process(&mut self, input: i32) -> Option<i32> {
+    fn process(&mut self, input: i32) -> Result<Option<i32>, PuzzleError> {
         let mut index: usize = 0;
         let mut last_output: Option<i32> = None;
         loop {
             let raw_code = self.positions[index];
-            let (opcode, parameter_modes) = IntCode::parse_opcode(raw_code);
+            let (opcode, parameter_modes) = IntCode::parse_opcode(raw_code)?;
             if opcode == 99 {
-                return last_output;
+                return Ok(last_output);
             } else if opcode == 1 {
                 self.opcode_1(&mut index, parameter_modes);
             } else if opcode == 2 {
@@ -145,8 +152,10 @@ impl IntCode {
                 self.opcode_3(&mut index, input);
             } else if opcode == 4 {
                 let next_output = self.opcode_4(&mut index, parameter_modes);
-                if last_output.is_some() && last_output.unwrap() > 0 {
-                    panic!("Diagnostic test failed")
+                if let Some(output) = last_output.filter(|output| *output > 0) {
+                    return Err(PuzzleError::unsupported(format!(
+                        "Diagnostic test failed with output {output}"
+                    )));
                 } else {
                     last_output = Some(next_output)
                 }
@@ -158,29 +167,61 @@ impl IntCode {
                 self.opcode_7(&mut index, parameter_modes);
             } else if opcode == 8 {
                 self.opcode_8(&mut index, parameter_modes);
+            } else {
+                return Err(PuzzleError::unsupported(format!(
+                    "Unknown opcode {opcode} at index {index}"
+                )));
             }
         }
     }
 }
 
-fn part_1(contents: &str) -> i32 {
-    let input: Vec<i32> = contents
-        .split(",")
-        .map(|x| x.parse::<i32>().unwrap())
-        .collect();
+fn parse_program(contents: &str) -> Result<Vec<i32>, PuzzleError> {
+    parse_fields(contents.trim_end(), ',', parse_number).map_err(|e| e.on_line(0))
+}
+
+fn diagnostic_code(contents: &str, input: i32) -> Result<i32, PuzzleError> {
+    let mut intcode = IntCode {
+        positions: parse_program(contents)?,
+    };
+    intcode
+        .process(input)?
+        .ok_or_else(|| PuzzleError::unsupported("The program gave no output"))
+}
+
+fn part_1(contents: &str) -> Result<i32, PuzzleError> {
+    diagnostic_code(contents, 1)
+}
 
-    let mut intcode = IntCode { positions: input };
-    intcode.process(1).unwrap()
+fn part_2(contents: &str) -> Result<i32, PuzzleError> {
+    diagnostic_code(contents, 5)
 }
 
-fn part_2(contents: &str) -> i32 {
-    let input: Vec<i32> = contents
-        .split(",")
-        .map(|x| x.parse::<i32>().unwrap())
-        .collect();
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 5;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
+    }
+}
 
-    let mut intcode = IntCode { positions: input };
-    intcode.process(5).unwrap()
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
@@ -207,7 +248,7 @@ mod tests {
     )]
     fn test_opcodes_1_and_2(#[case] positions: Vec<i32>, #[case] expected: Vec<i32>) {
         let mut intcode = IntCode { positions };
-        intcode.process(1);
+        intcode.process(1).unwrap();
         assert_eq!(intcode.positions, expected);
     }
 
@@ -220,7 +261,7 @@ mod tests {
         #[case] raw_code: i32,
         #[case] expected: (i32, HashMap<usize, ParameterMode>),
     ) {
-        assert_eq!(IntCode::parse_opcode(raw_code), expected);
+        assert_eq!(IntCode::parse_opcode(raw_code), Ok(expected));
     }
 
     #[test]
@@ -228,7 +269,7 @@ mod tests {
         let mut intcode = IntCode {
             positions: vec![1002, 4, 3, 4, 33],
         };
-        intcode.process(1);
+        intcode.process(1).unwrap();
         assert_eq!(intcode.positions, vec![1002, 4, 3, 4, 99]);
     }
 
@@ -237,7 +278,7 @@ mod tests {
         let mut intcode = IntCode {
             positions: vec![1101, 100, -1, 4, 0],
         };
-        intcode.process(1);
+        intcode.process(1).unwrap();
         assert_eq!(intcode.positions, vec![1101, 100, -1, 4, 99]);
     }
 
@@ -288,7 +329,7 @@ mod tests {
         #[case] expected: i32,
     ) {
         let mut intcode = IntCode { positions };
-        assert_eq!(intcode.process(input).unwrap(), expected);
+        assert_eq!(intcode.process(input), Ok(Some(expected)));
     }
 
     #[rstest]
@@ -318,7 +359,7 @@ mod tests {
         #[case] expected: i32,
     ) {
         let mut intcode = IntCode { positions };
-        assert_eq!(intcode.process(input).unwrap(), expected);
+        assert_eq!(intcode.process(input), Ok(Some(expected)));
     }
 
     #[rstest]
@@ -334,17 +375,21 @@ mod tests {
         let mut intcode = IntCode {
             positions: positions.clone(),
         };
-        assert_eq!(intcode.process(input).unwrap(), expected);
+        assert_eq!(intcode.process(input), Ok(Some(expected)));
+    }
+    #[test]
+    fn test_errors() {
+        let mut intcode = IntCode {
+            positions: vec![301, 0, 0, 0, 99],
+        };
+        assert!(intcode.process(1).is_err());
+        let mut intcode = IntCode {
+            positions: vec![42, 0, 0, 0, 99],
+        };
+        assert!(intcode.process(1).is_err());
+        assert_eq!(
+            part_1("3,0,4,0,x99\n").unwrap_err().to_string(),
+            "Malformed input at line 1, column 9: Expected a number, not \"x99\""
+        );
     }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 fn parse_orbits(contents: &str, bidirectional: bool) -> HashMap<&str, HashSet<&str>> {
     let mut orbits: HashMap<&str, HashSet<&str>> = HashMap::new();
@@ -33,15 +36,12 @@ fn part_1(contents: &str) -> u32 {
         nodes.push_back(key);
     }
 
-    while nodes.len() > 0 {
+    while !nodes.is_empty() {
         let node = nodes.pop_front().unwrap();
-        match orbits.get(node) {
-            Some(other_nodes) => other_nodes.iter().for_each(|n| {
-                orbit_count_checksum += 1;
-                nodes.push_back(n);
-            }),
-            None => {}
-        };
+        if let Some(other_nodes) = orbits.get(node) { other_nodes.iter().for_each(|n| {
+            orbit_count_checksum += 1;
+            nodes.push_back(n);
+        }) };
     }
 
     orbit_count_checksum
@@ -59,53 +59,50 @@ fn part_2(contents: &str) -> u32 {
     let target = "SAN";
     let minimum_distance_to_target = loop {
         let (distance, node) = nodes.pop().unwrap();
-        match orbits.get(node) {
-            Some(other_nodes) => {
-                if other_nodes.contains(target) {
-                    break distance - 1;
-                }
-                other_nodes.iter().for_each(|n| match min_distances.get(n) {
-                    Some(min_distance) => {
-                        if min_distance > &(distance + 1) {
-                            min_distances.insert(n, distance + 1);
-                            nodes.push((distance + 1, n))
-                        }
-                    }
-                    None => {
+        if let Some(other_nodes) = orbits.get(node) {
+            if other_nodes.contains(target) {
+                break distance - 1;
+            }
+            other_nodes.iter().for_each(|n| match min_distances.get(n) {
+                Some(min_distance) => {
+                    if min_distance > &(distance + 1) {
                         min_distances.insert(n, distance + 1);
                         nodes.push((distance + 1, n))
                     }
-                })
-            }
-            None => {}
+                }
+                None => {
+                    min_distances.insert(n, distance + 1);
+                    nodes.push((distance + 1, n))
+                }
+            })
         };
     };
     minimum_distance_to_target
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 6;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example_1.txt")), 42);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example_2.txt")), 4);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_fields, parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{HashMap, VecDeque};
-use std::time::Instant;
 
 use itertools::Itertools;
 
@@ -17,6 +20,7 @@ enum Status {
 
 #[derive(Debug)]
 struct ThrusterSignal {
+    #[allow(dead_code)]
     phase_settings: Vec<i64>,
     signal: i64,
 }
@@ -36,7 +40,7 @@ impl IntCode {
     fn from_memory(memory: Vec<i64>) -> Self {
         IntCode { memory, index: 0 }
     }
-    fn parse_opcode(raw_code: i64) -> (i64, HashMap<usize, ParameterMode>) {
+    fn parse_opcode(raw_code: i64) -> Result<(i64, HashMap<usize, ParameterMode>), PuzzleError> {
         let mut parameter_modes: HashMap<usize, ParameterMode> = HashMap::new();
         let opcode = raw_code % 100;
 
@@ -48,12 +52,16 @@ impl IntCode {
             let mode = match digit {
                 0 => ParameterMode::POSITION,
                 1 => ParameterMode::IMMEDIATE,
-                _ => panic!("ParameterMode digit should only be 0 or 1"),
+                _ => {
+                    return Err(PuzzleError::unsupported(format!(
+                        "ParameterMode digit should only be 0 or 1, not {digit} in {raw_code}"
+                    )))
+                }
             };
             parameter_modes.insert(offset, mode);
             offset += 1
         }
-        (opcode, parameter_modes)
+        Ok((opcode, parameter_modes))
     }
 
     fn read_memory(&self, mode: &ParameterMode, index: Option<usize>) -> i64 {
@@ -152,7 +160,7 @@ impl IntCode {
         self.index += num_parameters + 1;
     }
 
-    fn max_thruster_signal(memory: Vec<i64>) -> ThrusterSignal {
+    fn max_thruster_signal(memory: Vec<i64>) -> Result<ThrusterSignal, PuzzleError> {
         let mut max_thruster_signal = ThrusterSignal {
             phase_settings: vec![],
             signal: i64::MIN,
@@ -168,7 +176,7 @@ impl IntCode {
                 };
                 inputs.push_back(*phase);
                 inputs.push_back(output);
-                output = intcode.process(&mut inputs);
+                output = intcode.process(&mut inputs)?;
             }
 
             if output > max_thruster_signal.signal {
@@ -178,10 +186,10 @@ impl IntCode {
                 };
             }
         }
-        max_thruster_signal
+        Ok(max_thruster_signal)
     }
 
-    fn max_thruster_signal_with_feedback(memory: Vec<i64>) -> ThrusterSignal {
+    fn max_thruster_signal_with_feedback(memory: Vec<i64>) -> Result<ThrusterSignal, PuzzleError> {
         let mut max_thruster_signal = ThrusterSignal {
             phase_settings: vec![],
             signal: i64::MIN,
@@ -203,28 +211,21 @@ impl IntCode {
 
             let mut output_signal = 0;
             loop {
-                let signal = amplifiers[amplifier_index as usize]
-                    .process_to_output_or_terminate(&mut inputs[amplifier_index as usize]);
+                let signal = amplifiers[amplifier_index ]
+                    .process_to_output_or_terminate(&mut inputs[amplifier_index])?;
 
                 // update the output if there was some
                 amplifier_index = (amplifier_index + 1) % (num_amplifiers as usize);
-                match signal.output {
-                    Some(output) => {
-                        output_signal = output;
-                        inputs[amplifier_index as usize].push_back(output);
-                    }
-                    _ => {}
+                if let Some(output) = signal.output {
+                    output_signal = output;
+                    inputs[amplifier_index ].push_back(output);
                 }
 
                 // exit gracefully if the final amplifier had a terminate status
                 // we've already changed the amplifier index, so check takes
                 // that into account
-                if amplifier_index == 0 as usize {
-                    match signal.status {
-                        Status::TERMINATE => break,
-                        _ => {}
-                    }
-                }
+                if amplifier_index == 0_usize
+                    && signal.status == Status::TERMINATE { break }
             }
 
             // override maximum signal if these phase settings are better
@@ -235,12 +236,12 @@ impl IntCode {
                 };
             }
         }
-        max_thruster_signal
+        Ok(max_thruster_signal)
     }
 
-    fn process_op(&mut self, inputs: &mut VecDeque<i64>) -> Signal {
+    fn process_op(&mut self, inputs: &mut VecDeque<i64>) -> Result<Signal, PuzzleError> {
         let raw_code = self.memory[self.index];
-        let (opcode, parameter_modes) = IntCode::parse_opcode(raw_code);
+        let (opcode, parameter_modes) = IntCode::parse_opcode(raw_code)?;
         let mut output = None;
         let mut status = Status::CONTINUE;
         if opcode == 99 {
@@ -250,7 +251,9 @@ impl IntCode {
         } else if opcode == 2 {
             self.opcode_2(parameter_modes);
         } else if opcode == 3 {
-            let input = inputs.pop_front().unwrap();
+            let input = inputs
+                .pop_front()
+                .ok_or_else(|| PuzzleError::unsupported("The program needs more input"))?;
             self.opcode_3(input);
         } else if opcode == 4 {
             output = Some(self.opcode_4(parameter_modes));
@@ -262,13 +265,21 @@ impl IntCode {
             self.opcode_7(parameter_modes);
         } else if opcode == 8 {
             self.opcode_8(parameter_modes);
+        } else {
+            return Err(PuzzleError::unsupported(format!(
+                "Unknown opcode {opcode} at index {}",
+                self.index
+            )));
         }
-        Signal { output, status }
+        Ok(Signal { output, status })
     }
 
-    fn process_to_output_or_terminate(&mut self, inputs: &mut VecDeque<i64>) -> Signal {
+    fn process_to_output_or_terminate(
+        &mut self,
+        inputs: &mut VecDeque<i64>,
+    ) -> Result<Signal, PuzzleError> {
         loop {
-            let signal = self.process_op(inputs);
+            let signal = self.process_op(inputs)?;
             match signal {
                 Signal {
                     status: Status::TERMINATE,
@@ -276,50 +287,72 @@ impl IntCode {
                 }
                 | Signal {
                     output: Some(_), ..
-                } => return signal,
+                } => return Ok(signal),
                 _ => {}
             }
         }
     }
 
-    fn process(&mut self, inputs: &mut VecDeque<i64>) -> i64 {
+    fn process(&mut self, inputs: &mut VecDeque<i64>) -> Result<i64, PuzzleError> {
         let mut output = 0;
         loop {
-            let signal = self.process_op(inputs);
+            let signal = self.process_op(inputs)?;
             match signal.status {
-                Status::TERMINATE => return output,
-                Status::CONTINUE => match signal.output {
-                    Some(new_output) => {
-                        if output > 0 {
-                            panic!("Diagnostic test failed")
-                        }
-                        output = new_output;
+                Status::TERMINATE => return Ok(output),
+                Status::CONTINUE => if let Some(new_output) = signal.output {
+                    if output > 0 {
+                        return Err(PuzzleError::unsupported(format!(
+                            "Diagnostic test failed with output {output}"
+                        )));
                     }
-                    None => {}
+                    output = new_output;
                 },
             }
         }
     }
 }
 
-fn part_1(contents: &str) -> i64 {
-    let input: Vec<i64> = contents
-        .split(",")
-        .map(|x| x.parse::<i64>().unwrap())
-        .collect();
+fn parse_program(contents: &str) -> Result<Vec<i64>, PuzzleError> {
+    parse_fields(contents.trim_end(), ',', parse_number).map_err(|e| e.on_line(0))
+}
 
-    let max_thruster_signal = IntCode::max_thruster_signal(input);
-    max_thruster_signal.signal
+fn part_1(contents: &str) -> Result<i64, PuzzleError> {
+    let input = parse_program(contents)?;
+    let max_thruster_signal = IntCode::max_thruster_signal(input)?;
+    Ok(max_thruster_signal.signal)
 }
 
-fn part_2(contents: &str) -> i64 {
-    let input: Vec<i64> = contents
-        .split(",")
-        .map(|x| x.parse::<i64>().unwrap())
-        .collect();
+fn part_2(contents: &str) -> Result<i64, PuzzleError> {
+    let input = parse_program(contents)?;
+    let max_thruster_signal = IntCode::max_thruster_signal_with_feedback(input)?;
+    Ok(max_thruster_signal.signal)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 7;
+    const PARSES_INPUT: bool = false;
 
-    let max_thruster_signal = IntCode::max_thruster_signal_with_feedback(input);
-    max_thruster_signal.signal
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
@@ -346,7 +379,7 @@ mod tests {
     )]
     fn test_opcodes_1_and_2(#[case] memory: Vec<i64>, #[case] expected: Vec<i64>) {
         let mut intcode = IntCode { memory, index: 0 };
-        intcode.process(&mut VecDeque::from(vec![1]));
+        intcode.process(&mut VecDeque::from(vec![1])).unwrap();
         assert_eq!(intcode.memory, expected);
     }
 
@@ -359,7 +392,7 @@ mod tests {
         #[case] raw_code: i64,
         #[case] expected: (i64, HashMap<usize, ParameterMode>),
     ) {
-        assert_eq!(IntCode::parse_opcode(raw_code), expected);
+        assert_eq!(IntCode::parse_opcode(raw_code), Ok(expected));
     }
 
     #[test]
@@ -368,7 +401,7 @@ mod tests {
             memory: vec![1002, 4, 3, 4, 33],
             index: 0,
         };
-        intcode.process(&mut VecDeque::from(vec![1]));
+        intcode.process(&mut VecDeque::from(vec![1])).unwrap();
         assert_eq!(intcode.memory, vec![1002, 4, 3, 4, 99]);
     }
 
@@ -378,7 +411,7 @@ mod tests {
             memory: vec![1101, 100, -1, 4, 0],
             index: 0,
         };
-        intcode.process(&mut VecDeque::from(vec![1]));
+        intcode.process(&mut VecDeque::from(vec![1])).unwrap();
         assert_eq!(intcode.memory, vec![1101, 100, -1, 4, 99]);
     }
 
@@ -426,7 +459,7 @@ mod tests {
     fn test_opcodes_7_and_8(#[case] memory: Vec<i64>, #[case] input: i64, #[case] expected: i64) {
         let mut intcode = IntCode { memory, index: 0 };
         let mut inputs = VecDeque::from(vec![input]);
-        assert_eq!(intcode.process(&mut inputs), expected);
+        assert_eq!(intcode.process(&mut inputs), Ok(expected));
     }
 
     #[rstest]
@@ -453,7 +486,7 @@ mod tests {
     fn test_opcodes_5_and_6(#[case] memory: Vec<i64>, #[case] input: i64, #[case] expected: i64) {
         let mut intcode = IntCode { memory, index: 0 };
         let mut inputs = VecDeque::from(vec![input]);
-        assert_eq!(intcode.process(&mut inputs), expected);
+        assert_eq!(intcode.process(&mut inputs), Ok(expected));
     }
 
     #[rstest]
@@ -471,7 +504,7 @@ mod tests {
             index: 0,
         };
         let mut inputs = VecDeque::from(vec![input]);
-        assert_eq!(intcode.process(&mut inputs), expected);
+        assert_eq!(intcode.process(&mut inputs), Ok(expected));
     }
 
     #[rstest]
@@ -501,7 +534,7 @@ mod tests {
         #[case] phase_settings: Vec<i64>,
         #[case] signal: i64,
     ) {
-        let max_thruster_signal = IntCode::max_thruster_signal(memory);
+        let max_thruster_signal = IntCode::max_thruster_signal(memory).unwrap();
         assert_eq!(max_thruster_signal.phase_settings, phase_settings);
         assert_eq!(max_thruster_signal.signal, signal);
     }
@@ -529,19 +562,16 @@ mod tests {
         #[case] phase_settings: Vec<i64>,
         #[case] signal: i64,
     ) {
-        let max_thruster_signal = IntCode::max_thruster_signal_with_feedback(memory);
+        let max_thruster_signal = IntCode::max_thruster_signal_with_feedback(memory).unwrap();
         assert_eq!(max_thruster_signal.phase_settings, phase_settings);
         assert_eq!(max_thruster_signal.signal, signal);
     }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+    #[test]
+    fn test_errors() {
+        assert!(IntCode::parse_opcode(201).is_err());
+        let mut intcode = IntCode::from_memory(vec![3, 0, 99]);
+        assert!(intcode.process(&mut VecDeque::new()).is_err());
+        let mut intcode = IntCode::from_memory(vec![42, 0, 99]);
+        assert!(intcode.process(&mut VecDeque::from(vec![1])).is_err());
+    }
 }
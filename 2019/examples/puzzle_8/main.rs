@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::puzzle::{run, Puzzle};
+use serde::Deserialize;
 
 fn count_char(chars: &[char], char_to_match: char) -> usize {
     chars.iter().filter(|x| **x == char_to_match).count()
@@ -26,8 +29,8 @@ fn part_2(contents: &str, width: usize, height: usize) -> String {
     let chars: Vec<char> = contents.chars().collect();
     for layer in chars.chunks(width * height) {
         for (index, char) in layer.iter().enumerate() {
-            let row = (index / width) as usize;
-            let column = (index % width) as usize;
+            let row = index / width ;
+            let column = index % width ;
             let current_value = output[row][column];
             let digit = char.to_digit(10).unwrap();
             if current_value == 2 {
@@ -39,7 +42,7 @@ fn part_2(contents: &str, width: usize, height: usize) -> String {
     let joined_rows: Vec<String> = output
         .iter()
         .map(|x| {
-            x.into_iter()
+            x.iter()
                 .map(|n| n.to_string())
                 .collect::<Vec<String>>()
                 .join("")
@@ -52,6 +55,51 @@ fn part_2(contents: &str, width: usize, height: usize) -> String {
     joined_rows.join("")
 }
 
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Width of the image in pixels.
+    width: usize,
+    /// Height of the image in pixels.
+    height: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            width: 25,
+            height: 6,
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 8;
+    const PARSES_INPUT: bool = false;
+
+    type Params = Params;
+    type Parsed = (String, Params);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        Ok((input.to_owned(), *params))
+    }
+
+    fn part_1(&self, (input, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input, params.width, params.height).into())
+    }
+
+    fn part_2(&self, (input, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input, params.width, params.height).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -71,14 +119,3 @@ mod tests {
         assert_eq!(part_2(input, 2, 2), expected);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents, 25, 6);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents, 25, 6);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_fields, parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashMap;
-use std::time::Instant;
 
 #[derive(Debug, PartialEq, Eq)]
 enum ParameterMode {
@@ -13,7 +16,7 @@ struct IntCode {
 }
 
 impl IntCode {
-    fn parse_opcode(raw_code: i64) -> (i64, HashMap<i64, ParameterMode>) {
+    fn parse_opcode(raw_code: i64) -> Result<(i64, HashMap<i64, ParameterMode>), PuzzleError> {
         let mut parameter_modes: HashMap<i64, ParameterMode> = HashMap::new();
         let opcode = raw_code % 100;
 
@@ -26,12 +29,16 @@ impl IntCode {
                 0 => ParameterMode::POSITION,
                 1 => ParameterMode::IMMEDIATE,
                 2 => ParameterMode::RELATIVE,
-                _ => panic!("ParameterMode digit should only be 0, 1 or 2"),
+                _ => {
+                    return Err(PuzzleError::unsupported(format!(
+                        "ParameterMode digit should only be 0, 1 or 2, not {digit} in {raw_code}"
+                    )))
+                }
             };
             parameter_modes.insert(offset, mode);
             offset += 1
         }
-        (opcode, parameter_modes)
+        Ok((opcode, parameter_modes))
     }
 
     fn get_value_at_index(&self, index: i64, relative_base: i64, mode: &ParameterMode) -> i64 {
@@ -107,16 +114,21 @@ impl IntCode {
         relative_base: &mut i64,
         input: i64,
         parameter_modes: HashMap<i64, ParameterMode>,
-    ) {
+    ) -> Result<(), PuzzleError> {
         let raw_index = self.memory.get(&(*index + 1)).unwrap_or(&0);
-        let relative_index = raw_index + relative_base.clone();
+        let relative_index = raw_index + *relative_base;
         let change_index = match parameter_modes.get(&1).unwrap_or(&ParameterMode::POSITION) {
             ParameterMode::POSITION => raw_index,
             ParameterMode::RELATIVE => &relative_index,
-            _ => panic!("ParameterMode should only be POSITION or RELATIVE"),
+            ParameterMode::IMMEDIATE => {
+                return Err(PuzzleError::unsupported(
+                    "ParameterMode should only be POSITION or RELATIVE when writing",
+                ))
+            }
         };
         self.memory.insert(*change_index, input);
-        *index += 2
+        *index += 2;
+        Ok(())
     }
 
     fn opcode_4(
@@ -211,26 +223,28 @@ impl IntCode {
         *index += 2;
     }
 
-    fn process(&mut self, input: i64, relative_base: &mut i64) -> Option<i64> {
+    fn process(&mut self, input: i64, relative_base: &mut i64) -> Result<Option<i64>, PuzzleError> {
         let mut index: i64 = 0;
         let mut last_output: Option<i64> = None;
         loop {
             let raw_code = self.memory.get(&index).unwrap_or(&0);
-            let (opcode, parameter_modes) = IntCode::parse_opcode(*raw_code);
+            let (opcode, parameter_modes) = IntCode::parse_opcode(*raw_code)?;
             //println!("index={index} raw_code={raw_code} relative_base={relative_base} -> opcode={opcode} parameter_modes={parameter_modes:?}");
             //println!("{:?}", self.memory);
             if opcode == 99 {
-                return last_output;
+                return Ok(last_output);
             } else if opcode == 1 {
                 self.opcode_1(&mut index, relative_base, parameter_modes);
             } else if opcode == 2 {
                 self.opcode_2(&mut index, relative_base, parameter_modes);
             } else if opcode == 3 {
-                self.opcode_3(&mut index, relative_base, input, parameter_modes);
+                self.opcode_3(&mut index, relative_base, input, parameter_modes)?;
             } else if opcode == 4 {
                 let next_output = self.opcode_4(&mut index, relative_base, parameter_modes);
-                if last_output.is_some() && last_output.unwrap() > 0 {
-                    panic!("Diagnostic test failed, output={}", last_output.unwrap())
+                if let Some(last) = last_output.filter(|last| *last > 0) {
+                    return Err(PuzzleError::unsupported(format!(
+                        "Diagnostic test failed, output={last}"
+                    )));
                 } else {
                     last_output = Some(next_output)
                 }
@@ -244,22 +258,26 @@ impl IntCode {
                 self.opcode_8(&mut index, relative_base, parameter_modes);
             } else if opcode == 9 {
                 self.opcode_9(&mut index, relative_base, parameter_modes);
+            } else {
+                return Err(PuzzleError::unsupported(format!(
+                    "Unknown opcode {opcode} at index {index}"
+                )));
             }
         }
     }
 }
 
-fn part_1(contents: &str) -> i64 {
-    let memory_values: Vec<i64> = contents
-        .split(",")
-        .map(|x| x.parse::<i64>().unwrap())
-        .collect();
+fn part_1(contents: &str) -> Result<i64, PuzzleError> {
+    let memory_values: Vec<i64> =
+        parse_fields(contents.trim_end(), ',', parse_number).map_err(|e| e.on_line(0))?;
     let memory = create_memory(memory_values);
     let mut intcode = IntCode { memory };
-    intcode.process(1, &mut 0).unwrap()
+    intcode
+        .process(1, &mut 0)?
+        .ok_or_else(|| PuzzleError::unsupported("The program gave no output"))
 }
 
-fn part_2(contents: &str) -> u64 {
+fn part_2(_contents: &str) -> u64 {
     0
 }
 
@@ -271,6 +289,33 @@ fn create_memory(memory_values: Vec<i64>) -> HashMap<i64, i64> {
         .collect()
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 9;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -302,7 +347,7 @@ mod tests {
     fn test_part_1_specific_case_outputs(#[case] memory_values: Vec<i64>, #[case] expected: i64) {
         let memory = create_memory(memory_values);
         let mut intcode = IntCode { memory };
-        assert_eq!(intcode.process(5, &mut 0).unwrap(), expected);
+        assert_eq!(intcode.process(5, &mut 0), Ok(Some(expected)));
     }
 
     #[test]
@@ -311,17 +356,18 @@ mod tests {
         let memory = create_memory(memory_values);
         let mut intcode = IntCode { memory };
         let output = intcode.process(0, &mut 0);
-        assert_eq!(output.unwrap().to_string().len(), 16);
+        assert_eq!(output.unwrap().unwrap().to_string().len(), 16);
+    }
+    #[test]
+    fn test_errors() {
+        assert!(IntCode::parse_opcode(301).is_err());
+        let mut intcode = IntCode {
+            memory: create_memory(vec![103, 0, 99]),
+        };
+        assert!(intcode.process(1, &mut 0).is_err());
+        let mut intcode = IntCode {
+            memory: create_memory(vec![42, 0, 99]),
+        };
+        assert!(intcode.process(1, &mut 0).is_err());
     }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
@@ -1,45 +1,45 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use itertools::Itertools;
-use std::time::Instant;
 
-fn find_product(contents: &str, target: u32, combination_size: usize) -> Option<u32> {
-    contents
-        .lines()
-        .map(|x| x.parse::<u32>().unwrap())
+fn find_product(entries: &[u32], target: u32, combination_size: usize) -> Option<u32> {
+    entries
+        .iter()
+        .copied()
         .combinations(combination_size)
         .filter(|x| x.iter().sum::<u32>() == target)
         .map(|x| x.into_iter().product())
         .next()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
-
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(
-            find_product(include_str!("./example.txt"), 2020, 2),
-            Some(514579)
-        );
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+
+    type Params = NoParams;
+    type Parsed = Vec<u32>;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<Vec<u32>, PuzzleError> {
+        parse_lines(input, parse_number)
+    }
+
+    fn part_1(&self, entries: &Vec<u32>) -> Result<Answer, PuzzleError> {
+        let product = find_product(entries, 2020, 2)
+            .ok_or_else(|| PuzzleError::unsupported("No solution found"))?;
+        Ok(product.into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(
-            find_product(include_str!("./example.txt"), 2020, 3),
-            Some(241861950)
-        );
+    fn part_2(&self, entries: &Vec<u32>) -> Result<Answer, PuzzleError> {
+        let product = find_product(entries, 2020, 3)
+            .ok_or_else(|| PuzzleError::unsupported("No solution found"))?;
+        Ok(product.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = find_product(contents, 2020, 2);
-    println!("Answer for part 1 is: {:?}", part_1_answer);
-    let part_2_answer = find_product(contents, 2020, 3);
-    println!("Answer for part 2 is: {:?}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,6 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashMap;
-use std::fs;
-use std::time::Instant;
 
 fn parse_jolts(contents: &str) -> Vec<u32> {
     let mut jolts: Vec<u32> = vec![0];
@@ -11,11 +13,11 @@ fn parse_jolts(contents: &str) -> Vec<u32> {
     jolts.sort();
     // add in extra jolt with fixed difference
     jolts.push(jolts[jolts.len() - 1] + 3);
-    return jolts;
+    jolts
 }
 
 /* Count the jolt differences when moving from one to the next, no skipping */
-fn part_1(contents: &str) {
+fn part_1(contents: &str) -> u32 {
     let jolts = parse_jolts(contents);
     let mut diffs: HashMap<u32, u32> = HashMap::new();
     let mut current_index: usize = 1;
@@ -24,11 +26,11 @@ fn part_1(contents: &str) {
         diffs.entry(diff).and_modify(|e| *e += 1).or_insert(1);
         current_index += 1;
     }
-    println!("Answer for part 1 is: {}", diffs[&1] * diffs[&3]);
+    diffs[&1] * diffs[&3]
 }
 
 /* Count all valid sets of adapters, skipping allowed up to +3. */
-fn part_2(contents: &str) {
+fn part_2(contents: &str) -> u64 {
     let jolts = parse_jolts(contents);
     let mut adapter_set_count_for_index: Vec<u64> = vec![0; jolts.len()];
     // seed the count with the 1 set to index 0 (with jolt 0)
@@ -52,27 +54,32 @@ fn part_2(contents: &str) {
             adapter_set_count_for_index[index] += other_count;
         }
     }
-    println!(
-        "Answer for part 2 is: {}",
-        adapter_set_count_for_index[jolts.len() - 1]
-    );
+    adapter_set_count_for_index[jolts.len() - 1]
 }
 
-fn main() {
-    let start = Instant::now();
-    let file_names: [&str; 3] = [
-        "examples/puzzle_10/example_1.txt",
-        "examples/puzzle_10/example_2.txt",
-        "examples/puzzle_10/input.txt",
-    ];
-    for file_name in file_names.iter() {
-        println!("FILE: {}", file_name);
-        let contents = fs::read_to_string(file_name).expect("Could not read file");
-        part_1(&contents);
-        part_2(&contents);
-        let duration = start.elapsed();
-        println!("Took {:?} to solve this puzzle", duration);
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 10;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
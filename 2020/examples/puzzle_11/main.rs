@@ -1,453 +1,78 @@
-use std::fmt;
-use std::time::Instant;
-
-static FLOOR: char = '.';
-static EMPTY_SEAT: char = 'L';
-static OCCUPIED_SEAT: char = '#';
-
-#[derive(Debug)]
-struct Grid {
-    size: usize,
-    grid: Vec<Vec<char>>,
+use aoc_utils::answer::Answer;
+use aoc_utils::automaton::{Automaton, Square8, Topology};
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::{Grid, Point2D, SightLines};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Seat {
+    #[default]
+    Floor,
+    Empty,
+    Occupied,
 }
 
-impl Grid {
-    fn new(size: usize, data: &str) -> Grid {
-        let mut grid: Vec<Vec<char>> = Vec::new();
-        for line in data.lines() {
-            let mut row: Vec<char> = Vec::new();
-            for char in line.chars() {
-                row.push(char);
-            }
-            grid.push(row)
-        }
-        Grid { size, grid }
-    }
-
-    fn up(&mut self, row: usize, col: usize) -> Option<char> {
-        if row <= 0 {
-            return None;
-        }
-        Some(self.grid[row - 1][col])
-    }
-
-    fn down(&mut self, row: usize, col: usize) -> Option<char> {
-        if row >= self.size - 1 {
-            return None;
-        }
-        Some(self.grid[row + 1][col])
-    }
-
-    fn left(&mut self, row: usize, col: usize) -> Option<char> {
-        if col <= 0 {
-            return None;
-        }
-        Some(self.grid[row][col - 1])
-    }
-
-    fn right(&mut self, row: usize, col: usize) -> Option<char> {
-        if col >= self.size - 1 {
-            return None;
-        }
-        Some(self.grid[row][col + 1])
-    }
-
-    fn up_left(&mut self, row: usize, col: usize) -> Option<char> {
-        if col <= 0 || row <= 0 {
-            return None;
-        }
-        Some(self.grid[row - 1][col - 1])
-    }
-
-    fn up_right(&mut self, row: usize, col: usize) -> Option<char> {
-        if col >= self.size - 1 || row <= 0 {
-            return None;
-        }
-        Some(self.grid[row - 1][col + 1])
-    }
-
-    fn down_left(&mut self, row: usize, col: usize) -> Option<char> {
-        if col <= 0 || row >= self.size - 1 {
-            return None;
-        }
-        Some(self.grid[row + 1][col - 1])
-    }
-
-    fn down_right(&mut self, row: usize, col: usize) -> Option<char> {
-        if col >= self.size - 1 || row >= self.size - 1 {
-            return None;
-        }
-        Some(self.grid[row + 1][col + 1])
-    }
-
-    fn adjacency_count(&mut self, row: usize, col: usize) -> u32 {
-        let mut count: u32 = 0;
-        match self.down(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.up(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.right(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.left(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.down_left(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.down_right(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.up_left(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.up_right(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        count
-    }
-
-    fn num_seats_of_type(&mut self, seat_type: &char) -> u32 {
-        let mut count: u32 = 0;
-        for row in self.grid.iter() {
-            for char in row.iter() {
-                if char == seat_type {
-                    count += 1
-                }
-            }
-        }
-        count
-    }
-
-    fn next_iteration(&mut self, row: usize, col: usize) -> char {
-        let count: u32 = self.adjacency_count(row, col);
-        let current: char = self.grid[row][col];
-        return {
-            if current == EMPTY_SEAT && count == 0 {
-                OCCUPIED_SEAT
-            } else if current == OCCUPIED_SEAT && count >= 4 {
-                EMPTY_SEAT
-            } else {
-                current
-            }
-        };
-    }
-
-    fn iteration(&mut self) -> (Grid, bool) {
-        let mut grid: Vec<Vec<char>> = Vec::new();
-        let mut changes = false;
-        for row_index in 0..self.size {
-            let mut row: Vec<char> = Vec::new();
-            for col_index in 0..self.size {
-                let new_char = self.next_iteration(row_index, col_index);
-                row.push(new_char);
-                let old_char = self.grid[row_index][col_index];
-                if old_char != new_char {
-                    changes = true
-                }
-            }
-            grid.push(row)
-        }
-        let new_grid = Grid {
-            size: self.size,
-            grid,
-        };
-        (new_grid, changes)
+fn parse_seat(c: char) -> Result<Seat, PuzzleError> {
+    match c {
+        '.' => Ok(Seat::Floor),
+        'L' => Ok(Seat::Empty),
+        '#' => Ok(Seat::Occupied),
+        _ => Err(PuzzleError::malformed(format!("Expected a seat or floor, not {c:?}"))),
     }
 }
 
-impl fmt::Display for Grid {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut string_rep: String = String::new();
-        for row in self.grid.iter() {
-            for char in row.iter() {
-                string_rep.push(*char);
-            }
-            string_rep.push_str("\n");
-        }
-        write!(f, "{}", string_rep)
+// People sit down if nobody they can see is sitting, and get up again when
+// too many are
+fn next_seat(seat: Seat, seen: &[Seat], tolerance: usize) -> Seat {
+    let occupied = seen.iter().filter(|&&other| other == Seat::Occupied).count();
+    match seat {
+        Seat::Empty if occupied == 0 => Seat::Occupied,
+        Seat::Occupied if occupied >= tolerance => Seat::Empty,
+        _ => seat,
     }
 }
 
-#[derive(Debug)]
-struct SightLineGrid {
-    size: usize,
-    grid: Vec<Vec<char>>,
+fn settle(grid: &Grid<Seat>, topology: impl Topology<Point2D<usize>>, tolerance: usize) -> u32 {
+    let seats = grid.iter().map(|(point, &seat)| (point, seat));
+    let mut automaton =
+        Automaton::new(topology, seats, |seat, seen: &[Seat]| next_seat(seat, seen, tolerance));
+    automaton.run_to_fixed_point();
+    automaton.count(Seat::Occupied) as u32
 }
 
-impl SightLineGrid {
-    fn new(size: usize, data: &str) -> SightLineGrid {
-        let mut grid: Vec<Vec<char>> = Vec::new();
-        for line in data.lines() {
-            let mut row: Vec<char> = Vec::new();
-            for char in line.chars() {
-                row.push(char);
-            }
-            grid.push(row)
-        }
-        SightLineGrid { size, grid }
-    }
-
-    fn up(&mut self, row: usize, col: usize) -> Option<char> {
-        let mut row_index = row;
-        while row_index > 0 {
-            row_index -= 1;
-            let char = self.grid[row_index][col];
-            if char != FLOOR {
-                return Some(char);
-            }
-        }
-        None
-    }
-
-    fn down(&mut self, row: usize, col: usize) -> Option<char> {
-        let mut row_index = row;
-        while row_index < self.size - 1 {
-            row_index += 1;
-            let char = self.grid[row_index][col];
-            if char != FLOOR {
-                return Some(char);
-            }
-        }
-        None
-    }
-
-    fn left(&mut self, row: usize, col: usize) -> Option<char> {
-        let mut col_index = col;
-        while col_index > 0 {
-            col_index -= 1;
-            let char = self.grid[row][col_index];
-            if char != FLOOR {
-                return Some(char);
-            }
-        }
-        None
-    }
-
-    fn right(&mut self, row: usize, col: usize) -> Option<char> {
-        let mut col_index = col + 1;
-        while col_index <= self.size - 1 {
-            let char = self.grid[row][col_index];
-            if char != FLOOR {
-                return Some(char);
-            }
-            col_index += 1
-        }
-        None
-    }
-
-    fn up_left(&mut self, row: usize, col: usize) -> Option<char> {
-        let mut col_index = col;
-        let mut row_index = row;
-        while col_index > 0 && row_index > 0 {
-            col_index -= 1;
-            row_index -= 1;
-            let char = self.grid[row_index][col_index];
-            if char != FLOOR {
-                return Some(char);
-            }
-        }
-        None
-    }
-
-    fn up_right(&mut self, row: usize, col: usize) -> Option<char> {
-        let mut col_index = col;
-        let mut row_index = row;
-        while col_index < self.size - 1 && row_index > 0 {
-            col_index += 1;
-            row_index -= 1;
-            let char = self.grid[row_index][col_index];
-            if char != FLOOR {
-                return Some(char);
-            }
-        }
-        None
-    }
-
-    fn down_left(&mut self, row: usize, col: usize) -> Option<char> {
-        let mut col_index = col;
-        let mut row_index = row;
-        while col_index > 0 && row_index < self.size - 1 {
-            col_index -= 1;
-            row_index += 1;
-            let char = self.grid[row_index][col_index];
-            if char != FLOOR {
-                return Some(char);
-            }
-        }
-        None
-    }
-
-    fn down_right(&mut self, row: usize, col: usize) -> Option<char> {
-        let mut col_index = col;
-        let mut row_index = row;
-        while col_index < self.size - 1 && row_index < self.size - 1 {
-            col_index += 1;
-            row_index += 1;
-            let char = self.grid[row_index][col_index];
-            if char != FLOOR {
-                return Some(char);
-            }
-        }
-        None
-    }
-
-    fn adjacency_count(&mut self, row: usize, col: usize) -> u32 {
-        let mut count: u32 = 0;
-        match self.down(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.up(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.right(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.left(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.down_left(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.down_right(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.up_left(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        match self.up_right(row, col) {
-            Some(c) if c == OCCUPIED_SEAT => count += 1,
-            _ => {}
-        }
-        count
-    }
-
-    fn num_seats_of_type(&mut self, seat_type: &char) -> u32 {
-        let mut count: u32 = 0;
-        for row in self.grid.iter() {
-            for char in row.iter() {
-                if char == seat_type {
-                    count += 1
-                }
-            }
-        }
-        count
-    }
-
-    fn next_iteration(&mut self, row: usize, col: usize) -> char {
-        let count: u32 = self.adjacency_count(row, col);
-        let current: char = self.grid[row][col];
-        return {
-            if current == EMPTY_SEAT && count == 0 {
-                OCCUPIED_SEAT
-            } else if current == OCCUPIED_SEAT && count >= 5 {
-                EMPTY_SEAT
-            } else {
-                current
-            }
-        };
-    }
-
-    fn iteration(&mut self) -> (SightLineGrid, bool) {
-        let mut grid: Vec<Vec<char>> = Vec::new();
-        let mut changes = false;
-        for row_index in 0..self.size {
-            let mut row: Vec<char> = Vec::new();
-            for col_index in 0..self.size {
-                let new_char = self.next_iteration(row_index, col_index);
-                row.push(new_char);
-                let old_char = self.grid[row_index][col_index];
-                if old_char != new_char {
-                    changes = true
-                }
-            }
-            grid.push(row)
-        }
-        let new_grid = SightLineGrid {
-            size: self.size,
-            grid,
-        };
-        (new_grid, changes)
-    }
+fn part_1(grid: &Grid<Seat>) -> u32 {
+    settle(grid, Square8, 4)
 }
 
-impl fmt::Display for SightLineGrid {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut string_rep: String = String::new();
-        for row in self.grid.iter() {
-            for char in row.iter() {
-                string_rep.push(*char);
-            }
-            string_rep.push_str("\n");
-        }
-        write!(f, "{}", string_rep)
-    }
+fn part_2(grid: &Grid<Seat>) -> u32 {
+    // the floor never changes, so neither does which seats can see each other
+    let sight_lines = SightLines::new(grid, |&seat| seat == Seat::Floor);
+    settle(grid, sight_lines, 5)
 }
 
-fn part_1(contents: &str) {
-    let size = contents.lines().count();
-    let mut grid = Grid::new(size, contents);
+pub struct Solution;
 
-    let mut iteration = 0;
-    loop {
-        let (new_grid, changes) = grid.iteration();
-        grid = new_grid;
-        //println!("{}\n{}", iteration, grid);
-        if !changes {
-            break;
-        }
-        iteration += 1;
-    }
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 11;
 
-    println!(
-        "Answer for part 1 is:\n{}",
-        grid.num_seats_of_type(&OCCUPIED_SEAT)
-    );
-}
+    type Params = NoParams;
+    type Parsed = Grid<Seat>;
 
-fn part_2(contents: &str) {
-    let size = contents.lines().count();
-    let mut grid = SightLineGrid::new(size, contents);
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<Grid<Seat>, PuzzleError> {
+        Grid::parse(input, parse_seat)
+    }
 
-    let mut iteration = 0;
-    loop {
-        let (new_grid, changes) = grid.iteration();
-        grid = new_grid;
-        //println!("{}\n{}", iteration, grid);
-        if !changes {
-            break;
-        }
-        iteration += 1;
+    fn part_1(&self, grid: &Grid<Seat>) -> Result<Answer, PuzzleError> {
+        Ok(part_1(grid).into())
     }
 
-    println!(
-        "Answer for part 2 is:\n{}",
-        grid.num_seats_of_type(&OCCUPIED_SEAT)
-    );
+    fn part_2(&self, grid: &Grid<Seat>) -> Result<Answer, PuzzleError> {
+        Ok(part_2(grid).into())
+    }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    part_1(contents);
-    part_2(contents);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,69 +1,17 @@
-use rstest::*;
-use std::time::Instant;
-
-#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
-enum Direction {
-    NORTH,
-    SOUTH,
-    EAST,
-    WEST,
-}
-
-impl From<&Direction> for i32 {
-    fn from(direction: &Direction) -> i32 {
-        match direction {
-            Direction::NORTH => 0,
-            Direction::EAST => 90,
-            Direction::SOUTH => 180,
-            Direction::WEST => 270,
-        }
-    }
-}
-
-impl From<i32> for Direction {
-    fn from(degrees: i32) -> Direction {
-        match degrees {
-            0 => Direction::NORTH,
-            90 => Direction::EAST,
-            180 => Direction::SOUTH,
-            270 => Direction::WEST,
-            _ => panic!("Only multiples of 90 in [0, 360) are expected!"),
-        }
-    }
-}
-
-impl From<char> for Direction {
-    fn from(direction: char) -> Direction {
-        match direction {
-            'N' => Direction::NORTH,
-            'E' => Direction::EAST,
-            'S' => Direction::SOUTH,
-            'W' => Direction::WEST,
-            _ => panic!("Only N, S, E and W are expected"),
-        }
-    }
-}
-
-impl Direction {
-    fn rotate_clockwise(&self, degrees: i32) -> Direction {
-        let current_degrees = i32::from(self);
-        let degrees_after_rotation = (current_degrees + degrees) % 360;
-        Direction::from(degrees_after_rotation)
-    }
-
-    fn rotate_anticlockwise(&self, degrees: i32) -> Direction {
-        self.rotate_clockwise(360 - (degrees % 360))
-    }
-}
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, parse_number, PuzzleError};
+use aoc_utils::geometry::Direction4;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 trait Transform {
-    fn transform(&mut self, command: char, units: i32) -> ();
+    fn transform(&mut self, command: char, units: i32) -> Result<(), PuzzleError>;
 }
 
 struct ShipLocation {
     x: i32,
     y: i32,
-    direction: Direction,
+    direction: Direction4,
 }
 
 impl ShipLocation {
@@ -73,7 +21,7 @@ impl ShipLocation {
 }
 
 impl Transform for ShipLocation {
-    fn transform(&mut self, command: char, units: i32) {
+    fn transform(&mut self, command: char, units: i32) -> Result<(), PuzzleError> {
         match command {
             'N' => {
                 self.y += units;
@@ -88,16 +36,20 @@ impl Transform for ShipLocation {
                 self.x -= units;
             }
 
-            'L' => self.direction = Direction::rotate_anticlockwise(&self.direction, units),
-            'R' => self.direction = Direction::rotate_clockwise(&self.direction, units),
-            'F' => match self.direction {
-                Direction::NORTH => self.y += units,
-                Direction::SOUTH => self.y -= units,
-                Direction::EAST => self.x += units,
-                Direction::WEST => self.x -= units,
-            },
-            _ => panic!("Only commands N, S, E, W, L, R and R are supported"),
+            'L' => self.direction = self.direction.rotate(-units)?,
+            'R' => self.direction = self.direction.rotate(units)?,
+            'F' => {
+                let step = self.direction.unit_vector::<i32>() * units;
+                self.x += step.x;
+                self.y += step.y;
+            }
+            _ => {
+                return Err(PuzzleError::malformed(format!(
+                    "Unexpected command {command:?}, expected N, S, E, W, L, R or F"
+                )))
+            }
         }
+        Ok(())
     }
 }
 
@@ -106,7 +58,7 @@ impl Default for ShipLocation {
         ShipLocation {
             x: 0,
             y: 0,
-            direction: Direction::EAST,
+            direction: Direction4::East,
         }
     }
 }
@@ -117,9 +69,11 @@ struct Waypoint {
 }
 
 impl Waypoint {
-    fn rotate_clockwise(&mut self, degrees: i32) {
+    fn rotate_clockwise(&mut self, degrees: i32) -> Result<(), PuzzleError> {
         if degrees % 90 != 0 {
-            panic!("Only rotations by multiples of 90 degrees are supported")
+            return Err(PuzzleError::malformed(format!(
+                "Can only rotate by multiples of 90 degrees, not {degrees}"
+            )));
         }
         let radians = (degrees as f64).to_radians();
         // we can do this because we know that we will only be rotating by multiples of 90
@@ -130,14 +84,15 @@ impl Waypoint {
         let new_relative_y = self.relative_x * sine_theta + self.relative_y * cosine_theta;
         self.relative_x = new_relative_x;
         self.relative_y = new_relative_y;
+        Ok(())
     }
-    fn rotate_anticlockwise(&mut self, degrees: i32) {
-        self.rotate_clockwise(360 - (degrees % 360));
+    fn rotate_anticlockwise(&mut self, degrees: i32) -> Result<(), PuzzleError> {
+        self.rotate_clockwise(360 - (degrees % 360))
     }
 }
 
 impl Transform for Waypoint {
-    fn transform(&mut self, command: char, units: i32) {
+    fn transform(&mut self, command: char, units: i32) -> Result<(), PuzzleError> {
         match command {
             'N' => {
                 self.relative_y += units;
@@ -151,8 +106,13 @@ impl Transform for Waypoint {
             'W' => {
                 self.relative_x -= units;
             }
-            _ => panic!("Only commands N, S, E and W are supported"),
+            _ => {
+                return Err(PuzzleError::malformed(format!(
+                    "Can only move the waypoint N, S, E or W, not {command:?}"
+                )))
+            }
         }
+        Ok(())
     }
 }
 
@@ -165,59 +125,99 @@ impl Default for Waypoint {
     }
 }
 
-fn part_1(contents: &str) {
+/// The command and number of units on each line.
+fn parse_instructions(contents: &str) -> Result<Vec<(char, i32)>, PuzzleError> {
+    parse_lines(contents, |line| {
+        let mut chars = line.chars();
+        let command = chars
+            .next()
+            .ok_or_else(|| PuzzleError::malformed("Expected a command"))?;
+        let units = parse_number(chars.as_str()).map_err(|e| e.at_column(1))?;
+        Ok((command, units))
+    })
+}
+
+fn part_1(contents: &str) -> Result<i32, PuzzleError> {
     let mut ship_location = ShipLocation::default();
-    for line in contents.lines() {
-        let command = line.chars().next().unwrap();
-        let units = line[1..].parse::<i32>().unwrap();
-        ship_location.transform(command, units);
-    }
-    println!(
-        "Answer for part 1 is: {}",
-        ship_location.manhattan_distince()
-    );
+    for (index, (command, units)) in parse_instructions(contents)?.into_iter().enumerate() {
+        ship_location
+            .transform(command, units)
+            .map_err(|e| e.on_line(index))?;
+    }
+    Ok(ship_location.manhattan_distince())
 }
 
-fn part_2(contents: &str) {
+fn part_2(contents: &str) -> Result<i32, PuzzleError> {
     let mut ship_location = ShipLocation::default();
     let mut waypoint = Waypoint::default();
-    for line in contents.lines() {
-        let command = line.chars().next().unwrap();
-        let units = line[1..].parse::<i32>().unwrap();
-
-        if String::from("NSEW").contains(command) {
+    for (index, (command, units)) in parse_instructions(contents)?.into_iter().enumerate() {
+        let result = if String::from("NSEW").contains(command) {
             waypoint.transform(command, units)
         } else if command == 'F' {
             ship_location.x += units * waypoint.relative_x;
             ship_location.y += units * waypoint.relative_y;
+            Ok(())
         } else if command == 'L' {
             waypoint.rotate_clockwise(units)
-        } else {
+        } else if command == 'R' {
             waypoint.rotate_anticlockwise(units)
-        }
+        } else {
+            Err(PuzzleError::malformed(format!("Unexpected command {command:?}")))
+        };
+        result.map_err(|e| e.on_line(index))?;
     }
-    println!(
-        "Answer for part 1 is: {}",
-        ship_location.manhattan_distince()
-    );
+    Ok(ship_location.manhattan_distince())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 12;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::*;
 
     #[test]
-    #[should_panic]
     fn invalid_transform() {
         let mut waypoint = Waypoint::default();
-        waypoint.transform('F', 100);
+        assert!(waypoint.transform('F', 100).is_err());
     }
 
     #[test]
-    #[should_panic]
     fn invalid_rotation() {
         let mut waypoint = Waypoint::default();
-        waypoint.rotate_clockwise(35);
+        assert!(waypoint.rotate_clockwise(35).is_err());
+    }
+
+    #[test]
+    fn invalid_instruction() {
+        let error = part_1("F10\nN3\nX7").unwrap_err();
+        assert_eq!(error.position().map(|position| position.line), Some(2));
+        assert!(part_2("F10\nR4x").is_err());
     }
 
     #[rstest]
@@ -226,21 +226,13 @@ mod tests {
     #[case('W', 0)]
     #[case('N', 270)]
     fn rotation_direction_and_reverse(#[case] direction_char: char, #[case] degrees: i32) {
-        let direction: Direction = direction_char.into();
+        let direction = Direction4::try_from(direction_char).unwrap();
         assert_eq!(
             direction,
             direction
-                .rotate_clockwise(degrees)
-                .rotate_anticlockwise(degrees)
+                .rotate(degrees)
+                .and_then(|d| d.rotate(-degrees))
+                .unwrap()
         );
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    part_1(contents);
-    part_2(contents);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
@@ -1,9 +1,12 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use aoc_utils::number_theory::extended_euclidean_algorithm;
-use std::time::Instant;
 
-fn part_1(contents: &str) {
+fn part_1(contents: &str) -> i128 {
     let earliest_possible_timestamp: i128 =
-        contents.lines().nth(0).unwrap().parse::<i128>().unwrap();
+        contents.lines().next().unwrap().parse::<i128>().unwrap();
     let bus_ids_raw = contents.lines().nth(1).unwrap();
 
     let mut earliest_bus_id = 0;
@@ -20,10 +23,7 @@ fn part_1(contents: &str) {
             earliest_leave_timestamp = next_timestamp
         }
     }
-    println!(
-        "Answer for part 1 is: {}",
-        earliest_bus_id * (earliest_leave_timestamp - earliest_possible_timestamp)
-    );
+    earliest_bus_id * (earliest_leave_timestamp - earliest_possible_timestamp)
 }
 
 struct ModuloCondition {
@@ -32,6 +32,7 @@ struct ModuloCondition {
 }
 
 /* Essentially the Chinese Remainder Theorem */
+#[allow(non_snake_case)]
 fn part_2(bus_ids_raw: &str) -> i128 {
     let mut conditions: Vec<ModuloCondition> = Vec::new();
     for (index, bus_id_raw) in bus_ids_raw.split(",").enumerate() {
@@ -41,21 +42,21 @@ fn part_2(bus_ids_raw: &str) -> i128 {
         let bus_id = bus_id_raw.parse::<i128>().unwrap();
         conditions.push(ModuloCondition {
             n: bus_id,
-            rem: index as i128,
+            rem: -(index as i128),
         })
     }
     // compute miss one out products
     let product: i128 = conditions.iter().map(|condition| condition.n).product();
     let Ns: Vec<i128> = conditions
         .iter()
-        .map(|condition| (product / condition.n) as i128)
+        .map(|condition| product / condition.n )
         .collect();
 
     // find M such that M * N + m * n = 1
     let Ms: Vec<i128> = conditions
         .iter()
         .zip(Ns.iter())
-        .map(|(condition, N)| extended_euclidean_algorithm(condition.n, *N).0)
+        .map(|(condition, N)| extended_euclidean_algorithm(condition.n, *N).1)
         .collect();
 
     let chinese_remainder_answer: i128 = conditions
@@ -65,7 +66,38 @@ fn part_2(bus_ids_raw: &str) -> i128 {
         .map(|((condition, M), N)| condition.rem * M * N)
         .sum();
 
-    chinese_remainder_answer % product
+    chinese_remainder_answer.rem_euclid(product)
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 13;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        let bus_ids = input
+            .lines()
+            .nth(1)
+            .ok_or_else(|| PuzzleError::unsupported("No solution found"))?;
+        Ok(part_2(bus_ids).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
@@ -97,16 +129,3 @@ mod tests {
         assert_eq!(part_2("1789,37,47,1889"), 1202161486);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    part_1(contents);
-
-    let bus_ids_raw = contents.lines().nth(1).unwrap();
-    let answer = part_2(bus_ids_raw);
-    println!("Answer for part 2 is: {}", answer);
-
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
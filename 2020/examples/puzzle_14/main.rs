@@ -1,11 +1,15 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::time::Instant;
 
 static MASK_LENGTH: u32 = 36;
 static VALID_CHARS: &str = "X01";
 
 #[derive(PartialEq, Eq, Debug)]
+#[derive(Default)]
 struct BitMaskV1 {
     zeros_mask: u128,
     ones_mask: u128,
@@ -19,20 +23,15 @@ impl BitMaskV1 {
         }
         let mut zeros_mask: u128 = 0;
         let mut ones_mask: u128 = 0;
-        let mut index: u32 = 0;
-        for char in raw_mask.chars() {
-            match char.to_digit(10) {
-                Some(b) => {
-                    let power = 2_u128.pow(MASK_LENGTH - index - 1) as u128;
-                    if b > 0 {
-                        ones_mask += power
-                    } else {
-                        zeros_mask += power
-                    }
+        for (index, char) in (0..).zip(raw_mask.chars()) {
+            if let Some(b) = char.to_digit(10) {
+                let power = 2_u128.pow(MASK_LENGTH - index - 1);
+                if b > 0 {
+                    ones_mask += power
+                } else {
+                    zeros_mask += power
                 }
-                None => {}
             }
-            index += 1
         }
         Ok(BitMaskV1 {
             zeros_mask,
@@ -45,16 +44,9 @@ impl BitMaskV1 {
     }
 }
 
-impl Default for BitMaskV1 {
-    fn default() -> BitMaskV1 {
-        BitMaskV1 {
-            zeros_mask: 0,
-            ones_mask: 0,
-        }
-    }
-}
 
 #[derive(PartialEq, Eq, Debug)]
+#[derive(Default)]
 struct BitMaskV2 {
     xs_mask: u128,
     ones_mask: u128,
@@ -68,9 +60,8 @@ impl BitMaskV2 {
         }
         let mut xs_mask: u128 = 0;
         let mut ones_mask: u128 = 0;
-        let mut index: u32 = 0;
-        for char in raw_mask.chars() {
-            let power = 2_u128.pow(MASK_LENGTH - index - 1) as u128;
+        for (index, char) in (0..).zip(raw_mask.chars()) {
+            let power = 2_u128.pow(MASK_LENGTH - index - 1);
             match char.to_digit(10) {
                 Some(b) => {
                     if b > 0 {
@@ -79,7 +70,6 @@ impl BitMaskV2 {
                 }
                 None => xs_mask += power,
             }
-            index += 1
         }
         Ok(BitMaskV2 { xs_mask, ones_mask })
     }
@@ -91,7 +81,7 @@ impl BitMaskV2 {
 
         let mut components: Vec<u128> = Vec::new();
         for exponent in 0..MASK_LENGTH {
-            let power = 2_u128.pow(exponent) as u128;
+            let power = 2_u128.pow(exponent);
             if (power & self.xs_mask) != 0 {
                 components.push(power)
             }
@@ -107,60 +97,86 @@ impl BitMaskV2 {
     }
 }
 
-impl Default for BitMaskV2 {
-    fn default() -> BitMaskV2 {
-        BitMaskV2 {
-            xs_mask: 0,
-            ones_mask: 0,
-        }
-    }
-}
 
-fn parse_memory_line(line: &str) -> (u128, u128) {
+fn parse_memory_line(line: &str) -> Result<(u128, u128), PuzzleError> {
     // line looks like mem[<int>] = <int> where we want the <int>s
-    let split = line.split(" = ").collect::<Vec<&str>>();
+    let malformed = || PuzzleError::malformed("Expected a line like \"mem[8] = 11\"");
+    let (location, value) = line.split_once(" = ").ok_or_else(malformed)?;
     // remove mem[ from start and ] from end
-    let memory_location = split[0][4..split[0].len() - 1].parse::<u128>().unwrap();
-    let memory_value = split[1].parse::<u128>().unwrap();
-    (memory_location, memory_value)
+    let location = location
+        .strip_prefix("mem[")
+        .and_then(|location| location.strip_suffix(']'))
+        .ok_or_else(malformed)?;
+    let memory_location = parse_number(location).map_err(|e| e.at_column(4))?;
+    let memory_value = parse_number(value).map_err(|e| e.at_column(line.len() - value.len()))?;
+    Ok((memory_location, memory_value))
 }
 
-fn part_1(contents: &str) -> u128 {
+/// An error for a mask on the line which either version rejects.
+fn mask_error(index: usize) -> PuzzleError {
+    let message = format!("Expected a mask of {MASK_LENGTH} X, 0 and 1 characters");
+    PuzzleError::malformed(message).on_line(index).at_column(7)
+}
+
+fn part_1(contents: &str) -> Result<u128, PuzzleError> {
     let mut memory: HashMap<u128, u128> = HashMap::new();
     let mut mask = BitMaskV1::default();
-    for line in contents.lines() {
-        if line.starts_with("mask") {
-            let raw_mask = line.strip_prefix("mask = ").unwrap();
-            match BitMaskV1::new(raw_mask) {
-                Ok(m) => mask = m,
-                Err(_) => panic!("Could not parse bit mask"),
-            };
-        } else {
-            let (memory_location, initial_value) = parse_memory_line(line);
-            memory.insert(memory_location, mask.transform(initial_value));
+    for (index, line) in contents.lines().enumerate() {
+        match line.strip_prefix("mask = ") {
+            Some(raw_mask) => mask = BitMaskV1::new(raw_mask).map_err(|_| mask_error(index))?,
+            None => {
+                let (memory_location, initial_value) =
+                    parse_memory_line(line).map_err(|e| e.on_line(index))?;
+                memory.insert(memory_location, mask.transform(initial_value));
+            }
         }
     }
-    memory.values().sum()
+    Ok(memory.values().sum())
 }
 
-fn part_2(contents: &str) -> u128 {
+fn part_2(contents: &str) -> Result<u128, PuzzleError> {
     let mut memory: HashMap<u128, u128> = HashMap::new();
     let mut mask = BitMaskV2::default();
-    for line in contents.lines() {
-        if line.starts_with("mask") {
-            let raw_mask = line.strip_prefix("mask = ").unwrap();
-            match BitMaskV2::new(raw_mask) {
-                Ok(m) => mask = m,
-                Err(_) => panic!("Could not parse bit mask"),
-            };
-        } else {
-            let (memory_location, memory_value) = parse_memory_line(line);
-            for transformed_location in mask.locations(memory_location).into_iter() {
-                memory.insert(transformed_location, memory_value);
+    for (index, line) in contents.lines().enumerate() {
+        match line.strip_prefix("mask = ") {
+            Some(raw_mask) => mask = BitMaskV2::new(raw_mask).map_err(|_| mask_error(index))?,
+            None => {
+                let (memory_location, memory_value) =
+                    parse_memory_line(line).map_err(|e| e.on_line(index))?;
+                for transformed_location in mask.locations(memory_location).into_iter() {
+                    memory.insert(transformed_location, memory_value);
+                }
             }
         }
     }
-    memory.values().sum()
+    Ok(memory.values().sum())
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 14;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
@@ -192,22 +208,16 @@ mod tests {
     #[case("X1")]
     #[case("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0XXXXXXXXXXX")]
     fn test_error_for_invalid_length(#[case] raw_mask: &str) {
-        let result = match BitMaskV1::new(raw_mask) {
-            Ok(_) => false,
-            Err(_) => true,
-        };
-        assert_eq!(result, true);
+        let result = BitMaskV1::new(raw_mask).is_err();
+        assert!(result);
     }
 
     #[rstest]
     #[case("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX02")]
     #[case("XXXXAXXXBXXXXXXXXXXXXXXXXXXXX1XXXX00")]
     fn test_error_for_invalid_characters(#[case] raw_mask: &str) {
-        let result = match BitMaskV1::new(raw_mask) {
-            Ok(_) => false,
-            Err(_) => true,
-        };
-        assert_eq!(result, true);
+        let result = BitMaskV1::new(raw_mask).is_err();
+        assert!(result);
     }
 
     #[rstest]
@@ -221,23 +231,13 @@ mod tests {
         assert_eq!(bit_mask.transform(input), expected_output);
     }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example_1.txt")), 165);
-    }
-
-    #[test]
-    fn test_part_2_example_1() {
-        assert_eq!(part_2(include_str!("./example_2.txt")), 208);
-    }
-
     #[test]
     fn test_part_2_example_2() {
         let input = "mask = 0XX000X1111001010X10XX1101XX00X00100
 mem[50596] = 1000
 mask = 0X000001111001010X1011100100001X0X0X
 mem[45713] = 1";
-        assert_eq!(part_2(input), 508032);
+        assert_eq!(part_2(input), Ok(508032));
     }
 
     #[test]
@@ -246,17 +246,6 @@ mem[45713] = 1";
 mem[8] = 4
 mask = XX0000000000000000000000000000000000
 mem[0] = 5";
-        assert_eq!(part_2(input), 52);
+        assert_eq!(part_2(input), Ok(52));
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
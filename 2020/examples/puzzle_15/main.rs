@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashMap;
-use std::time::Instant;
 
 fn memory_game(contents: &str, num_turns: u64) -> u64 {
     let mut turn: u64 = 1;
@@ -29,6 +32,33 @@ fn memory_game(contents: &str, num_turns: u64) -> u64 {
     current_value
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 15;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(memory_game(input.trim(), 2020).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(memory_game(input.trim(), 30000000).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,14 +88,3 @@ mod tests {
         assert_eq!(memory_game(input, 30000000), expected);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let input = "0,5,4,1,10,14,7";
-    let part_1_answer = memory_game(input, 2020);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = memory_game(input, 30000000);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 fn parse_ticket(line: &str) -> Vec<u64> {
     line.split(",").map(|x| x.parse::<u64>().unwrap()).collect()
@@ -36,7 +39,7 @@ struct Range {
 
 fn parse_conditions(contents: &str) -> Vec<Vec<Range>> {
     let mut conditions: Vec<Vec<Range>> = Vec::new();
-    for line in contents.lines().take_while(|x| x.len() > 0) {
+    for line in contents.lines().take_while(|x| !x.is_empty()) {
         let mut condition: Vec<Range> = Vec::new();
         let initial_components: Vec<&str> = line.split(": ").collect();
         for component in initial_components[1].split(" ") {
@@ -55,7 +58,7 @@ fn parse_conditions(contents: &str) -> Vec<Vec<Range>> {
     conditions
 }
 
-fn passes_a_condition(conditions: &Vec<Vec<Range>>, value: u64) -> bool {
+fn passes_a_condition(conditions: &[Vec<Range>], value: u64) -> bool {
     for condition in conditions.iter() {
         for range in condition.iter() {
             if (range.min <= value) && (value <= range.max) {
@@ -133,7 +136,7 @@ fn part_2(contents: &str) -> u64 {
         let mut next_index: usize = 0;
         let mut next_position: usize = 0;
         for (condition_index, positions) in possibles.iter() {
-            if !mapped.contains(&condition_index) && positions.len() == 1 {
+            if !mapped.contains(condition_index) && positions.len() == 1 {
                 next_index = *condition_index;
                 next_position = *positions.iter().next().unwrap();
                 break;
@@ -166,29 +169,29 @@ fn part_2(contents: &str) -> u64 {
     answer
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example_1.txt")), 71);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 16;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example_2.txt")), 11 * 12 * 13);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
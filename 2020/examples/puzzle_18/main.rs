@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::cmp;
-use std::time::Instant;
 
 #[derive(Debug, PartialEq, Eq)]
 enum Component {
@@ -59,7 +62,7 @@ fn parse_chunk(chunk: &str) -> Vec<Component> {
 fn extract_number(component: Component) -> Result<u128, String> {
     match component {
         Component::Integer { i } => Ok(i),
-        _ => return Err("First component must be an integer!".to_string()),
+        _ => Err("First component must be an integer!".to_string()),
     }
 }
 
@@ -67,7 +70,7 @@ fn apply_operator(value: u128, operator: &Component, next_value: u128) -> Result
     match operator {
         Component::Add => Ok(value + next_value),
         Component::Multiply => Ok(value * next_value),
-        _ => return Err("Expected a * or + here!".to_string()),
+        _ => Err("Expected a * or + here!".to_string()),
     }
 }
 
@@ -190,6 +193,33 @@ fn part_2(contents: &str) -> u128 {
     answer
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 18;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -238,14 +268,3 @@ mod tests {
         assert_eq!(advanced_evaluate_expression(expression), expected_output);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer: u128 = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer: u128 = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
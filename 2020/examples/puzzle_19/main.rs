@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 #[derive(Debug)]
 enum Rule {
@@ -16,7 +19,6 @@ impl Default for Rule {
 
 fn parse_sequence(rule: &str) -> Vec<String> {
     rule.split_whitespace()
-        .into_iter()
         .map(|x| x.to_string())
         .collect()
 }
@@ -66,7 +68,7 @@ struct PossibleMessage {
     rule_numbers: Vec<String>,
 }
 
-fn concat(seq_1: &Vec<String>, seq_2: &[String]) -> Vec<String> {
+fn concat(seq_1: &[String], seq_2: &[String]) -> Vec<String> {
     seq_1.iter().cloned().chain(seq_2.iter().cloned()).collect()
 }
 
@@ -95,7 +97,7 @@ fn iterate_messages<'a>(
                 if next_message.len() > max_len {
                     continue;
                 } else if messages.contains(&next_message) {
-                    if remaining_rule_numbers.len() == 0 {
+                    if remaining_rule_numbers.is_empty() {
                         valid_messages.insert(next_message);
                     } else {
                         next_possible_messages.push(PossibleMessage {
@@ -103,7 +105,7 @@ fn iterate_messages<'a>(
                             rule_numbers: remaining_rule_numbers.to_vec(),
                         });
                     }
-                } else if remaining_rule_numbers.len() == 0 {
+                } else if remaining_rule_numbers.is_empty() {
                     continue;
                 } else {
                     for message in messages {
@@ -149,7 +151,7 @@ fn find_valid_message(rules: HashMap<String, Rule>, messages: HashSet<String>) -
         let next_possible_messages =
             iterate_messages(&possible_messages, &mut valid_messages, &messages, &rules);
         possible_messages = next_possible_messages;
-        if possible_messages.len() == 0 {
+        if possible_messages.is_empty() {
             break;
         }
     }
@@ -180,24 +182,29 @@ fn part_2(contents: &str) -> usize {
     find_valid_message(rules, messages)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 2);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 19;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
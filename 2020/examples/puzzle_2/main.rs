@@ -1,6 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashMap;
-use std::fs;
-use std::time::Instant;
 
 fn valid_password_part_1(
     password: String,
@@ -35,54 +37,82 @@ fn valid_password_part_2(
     second_index: u8,
 ) -> bool {
     let mut num_matches = 0;
-    match password.chars().nth((first_index - 1).into()) {
+    match first_index.checked_sub(1).and_then(|index| password.chars().nth(index.into())) {
         Some(c) if c == character => num_matches += 1,
         _ => {}
     }
-    match password.chars().nth((second_index - 1).into()) {
+    match second_index.checked_sub(1).and_then(|index| password.chars().nth(index.into())) {
         Some(c) if c == character => num_matches += 1,
         _ => {}
     }
     num_matches == 1
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = fs::read_to_string("examples/puzzle_2/input.txt").expect("Could not read file");
+/// A password with the character its policy is about and the policy's two numbers.
+type Entry = (String, char, u8, u8);
 
-    let mut num_valid_part_1 = 0;
-    let mut num_valid_part_2 = 0;
-    for line in contents.lines() {
-        let parsed_line: String = line.trim().parse().unwrap();
-        let line_parts: Vec<&str> = parsed_line.split_whitespace().collect();
+fn parse_line(line: &str) -> Result<Entry, PuzzleError> {
+    let malformed =
+        || PuzzleError::malformed(format!("Expected a policy and a password, not {line:?}"));
+    let line_parts: Vec<&str> = line.split_whitespace().collect();
+    let [conditions, character, password] = line_parts[..] else {
+        return Err(malformed());
+    };
+    let (min_occurrences, max_occurrences) = conditions.split_once('-').ok_or_else(malformed)?;
+    let character = character.chars().next().ok_or_else(malformed)?;
+    Ok((
+        password.to_string(),
+        character,
+        parse_number(min_occurrences)?,
+        parse_number(max_occurrences)?,
+    ))
+}
 
-        let conditions: Vec<&str> = line_parts[0].split("-").collect();
-        let character = line_parts[1].chars().next().expect("string is empty");
-        let password = line_parts[2];
+fn part_1(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .filter(|(password, character, min_occurrences, max_occurrences)| {
+            valid_password_part_1(
+                password.to_string(),
+                *character,
+                *min_occurrences,
+                *max_occurrences,
+            )
+        })
+        .count()
+}
 
-        let min_occurrences = conditions[0].parse::<u8>().unwrap();
-        let max_occurrences = conditions[1].parse::<u8>().unwrap();
+fn part_2(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .filter(|(password, character, first_index, second_index)| {
+            valid_password_part_2(password.to_string(), *character, *first_index, *second_index)
+        })
+        .count()
+}
 
-        if valid_password_part_1(
-            password.to_string(),
-            character,
-            min_occurrences,
-            max_occurrences,
-        ) {
-            num_valid_part_1 += 1;
-        }
-        if valid_password_part_2(
-            password.to_string(),
-            character,
-            min_occurrences,
-            max_occurrences,
-        ) {
-            num_valid_part_2 += 1;
-        }
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+
+    type Params = NoParams;
+    type Parsed = Vec<Entry>;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<Vec<Entry>, PuzzleError> {
+        parse_lines(input, parse_line)
+    }
+
+    fn part_1(&self, entries: &Vec<Entry>) -> Result<Answer, PuzzleError> {
+        Ok(part_1(entries).into())
     }
-    println!("{} valid passwwords for part 1", num_valid_part_1);
-    println!("{} valid passwwords for part 2", num_valid_part_2);
 
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+    fn part_2(&self, entries: &Vec<Entry>) -> Result<Answer, PuzzleError> {
+        Ok(part_2(entries).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
@@ -1,6 +1,9 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
-use std::time::Instant;
 
 const TILE_SIZE: usize = 10;
 
@@ -16,7 +19,7 @@ impl Default for Tile {
 impl fmt::Display for Tile {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for row in self.0 {
-            write!(f, "{}\n", row.iter().cloned().collect::<String>());
+            writeln!(f, "{}", row.iter().cloned().collect::<String>())?;
         }
         write!(f, "")
     }
@@ -59,16 +62,16 @@ impl Tile {
 
     /// 8 variants through transformations
     fn variants(&self) -> Vec<Tile> {
-        let mut tiles: Vec<Tile> = Vec::new();
-        tiles.push(*self);
-        tiles.push(self.rotate());
-        tiles.push(self.rotate().rotate());
-        tiles.push(self.rotate().rotate().rotate());
-        tiles.push(self.flip_horizontal());
-        tiles.push(self.flip_horizontal().rotate());
-        tiles.push(self.flip_horizontal().rotate().rotate());
-        tiles.push(self.flip_horizontal().rotate().rotate().rotate());
-        tiles
+        vec![
+            *self,
+            self.rotate(),
+            self.rotate().rotate(),
+            self.rotate().rotate().rotate(),
+            self.flip_horizontal(),
+            self.flip_horizontal().rotate(),
+            self.flip_horizontal().rotate().rotate(),
+            self.flip_horizontal().rotate().rotate().rotate(),
+        ]
     }
 
     fn top(&self) -> [char; TILE_SIZE] {
@@ -163,8 +166,8 @@ struct Arrangement {
 impl fmt::Display for Arrangement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         for (coords, tile) in self.tiles.iter() {
-            write!(f, "Tile {} @ {coords:?}\n", tile.id);
-            write!(f, "{}\n", tile.tile);
+            writeln!(f, "Tile {} @ {coords:?}", tile.id)?;
+            writeln!(f, "{}", tile.tile)?;
         }
         write!(f, "")
     }
@@ -190,7 +193,7 @@ impl Arrangement {
 
     fn is_valid_square(&self) -> bool {
         let mut neighbour_counts: BTreeMap<i8, i8> = BTreeMap::new();
-        for (coords, tile) in self.tiles.iter() {
+        for (coords, _tile) in self.tiles.iter() {
             let num_neighbours = self.get_num_neighbours(coords);
             neighbour_counts
                 .entry(num_neighbours)
@@ -231,7 +234,7 @@ impl Arrangement {
 fn create_new_arrangements(
     arrangements: BTreeSet<Arrangement>,
     tile: TileWithId,
-    grid_size: i8,
+    _grid_size: i8,
 ) -> BTreeSet<Arrangement> {
     let mut new_arrangements: BTreeSet<Arrangement> = BTreeSet::new();
 
@@ -242,37 +245,25 @@ fn create_new_arrangements(
 
         for variant in tile.tile.variants().iter() {
             for coords in arrangement.outside.iter() {
-                match arrangement.tiles.get(&(coords.0 + 1, coords.1)) {
-                    Some(other_tile) => {
-                        if !variant.match_right(&other_tile.tile) {
-                            continue;
-                        }
+                if let Some(other_tile) = arrangement.tiles.get(&(coords.0 + 1, coords.1)) {
+                    if !variant.match_right(&other_tile.tile) {
+                        continue;
                     }
-                    _ => (),
                 }
-                match arrangement.tiles.get(&(coords.0 - 1, coords.1)) {
-                    Some(other_tile) => {
-                        if !variant.match_left(&other_tile.tile) {
-                            continue;
-                        }
+                if let Some(other_tile) = arrangement.tiles.get(&(coords.0 - 1, coords.1)) {
+                    if !variant.match_left(&other_tile.tile) {
+                        continue;
                     }
-                    _ => (),
                 }
-                match arrangement.tiles.get(&(coords.0, coords.1 + 1)) {
-                    Some(other_tile) => {
-                        if !variant.match_top(&other_tile.tile) {
-                            continue;
-                        }
+                if let Some(other_tile) = arrangement.tiles.get(&(coords.0, coords.1 + 1)) {
+                    if !variant.match_top(&other_tile.tile) {
+                        continue;
                     }
-                    _ => (),
                 }
-                match arrangement.tiles.get(&(coords.0, coords.1 - 1)) {
-                    Some(other_tile) => {
-                        if !variant.match_bottom(&other_tile.tile) {
-                            continue;
-                        }
+                if let Some(other_tile) = arrangement.tiles.get(&(coords.0, coords.1 - 1)) {
+                    if !variant.match_bottom(&other_tile.tile) {
+                        continue;
                     }
-                    _ => (),
                 }
 
                 // passed all checks, so this is a valid arrangement
@@ -284,7 +275,7 @@ fn create_new_arrangements(
                         tile: *variant,
                     },
                 );
-                new_arrangement.ids.insert(tile.id.clone());
+                new_arrangement.ids.insert(tile.id);
                 new_arrangement.outside.remove(coords);
                 let above = (coords.0, coords.1 + 1);
                 if !new_arrangement.tiles.contains_key(&above) {
@@ -370,12 +361,11 @@ fn find_corners(tiles: Vec<TileWithId>) -> Vec<u64> {
             corners.push(tile.id);
         }
     }
-    println!("{corners:?}");
     corners
 }
 
 fn part_1(contents: &str) -> u64 {
-    let mut tiles: Vec<TileWithId> = parse_tiles(contents)
+    let tiles: Vec<TileWithId> = parse_tiles(contents)
         .into_iter()
         .map(|(i, t)| TileWithId::new(i, t))
         .collect::<Vec<_>>();
@@ -394,16 +384,13 @@ fn create_mega_grid(arrangement: Arrangement, grid_size: i8) -> Vec<Vec<char>> {
         let tile_arr = tile.tile.0;
         for (row_index, row) in tile_arr[1..TILE_SIZE - 1].iter().enumerate() {
             for (column_index, char) in row[1..TILE_SIZE - 1].iter().enumerate() {
-                let mega_row_index = coords.1.abs() as usize * (TILE_SIZE - 2) + row_index;
+                let mega_row_index = coords.1.unsigned_abs() as usize * (TILE_SIZE - 2) + row_index;
                 let mega_column_index =
                     (grid_size - coords.0.abs() - 1) as usize * (TILE_SIZE - 2) + column_index;
                 grid[mega_row_index][mega_column_index] = *char;
             }
         }
     }
-    for row in grid.iter() {
-        println!("{}", row.iter().cloned().collect::<String>());
-    }
     grid
 }
 
@@ -417,7 +404,6 @@ fn part_2(contents: &str) -> u64 {
 
     let num_iterations = tiles.len() - 1;
     let grid_size = (tiles.len() as f64).sqrt() as i8;
-    println!("Grid size is {grid_size} ({} tiles)", tiles.len());
     let mut arrangements: BTreeSet<Arrangement> = BTreeSet::new();
 
     // corners are 1009, 1087, 1327, 2753
@@ -430,20 +416,20 @@ fn part_2(contents: &str) -> u64 {
         outside: BTreeSet::from([(-1, 0), (0, -1)]),
     });
 
-    for iteration in 0..num_iterations {
+    for _iteration in 0..num_iterations {
         let mut new_arrangements: BTreeSet<Arrangement> = BTreeSet::new();
 
-        for (id, tile) in tiles.iter() {
+        for (_id, tile) in tiles.iter() {
             let new_arrangements_with_tile =
                 create_new_arrangements(arrangements.clone(), tile.clone(), grid_size);
-            if new_arrangements_with_tile.len() > 0 {
+            if !new_arrangements_with_tile.is_empty() {
                 for arrangement in new_arrangements_with_tile.into_iter() {
                     new_arrangements.insert(arrangement);
                 }
                 break;
             }
         }
-        if arrangements.len() == 0 {
+        if arrangements.is_empty() {
             panic!("Should never have no arrangements!");
         }
         arrangements = new_arrangements;
@@ -458,14 +444,42 @@ fn part_2(contents: &str) -> u64 {
     panic!("At least one arrangement should be a valid square!")
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 20;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::input::load_example;
     use rstest::*;
 
     #[fixture]
-    fn tiles() -> BTreeMap<u64, Tile> {
-        parse_tiles(include_str!("./example.txt"))
+    fn tiles() -> Option<BTreeMap<u64, Tile>> {
+        load_example(2020, 20, "example.txt").map(|example| parse_tiles(&example))
     }
 
     #[rstest]
@@ -478,42 +492,32 @@ mod tests {
     #[case(2971)]
     #[case(1489)]
     #[case(1171)]
-    fn test_transformations_are_invariant(tiles: BTreeMap<u64, Tile>, #[case] id: u64) {
-        let tile = tiles.get(&id).unwrap().clone();
+    fn test_transformations_are_invariant(tiles: Option<BTreeMap<u64, Tile>>, #[case] id: u64) {
+        let Some(tiles) = tiles else {
+            return;
+        };
+        let tile = *tiles.get(&id).unwrap();
 
         assert_eq!(tile, tile.flip_diagonal().flip_diagonal());
         assert_eq!(tile, tile.flip_horizontal().flip_horizontal());
         assert_eq!(tile, tile.flip_vertical().flip_vertical());
         assert_eq!(tile, tile.rotate().rotate().rotate().rotate());
 
-        assert_eq!(tile.match_bottom(&tile.flip_horizontal()), true);
-        assert_eq!(tile.match_top(&tile.flip_horizontal()), true);
-        assert_eq!(tile.match_left(&tile.flip_vertical()), true);
-        assert_eq!(tile.match_right(&tile.flip_vertical()), true);
+        assert!(tile.match_bottom(&tile.flip_horizontal()));
+        assert!(tile.match_top(&tile.flip_horizontal()));
+        assert!(tile.match_left(&tile.flip_vertical()));
+        assert!(tile.match_right(&tile.flip_vertical()));
     }
 
     #[rstest]
-    fn test_matches_in_example(tiles: BTreeMap<u64, Tile>) {
+    fn test_matches_in_example(tiles: Option<BTreeMap<u64, Tile>>) {
+        let Some(tiles) = tiles else {
+            return;
+        };
         let top_middle = tiles.get(&2311).unwrap().flip_horizontal();
         let top_left = tiles.get(&1951).unwrap().flip_horizontal();
         let middle = tiles.get(&1427).unwrap().flip_horizontal();
-        assert_eq!(top_middle.match_left(&top_left), true);
-        assert_eq!(top_middle.match_bottom(&middle), true);
+        assert!(top_middle.match_left(&top_left));
+        assert!(top_middle.match_bottom(&middle));
     }
-
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 20899048083289);
-    }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
@@ -1,6 +1,9 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use parse_display::{Display, FromStr};
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 #[derive(Clone, Debug, Display, FromStr)]
 #[display(r"{ingredients} (contains {allergens})")]
@@ -86,7 +89,7 @@ fn part_1(contents: &str) -> u64 {
 }
 
 fn part_2(contents: &str) -> String {
-    let (recipes, ingredient_to_allergen) = find_allergen_mapping(contents);
+    let (_recipes, ingredient_to_allergen) = find_allergen_mapping(contents);
 
     let mut pairs = ingredient_to_allergen
         .into_iter()
@@ -101,29 +104,29 @@ fn part_2(contents: &str) -> String {
         .join(",")
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 21;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 5);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 1);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
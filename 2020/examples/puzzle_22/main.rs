@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::Instant;
 
 enum Winner {
     PLAYER_1,
@@ -78,13 +81,10 @@ fn game(
             Vec::from_iter(player_1.clone()),
             Vec::from_iter(player_2.clone()),
         );
-        match seen_games.get(&key) {
-            Some(winner) => match winner {
-                Winner::PLAYER_1 => return (Winner::PLAYER_1, score(player_1)),
-                Winner::PLAYER_2 => return (Winner::PLAYER_2, score(player_2)),
-            },
-            _ => (),
-        }
+        if let Some(winner) = seen_games.get(&key) { match winner {
+            Winner::PLAYER_1 => return (Winner::PLAYER_1, score(player_1)),
+            Winner::PLAYER_2 => return (Winner::PLAYER_2, score(player_2)),
+        } }
         if hands_seen.contains(&key) {
             seen_games.insert(original_key, Winner::PLAYER_1);
             return (Winner::PLAYER_1, score(player_1));
@@ -140,10 +140,36 @@ fn part_2(contents: &str) -> u64 {
     score
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 22;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rstest::*;
 
     #[test]
     fn test_score() {
@@ -153,11 +179,6 @@ mod tests {
         );
     }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 306);
-    }
-
     #[test]
     fn test_recursion_check() {
         let player_1 = VecDeque::from_iter([43, 19]);
@@ -165,20 +186,4 @@ mod tests {
         let mut seen_games: HashMap<(Vec<u64>, Vec<u64>), Winner> = HashMap::new();
         game(player_1, player_2, &mut seen_games);
     }
-
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 291);
-    }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
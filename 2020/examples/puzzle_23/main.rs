@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::puzzle::{run, Puzzle};
+use serde::Deserialize;
 
 fn do_move(
     cups: &mut Vec<u64>,
@@ -19,7 +22,7 @@ fn do_move(
         cups.remove(0)
     };
 
-    let mut destination: u64 = current_cup.clone();
+    let mut destination: u64 = *current_cup;
     loop {
         if destination == 1 {
             destination = length as u64;
@@ -73,7 +76,7 @@ fn part_1(contents: &str, num_moves: u8) -> u64 {
         .sum()
 }
 
-fn linked_list_move(cups: &mut Vec<usize>, current_cup: &mut usize, numbers_up_to: usize) {
+fn linked_list_move(cups: &mut [usize], current_cup: &mut usize, numbers_up_to: usize) {
     let one_ahead_cup = cups[*current_cup];
     let two_ahead_cup = cups[one_ahead_cup];
     let three_ahead_cup = cups[two_ahead_cup];
@@ -98,7 +101,7 @@ fn linked_list_move(cups: &mut Vec<usize>, current_cup: &mut usize, numbers_up_t
     }
 
     // insert
-    let mut destination_cup = cups[destination];
+    let destination_cup = cups[destination];
     cups[three_ahead_cup] = destination_cup;
 
     cups[destination] = one_ahead_cup;
@@ -120,12 +123,17 @@ fn part_2(contents: &str, num_moves: u64, numbers_up_to: usize) -> usize {
         } else {
             parsed_input.len() + 1
         };
-        cups[*value as usize] = cup
+        cups[*value ] = cup
     }
     // first additional number
     let additional_start = parsed_input.len() + 1;
-    for index in additional_start..numbers_up_to {
-        cups[index] = index + 1;
+    for (index, cup) in cups
+        .iter_mut()
+        .enumerate()
+        .take(numbers_up_to)
+        .skip(additional_start)
+    {
+        *cup = index + 1;
     }
     // final additional number
     cups[numbers_up_to] = parsed_input[0];
@@ -140,10 +148,57 @@ fn part_2(contents: &str, num_moves: u64, numbers_up_to: usize) -> usize {
     next * next_again
 }
 
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Moves made with only the labelled cups.
+    part_1_moves: u8,
+    /// Moves made once the cups are padded out.
+    part_2_moves: u64,
+    /// Label of the last cup once padded out.
+    numbers_up_to: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part_1_moves: 100,
+            part_2_moves: 10_000_000,
+            numbers_up_to: 1_000_000,
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 23;
+    const PARSES_INPUT: bool = false;
+
+    type Params = Params;
+    type Parsed = (String, Params);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        Ok((input.to_owned(), *params))
+    }
+
+    fn part_1(&self, (input, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input.trim(), params.part_1_moves).into())
+    }
+
+    fn part_2(&self, (input, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input.trim(), params.part_2_moves, params.numbers_up_to).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rstest::*;
 
     #[test]
     fn test_part_1_example_few_moves() {
@@ -160,13 +215,3 @@ mod tests {
         assert_eq!(part_2("389125467", 10_000_000, 1_000_000), 149245887792);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let part_1_answer = part_1("284573961", 100);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2("284573961", 10_000_000, 1_000_000);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 const MODULO: u128 = 20201227;
 
@@ -7,7 +10,7 @@ fn get_loop_size(public_key: u128) -> u128 {
     let mut value = 1;
     loop {
         value *= 7;
-        value = value % MODULO;
+        value %= MODULO;
         loop_number += 1;
         if value == public_key {
             return loop_number;
@@ -17,32 +20,54 @@ fn get_loop_size(public_key: u128) -> u128 {
 
 fn get_encryption_key(card_public_key: u128, door_public_key: u128) -> u128 {
     let card_loop_size = get_loop_size(card_public_key);
-    let door_loop_size = get_loop_size(door_public_key);
 
     let subject_number = door_public_key;
     let mut value = 1;
     for _ in 0..card_loop_size {
         value *= subject_number;
-        value = value % MODULO
+        value %= MODULO
     }
     value
 }
 
+fn part_1(contents: &str) -> u128 {
+    let public_keys: Vec<u128> = contents
+        .lines()
+        .map(|x| x.parse::<u128>().unwrap())
+        .collect();
+    get_encryption_key(public_keys[0], public_keys[1])
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 25;
+    const HAS_PART_2: bool = false;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rstest::*;
 
     #[test]
     fn test_get_encryption_key_example() {
-        assert_eq!(part_1(5764801, 17807724), 14897079);
+        assert_eq!(get_encryption_key(5764801, 17807724), 14897079);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let answer = get_encryption_key(17115212, 3667832);
-    println!("Answer is: {}", answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
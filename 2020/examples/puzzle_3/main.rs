@@ -1,7 +1,9 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::cmp;
 use std::collections::HashSet;
-use std::fs;
-use std::time::Instant;
 
 fn num_trees_for_slope(
     trees: &HashSet<(usize, usize)>,
@@ -27,9 +29,7 @@ fn num_trees_for_slope(
     num_trees
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = fs::read_to_string("examples/puzzle_3/input.txt").expect("Could not read file");
+fn parse_trees(contents: &str) -> (HashSet<(usize, usize)>, usize, usize) {
     let mut trees = HashSet::<(usize, usize)>::new();
     let mut max_row_index: usize = 0;
     let mut max_col_index: usize = 0;
@@ -45,16 +45,47 @@ fn main() {
             }
         }
     }
+    (trees, max_row_index + 1, max_col_index + 1)
+}
+
+fn part_1(contents: &str) -> usize {
+    let (trees, num_rows, num_cols) = parse_trees(contents);
+    num_trees_for_slope(&trees, (1, 3), num_rows, num_cols)
+}
 
+fn part_2(contents: &str) -> usize {
+    let (trees, num_rows, num_cols) = parse_trees(contents);
     let slopes: [(usize, usize); 5] = [(1, 1), (1, 3), (1, 5), (1, 7), (2, 1)];
     let mut answer: usize = 1;
     for slope in slopes.iter() {
-        let num_trees: usize =
-            num_trees_for_slope(&trees, *slope, max_row_index + 1, max_col_index + 1);
-        println!("{:?} -> {}", slope, num_trees);
-        answer *= num_trees;
+        answer *= num_trees_for_slope(&trees, *slope, num_rows, num_cols);
     }
-    println!("{}", answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+    answer
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
@@ -1,26 +1,29 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 type Validator = fn(&str) -> bool;
 
 fn validate_birth_year(year: &str) -> bool {
     match year.parse::<i32>() {
-        Ok(y) => (y >= 1920) && (y <= 2002),
+        Ok(y) => (1920..=2002).contains(&y),
         Err(_) => false,
     }
 }
 
 fn validate_issue_year(year: &str) -> bool {
     match year.parse::<i32>() {
-        Ok(y) => (y >= 2010) && (y <= 2020),
+        Ok(y) => (2010..=2020).contains(&y),
         Err(_) => false,
     }
 }
 
 fn validate_expiration_year(year: &str) -> bool {
     match year.parse::<i32>() {
-        Ok(y) => (y >= 2020) && (y <= 2030),
+        Ok(y) => (2020..=2030).contains(&y),
         Err(_) => false,
     }
 }
@@ -65,7 +68,7 @@ fn part_1(input: &str) -> u32 {
     let mut num_valid_fields = 0;
 
     for line in input.split("\n") {
-        if line == "" {
+        if line.is_empty() {
             // new passport about to begin, check old one first
             if num_valid_fields == expected_num_valid_fields {
                 num_valid_passports += 1;
@@ -106,7 +109,7 @@ fn part_2(input: &str) -> u32 {
     let mut num_valid_fields = 0;
 
     for line in input.split("\n") {
-        if line == "" {
+        if line.is_empty() {
             // new passport about to begin, check old one first
             if num_valid_fields == expected_num_valid_fields {
                 num_valid_passports += 1;
@@ -115,13 +118,10 @@ fn part_2(input: &str) -> u32 {
         } else {
             for field_info in line.split(" ") {
                 let (key, value) = field_info.split(":").collect_tuple().unwrap();
-                match validators.get(key) {
-                    Some(validator) => {
-                        if validator(value) {
-                            num_valid_fields += 1
-                        }
+                if let Some(validator) = validators.get(key) {
+                    if validator(value) {
+                        num_valid_fields += 1
                     }
-                    None => (),
                 }
             }
         }
@@ -134,13 +134,29 @@ fn part_2(input: &str) -> u32 {
     num_valid_passports
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    let part_2_answer = part_2(contents);
+pub struct Solution;
 
-    println!("Part 1: {}, Part 2: {}", part_1_answer, part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 4;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
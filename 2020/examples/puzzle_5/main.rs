@@ -1,17 +1,17 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 fn parse_row(pass_fragment: &str) -> isize {
     let row_map: HashMap<char, char> = vec![('F', '0'), ('B', '1')].into_iter().collect();
 
     let mut binary: String = "".to_string();
     for char in pass_fragment.chars() {
-        match row_map.get(&char) {
-            Some(binary_char) => binary.push_str(&binary_char.to_string()),
-            None => (),
-        }
+        if let Some(binary_char) = row_map.get(&char) { binary.push(*binary_char) }
     }
-    return isize::from_str_radix(&binary, 2).unwrap();
+    isize::from_str_radix(&binary, 2).unwrap()
 }
 
 fn parse_column(pass_fragment: &str) -> isize {
@@ -19,16 +19,13 @@ fn parse_column(pass_fragment: &str) -> isize {
 
     let mut binary: String = "".to_string();
     for char in pass_fragment.chars() {
-        match column_map.get(&char) {
-            Some(binary_char) => binary.push_str(&binary_char.to_string()),
-            None => (),
-        }
+        if let Some(binary_char) = column_map.get(&char) { binary.push(*binary_char) }
     }
-    return isize::from_str_radix(&binary, 2).unwrap();
+    isize::from_str_radix(&binary, 2).unwrap()
 }
 
 fn compute_seat_id(row: isize, column: isize) -> isize {
-    return row * 8 + column;
+    row * 8 + column
 }
 
 fn maximum_seat_id(contents: &str) -> isize {
@@ -41,7 +38,7 @@ fn maximum_seat_id(contents: &str) -> isize {
             max_seat_id = seat_id
         }
     }
-    return max_seat_id;
+    max_seat_id
 }
 
 fn find_seat_id(contents: &str) -> isize {
@@ -65,18 +62,32 @@ fn find_seat_id(contents: &str) -> isize {
             break;
         }
     }
-    return my_seat_id;
+    my_seat_id
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 5;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(maximum_seat_id(input).into())
+    }
 
-    let max_seat_id = maximum_seat_id(contents);
-    println!("Maximum seat id: {}", max_seat_id);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(find_seat_id(input).into())
+    }
+}
 
-    let seat_id = find_seat_id(contents);
-    println!("My seat id: {}", seat_id);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
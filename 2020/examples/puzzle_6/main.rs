@@ -1,12 +1,15 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashSet;
-use std::time::Instant;
 
 /* Count unique questions per group and then sum */
-fn part_1(contents: &str) {
+fn part_1(contents: &str) -> i32 {
     let mut total_questions: i32 = 0;
     let mut questions_answered_by_group: HashSet<char> = HashSet::new();
     for line in contents.lines() {
-        if line.len() == 0 {
+        if line.is_empty() {
             total_questions += questions_answered_by_group.len() as i32;
             questions_answered_by_group = HashSet::new();
         } else {
@@ -18,21 +21,21 @@ fn part_1(contents: &str) {
     // deal with final group
     total_questions += questions_answered_by_group.len() as i32;
 
-    println!("Answer to part 1 is: {}", total_questions);
+    total_questions
 }
 
 /* Count questions per group answered by all of group, then sum. */
-fn part_2(contents: &str) {
+fn part_2(contents: &str) -> i32 {
     let mut total_questions: i32 = 0;
     let mut questions_answered_by_all: HashSet<char> = HashSet::new();
     let mut first_in_group: bool = true;
 
     for line in contents.lines() {
-        if line.len() == 0 {
+        if line.is_empty() {
             total_questions += questions_answered_by_all.len() as i32;
             questions_answered_by_all = HashSet::new();
             first_in_group = true;
-        } else if !first_in_group && questions_answered_by_all.len() == 0 {
+        } else if !first_in_group && questions_answered_by_all.is_empty() {
             // already no common questions for people in this group
             continue;
         } else {
@@ -42,27 +45,42 @@ fn part_2(contents: &str) {
                 questions_answered_by_person.insert(question);
             }
 
-            if questions_answered_by_all.len() == 0 {
+            if questions_answered_by_all.is_empty() {
                 questions_answered_by_all = questions_answered_by_person
             } else {
-                questions_answered_by_all = questions_answered_by_all
-                    .into_iter()
-                    .filter(|e| questions_answered_by_person.contains(e))
-                    .collect();
+                questions_answered_by_all.retain(|e| questions_answered_by_person.contains(e));
             }
         }
     }
     // deal with final group
     total_questions += questions_answered_by_all.len() as i32;
 
-    println!("Answer to part 2 is: {}", total_questions);
+    total_questions
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    part_1(contents);
-    part_2(contents);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 6;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
@@ -1,6 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::fs;
-use std::time::Instant;
 
 static NULL_BAG: &str = "no other bag";
 
@@ -26,7 +28,7 @@ fn parse_parent_tree(contents: &str) -> HashMap<String, Vec<String>> {
                 .push(components[0].to_owned());
         }
     }
-    return parent_bags;
+    parent_bags
 }
 
 #[derive(Clone, Debug)]
@@ -61,36 +63,33 @@ fn parse_child_tree(contents: &str) -> HashMap<String, Vec<Bag>> {
                 });
         }
     }
-    return child_bags;
+    child_bags
 }
 
-fn part_1(contents: &String) {
+fn part_1(contents: &str) -> usize {
     let parent_bags = parse_parent_tree(contents);
 
     let mut seen_bags: HashSet<String> = HashSet::new();
     let mut current_bags: VecDeque<String> = VecDeque::from([String::from("shiny gold bag")]);
     let mut bags_that_can_hold: HashSet<String> = HashSet::new();
-    while current_bags.len() > 0 {
+    while !current_bags.is_empty() {
         let current_bag = current_bags.pop_front().unwrap().to_string();
-        match parent_bags.get(&current_bag) {
-            Some(bags) => {
-                for bag in bags.iter() {
-                    if !seen_bags.contains(bag) {
-                        current_bags.push_back(bag.to_string())
-                    }
-                    bags_that_can_hold.insert(bag.to_string());
+        if let Some(bags) = parent_bags.get(&current_bag) {
+            for bag in bags.iter() {
+                if !seen_bags.contains(bag) {
+                    current_bags.push_back(bag.to_string())
                 }
+                bags_that_can_hold.insert(bag.to_string());
             }
-            None => {}
         }
 
         seen_bags.insert(current_bag);
     }
 
-    println!("Answer for part 1 is: {}", bags_that_can_hold.len());
+    bags_that_can_hold.len()
 }
 
-fn part_2(contents: &String) {
+fn part_2(contents: &str) -> i32 {
     let child_bags = parse_child_tree(contents);
 
     let start_bag: Bag = Bag {
@@ -99,37 +98,44 @@ fn part_2(contents: &String) {
     };
     let mut current_bags: VecDeque<Bag> = VecDeque::from([start_bag]);
     let mut num_bags: i32 = 0;
-    while current_bags.len() > 0 {
+    while !current_bags.is_empty() {
         let current_bag = current_bags.pop_front().unwrap();
-        match child_bags.get(&current_bag.name) {
-            Some(bags) => {
-                for bag in bags.iter() {
-                    num_bags += current_bag.count * bag.count;
-                    let mut new_bag: Bag = bag.clone();
-                    new_bag.count = current_bag.count * bag.count;
-                    current_bags.push_back(new_bag)
-                }
+        if let Some(bags) = child_bags.get(&current_bag.name) {
+            for bag in bags.iter() {
+                num_bags += current_bag.count * bag.count;
+                let mut new_bag: Bag = bag.clone();
+                new_bag.count = current_bag.count * bag.count;
+                current_bags.push_back(new_bag)
             }
-            None => {}
         }
     }
 
-    println!("Answer for part 2 is: {}", num_bags);
+    num_bags
 }
 
-fn main() {
-    let start = Instant::now();
-    let file_names: [&str; 3] = [
-        "examples/puzzle_7/example_1.txt",
-        "examples/puzzle_7/example_2.txt",
-        "examples/puzzle_7/input.txt",
-    ];
-    for file_name in file_names.iter() {
-        println!("FILE: {}", file_name);
-        let contents = fs::read_to_string(file_name).expect("Could not read file");
-        part_1(&contents);
-        part_2(&contents);
-        let duration = start.elapsed();
-        println!("Took {:?} to solve this puzzle", duration);
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 7;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
+
+pub fn main() {
+    run(&Solution);
+}
@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 static NO_OP: &str = "nop";
 static ACC: &str = "acc";
@@ -23,7 +26,7 @@ fn parse_instructions(contents: &str) -> HashMap<i32, (i32, i32)> {
     commands
 }
 
-fn part_1(contents: &str) {
+fn part_1(contents: &str) -> i32 {
     let commands = parse_instructions(contents);
     let mut acc = 0;
     let mut seen_indexes: HashSet<i32> = HashSet::new();
@@ -39,7 +42,7 @@ fn part_1(contents: &str) {
         acc += acc_change;
         current_index += move_by
     }
-    println!("Answer to part 1 is: {}", acc);
+    acc
 }
 
 /* Perturb the first jump/nop command directly after the previously perturbed one.
@@ -82,10 +85,10 @@ fn parse_instructions_with_perturbation(
     if perturb_index < 0 {
         unreachable!()
     }
-    return (commands, perturb_index);
+    (commands, perturb_index)
 }
 
-fn part_2(contents: &str) {
+fn part_2(contents: &str) -> i32 {
     let mut perturb_index = 0;
 
     // outer loop we perturb the next jump/nop command each time
@@ -113,14 +116,32 @@ fn part_2(contents: &str) {
             current_index += move_by
         }
     };
-    println!("Answer to part 2 is: {}", answer);
+    answer
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    part_1(contents);
-    part_2(contents);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 8;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
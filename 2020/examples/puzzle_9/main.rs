@@ -1,7 +1,8 @@
-use itertools;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, parse_number, PuzzleError};
+use aoc_utils::puzzle::{run, Puzzle};
+use serde::Deserialize;
 use std::collections::VecDeque;
-use std::fs;
-use std::time::Instant;
 
 fn check_pairs(values: &VecDeque<u64>, number: u64) -> bool {
     for (a, b) in itertools::iproduct!(values, values) {
@@ -12,10 +13,9 @@ fn check_pairs(values: &VecDeque<u64>, number: u64) -> bool {
     false
 }
 
-fn part_1(contents: &String, preamble_length: usize) -> Option<u64> {
+fn part_1(numbers: &[u64], preamble_length: usize) -> Option<u64> {
     let mut deque: VecDeque<u64> = VecDeque::with_capacity(preamble_length);
-    for line in contents.lines() {
-        let number = line.parse::<u64>().unwrap();
+    for &number in numbers {
         if deque.len() == preamble_length {
             if !check_pairs(&deque, number) {
                 return Some(number);
@@ -29,7 +29,7 @@ fn part_1(contents: &String, preamble_length: usize) -> Option<u64> {
     None
 }
 
-fn get_encryption_weakness(numbers: Vec<u64>, start_index: usize, end_index: usize) -> u64 {
+fn get_encryption_weakness(numbers: &[u64], start_index: usize, end_index: usize) -> u64 {
     let mut min = u64::MAX;
     let mut max = 0;
     let mut current_index = start_index;
@@ -43,15 +43,10 @@ fn get_encryption_weakness(numbers: Vec<u64>, start_index: usize, end_index: usi
         }
         current_index += 1;
     }
-    return min + max;
+    min + max
 }
 
-fn part_2(contents: &String, target: &u64) {
-    let mut numbers: Vec<u64> = Vec::new();
-    for line in contents.lines() {
-        let number = line.parse::<u64>().unwrap();
-        numbers.push(number)
-    }
+fn part_2(numbers: &[u64], target: &u64) -> Option<u64> {
     for (index, _) in numbers.iter().enumerate() {
         let mut total: u64 = 0;
         let mut current_index = index;
@@ -60,10 +55,7 @@ fn part_2(contents: &String, target: &u64) {
                 Some(n) => {
                     total += n;
                     if total == *target {
-                        let encryption_weakness =
-                            get_encryption_weakness(numbers, index, current_index);
-                        println!("Answer for part 2 is: {}", encryption_weakness);
-                        return;
+                        return Some(get_encryption_weakness(numbers, index, current_index));
                     } else if total > *target {
                         break;
                     }
@@ -76,23 +68,50 @@ fn part_2(contents: &String, target: &u64) {
             current_index += 1
         }
     }
+    None
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Number of previous values which the next one should be a sum of two of.
+    preamble_length: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { preamble_length: 25 }
+    }
 }
 
-fn main() {
-    let start = Instant::now();
-    let file_names: [(&str, usize); 2] = [
-        ("examples/puzzle_9/example.txt", 5),
-        ("examples/puzzle_9/input.txt", 25),
-    ];
-    for (file_name, preamble_length) in file_names.iter() {
-        println!("FILE: {}", file_name);
-        let contents = fs::read_to_string(file_name).expect("Could not read file");
-        let part_1_answer = part_1(&contents, *preamble_length).unwrap();
-        println!("Answer for part 1 is: {}", part_1_answer);
-        part_2(&contents, &part_1_answer);
-        let duration = start.elapsed();
-        println!("Took {:?} to solve this puzzle", duration);
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 9;
+
+    type Params = Params;
+    type Parsed = (Vec<u64>, Params);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        Ok((parse_lines(input, parse_number)?, *params))
+    }
+
+    fn part_1(&self, (numbers, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        let invalid = part_1(numbers, params.preamble_length)
+            .ok_or_else(|| PuzzleError::unsupported("No solution found"))?;
+        Ok(invalid.into())
     }
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+
+    fn part_2(&self, (numbers, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        let invalid = part_1(numbers, params.preamble_length)
+            .ok_or_else(|| PuzzleError::unsupported("No solution found"))?;
+        let weakness = part_2(numbers, &invalid)
+            .ok_or_else(|| PuzzleError::unsupported("No solution found"))?;
+        Ok(weakness.into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
@@ -1,6 +1,9 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, parse_number, PuzzleError};
+use aoc_utils::puzzle::{run, Puzzle};
+use serde::Deserialize;
 use std::collections::BTreeMap;
 use std::fmt::Display;
-use std::time::Instant;
 
 #[derive(Debug)]
 enum Command {
@@ -8,23 +11,27 @@ enum Command {
     ADDX(i32),
 }
 
-impl From<&str> for Command {
-    fn from(s: &str) -> Self {
+impl TryFrom<&str> for Command {
+    type Error = PuzzleError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         let components = s.split_whitespace().collect::<Vec<&str>>();
         match components.len() {
-            1 => Command::NOOP,
-            2 => Command::ADDX(components[1].parse::<i32>().unwrap()),
-            _ => panic!("Cannot parse this line!"),
+            1 => Ok(Command::NOOP),
+            2 => Ok(Command::ADDX(parse_number(components[1])?)),
+            _ => Err(PuzzleError::malformed(format!(
+                "Expected noop or addx with a value, not {s:?}"
+            ))),
         }
     }
 }
 
-fn part_1(contents: &str, cycles_to_sum: Vec<i32>) -> i32 {
+fn part_1(contents: &str, cycles_to_sum: Vec<i32>) -> Result<i32, PuzzleError> {
     let mut register: BTreeMap<i32, i32> = BTreeMap::from([(0, 1)]);
     let mut current_value: i32 = 1;
     let mut cycle: i32 = 1;
-    for line in contents.lines() {
-        match Command::from(line) {
+    for command in parse_lines(contents, |line| Command::try_from(line))? {
+        match command {
             Command::NOOP => {
                 register.entry(cycle).or_insert(current_value);
                 cycle += 1;
@@ -39,7 +46,7 @@ fn part_1(contents: &str, cycles_to_sum: Vec<i32>) -> i32 {
         };
     }
 
-    cycles_to_sum.iter().map(|x| register[x] * x).sum()
+    Ok(cycles_to_sum.iter().map(|x| register[x] * x).sum())
 }
 
 struct CathodeRayTube([[char; 40]; 6]);
@@ -53,7 +60,7 @@ impl Default for CathodeRayTube {
 impl Display for CathodeRayTube {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for row in self.0 {
-            write!(f, "{}\n", String::from_iter(row));
+            writeln!(f, "{}", String::from_iter(row))?;
         }
         write!(f, "")
     }
@@ -67,7 +74,7 @@ impl CathodeRayTube {
     fn to_crt_index(&self, cycle: i32) -> (usize, usize) {
         let row_index = (cycle as usize) / self.num_columns();
         let column_index = (cycle as usize) % self.num_columns();
-        (row_index as usize, column_index)
+        (row_index, column_index)
     }
 
     fn update(&mut self, cycle: i32, current_register_value: i32) {
@@ -78,14 +85,14 @@ impl CathodeRayTube {
     }
 }
 
-fn part_2(contents: &str) -> String {
+fn part_2(contents: &str) -> Result<String, PuzzleError> {
     let mut crt = CathodeRayTube::default();
 
     let mut register: BTreeMap<i32, i32> = BTreeMap::from([(0, 1)]);
     let mut current_value: i32 = 1;
     let mut cycle: i32 = 1;
-    for line in contents.lines() {
-        match Command::from(line) {
+    for command in parse_lines(contents, |line| Command::try_from(line))? {
+        match command {
             Command::NOOP => {
                 register.entry(cycle).or_insert(current_value);
                 crt.update(cycle, current_value);
@@ -105,7 +112,47 @@ fn part_2(contents: &str) -> String {
             }
         };
     }
-    format!("{}", crt)
+    Ok(format!("{}", crt))
+}
+
+#[derive(Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Cycles during which the signal strength is summed.
+    cycles_to_sum: Vec<i32>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { cycles_to_sum: Vec::from([20, 60, 100, 140, 180, 220]) }
+    }
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+    const PARSES_INPUT: bool = false;
+
+    type Params = Params;
+    type Parsed = (String, Params);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        Ok((input.to_owned(), params.clone()))
+    }
+
+    fn part_1(&self, (input, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input, params.cycles_to_sum.clone())?.into())
+    }
+
+    fn part_2(&self, (input, _): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
@@ -113,19 +160,14 @@ mod tests {
     use super::*;
     use rstest::*;
 
+    #[allow(clippy::identity_op)]
     #[test]
     fn test_part_1_minimal_example() {
         let example: &str = "noop
         addx 3
         addx -5";
         let cycles_to_sum: Vec<i32> = Vec::from([1, 3, 5]);
-        assert_eq!(part_1(example, cycles_to_sum), 1 * 1 + 3 * 1 + 5 * 4);
-    }
-
-    #[test]
-    fn test_part_1_example() {
-        let cycles_to_sum: Vec<i32> = Vec::from([20, 60, 100, 140, 180, 220]);
-        assert_eq!(part_1(include_str!("./example.txt"), cycles_to_sum), 13140);
+        assert_eq!(part_1(example, cycles_to_sum), Ok(1 * 1 + 3 * 1 + 5 * 4));
     }
 
     #[rstest]
@@ -133,27 +175,7 @@ mod tests {
     #[case(39, (0, 39))]
     #[case(60, (1, 20))]
     fn test_to_crt_index(#[case] cycle: i32, #[case] expected_index: (usize, usize)) {
-        let mut crt = CathodeRayTube::default();
+        let crt = CathodeRayTube::default();
         assert_eq!(crt.to_crt_index(cycle), expected_index);
     }
-
-    #[test]
-    fn test_part_2_example() {
-        let answer = String::from(
-            "##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....\n",
-        );
-        assert_eq!(part_2(include_str!("./example.txt")), answer);
-    }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let cycles_to_sum: Vec<i32> = Vec::from([20, 60, 100, 140, 180, 220]);
-    let part_1_answer = part_1(contents, cycles_to_sum);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is:\n{}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
@@ -1,6 +1,9 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use parse_display::{Display, FromStr};
 use std::collections::HashMap;
-use std::time::Instant;
 
 #[derive(Clone, Copy, Debug, Display, FromStr)]
 
@@ -34,7 +37,7 @@ struct Monkey {
 
 impl Monkey {
     fn apply_test(&self, item: u128) -> usize {
-        if item % self.test_modulo == 0 {
+        if item.is_multiple_of(self.test_modulo) {
             self.test_true
         } else {
             self.test_false
@@ -129,17 +132,14 @@ fn process_monkeys(contents: &str, num_rounds: u32, divide_by_factor: u128) -> u
     let num_monkeys = monkey_items.len();
 
     // modulus by this to prevent overflows
-    let max_modulus: u128 = monkeys.iter().map(|(_, x)| x.test_modulo).product();
+    let max_modulus: u128 = monkeys.values().map(|x| x.test_modulo).product();
 
     for _ in 0..num_rounds {
         for monkey_index in 0..num_monkeys {
             // do any moves from previous monkey
             for (move_monkey_index, item) in move_queue.iter() {
-                match monkey_items.get_mut(move_monkey_index) {
-                    Some(items) => {
-                        items.push(*item);
-                    }
-                    _ => {}
+                if let Some(items) = monkey_items.get_mut(move_monkey_index) {
+                    items.push(*item);
                 }
             }
             move_queue = Vec::new();
@@ -154,14 +154,11 @@ fn process_monkeys(contents: &str, num_rounds: u32, divide_by_factor: u128) -> u
                 .or_insert(num_items);
 
             for _ in 0..monkey_items[&monkey_index].len() {
-                match monkey_items.get_mut(&monkey_index) {
-                    Some(items) => {
-                        let item = items.pop().unwrap();
-                        let new_item = monkey.operation.apply(item, divide_by_factor) % max_modulus;
-                        let new_monkey_index = monkey.apply_test(new_item);
-                        move_queue.push((new_monkey_index, new_item));
-                    }
-                    _ => {}
+                if let Some(items) = monkey_items.get_mut(&monkey_index) {
+                    let item = items.pop().unwrap();
+                    let new_item = monkey.operation.apply(item, divide_by_factor) % max_modulus;
+                    let new_monkey_index = monkey.apply_test(new_item);
+                    move_queue.push((new_monkey_index, new_item));
                 }
             }
         }
@@ -171,32 +168,29 @@ fn process_monkeys(contents: &str, num_rounds: u32, divide_by_factor: u128) -> u
     items_seen[0] * items_seen[1]
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(process_monkeys(include_str!("./example.txt"), 20, 3), 10605);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(process_monkeys(input, 20, 3).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(
-            process_monkeys(include_str!("./example.txt"), 10_000, 1),
-            2713310158
-        );
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(process_monkeys(input, 10_000, 1).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = process_monkeys(contents, 20, 3);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = process_monkeys(contents, 10_000, 1);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
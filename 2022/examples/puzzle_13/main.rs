@@ -1,7 +1,10 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use itertools::{EitherOrBoth::*, Itertools};
 use serde_json::{json, Value};
 use std::cmp::Ordering;
-use std::time::Instant;
 
 fn compare(left: &Value, right: &Value) -> Ordering {
     for pair in left
@@ -28,8 +31,8 @@ fn compare(left: &Value, right: &Value) -> Ordering {
                     cmp => return cmp,
                 },
             },
-            Left(l) => return Ordering::Greater,
-            Right(r) => return Ordering::Less,
+            Left(_l) => return Ordering::Greater,
+            Right(_r) => return Ordering::Less,
         }
     }
     Ordering::Equal
@@ -49,11 +52,8 @@ fn part_1(contents: &str) -> usize {
 
         let left = serde_json::from_str(left_raw).unwrap();
         let right = serde_json::from_str(right_raw).unwrap();
-        match compare(&left, &right) {
-            Ordering::Less => {
-                answer += index + 1;
-            }
-            _ => {}
+        if compare(&left, &right) == Ordering::Less {
+            answer += index + 1;
         }
     }
     answer
@@ -110,43 +110,49 @@ fn part_2(contents: &str) -> usize {
     answer
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::*;
 
     #[rstest]
-    #[case("[1,1,3,1,1]", "[1,1,5,1,1]", -1)]
-    #[case("[[1],[2,3,4]]", "[[1],4]", -1)]
-    #[case("[9]", "[[8,7,6]]", 1)]
-    #[case("[[4,4],4,4]", "[[4,4],4,4,4]", -1)]
-    #[case("[7,7,7,7]", "[7,7,7]", 1)]
-    #[case("[[[]]]", "[[]]", 1)]
-    #[case("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]", 1)]
-    fn test_comparison(#[case] left_raw: &str, #[case] right_raw: &str, #[case] in_order: i8) {
+    #[case("[1,1,3,1,1]", "[1,1,5,1,1]", Ordering::Less)]
+    #[case("[[1],[2,3,4]]", "[[1],4]", Ordering::Less)]
+    #[case("[9]", "[[8,7,6]]", Ordering::Greater)]
+    #[case("[[4,4],4,4]", "[[4,4],4,4,4]", Ordering::Less)]
+    #[case("[7,7,7,7]", "[7,7,7]", Ordering::Greater)]
+    #[case("[[[]]]", "[[]]", Ordering::Greater)]
+    #[case("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]", Ordering::Greater)]
+    fn test_comparison(#[case] left_raw: &str, #[case] right_raw: &str, #[case] in_order: Ordering) {
         let left = serde_json::from_str(left_raw).unwrap();
         let right = serde_json::from_str(right_raw).unwrap();
         assert_eq!(compare(&left, &right), in_order);
     }
-
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 13);
-    }
-
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 140);
-    }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
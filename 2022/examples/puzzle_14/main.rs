@@ -1,57 +1,24 @@
-use std::collections::HashSet;
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, PuzzleError};
+use aoc_utils::geometry::{Direction8, Orientation, Point2D, Rect, SparseGrid};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-struct Point {
-    x: u32,
-    y: u32,
-}
-
-impl From<&str> for Point {
-    fn from(s: &str) -> Self {
-        let components = s.split(",").collect::<Vec<&str>>();
-        Point {
-            x: components[0].parse::<u32>().unwrap(),
-            y: components[1].parse::<u32>().unwrap(),
-        }
-    }
-}
+type Point = Point2D<u32>;
 
-struct Bounds {
-    min_x: u32,
-    max_x: u32,
-    min_y: u32,
-    max_y: u32,
-}
-
-impl From<&HashSet<Point>> for Bounds {
-    fn from(filled_squares: &HashSet<Point>) -> Self {
-        Bounds {
-            max_x: filled_squares.iter().map(|p| p.x).max().unwrap(),
-            min_x: filled_squares.iter().map(|p| p.x).min().unwrap(),
-            max_y: filled_squares.iter().map(|p| p.y).max().unwrap(),
-            min_y: filled_squares.iter().map(|p| p.y).min().unwrap(),
-        }
-    }
+/// Whether sand has fallen past the rocks, where it will keep falling forever.
+fn check_out_of_bounds(rocks: &Rect<u32>, point: Point) -> bool {
+    point.x > rocks.max[0] || point.x < rocks.min[0] || point.y >= rocks.max[1]
 }
 
-impl Bounds {
-    fn check_out_of_bounds(&self, point: Point) -> bool {
-        point.x > self.max_x || point.x < self.min_x || point.y >= self.max_y
-    }
-}
-
-fn parse(contents: &str) -> HashSet<Point> {
-    let mut rocks: Vec<Vec<Point>> = Vec::new();
-    let mut filled_squares: HashSet<Point> = HashSet::new();
-    for line in contents.lines() {
-        let rock_line: Vec<Point> = line
-            .replace(" ", "")
+fn parse(contents: &str) -> Result<SparseGrid<u32>, PuzzleError> {
+    let mut filled_squares = SparseGrid::new();
+    let rocks: Vec<Vec<Point>> = parse_lines(contents, |line| {
+        line.replace(" ", "")
             .split("->")
-            .map(|x| Point::from(x))
-            .collect::<Vec<_>>();
-        rocks.push(rock_line)
-    }
+            .map(|point| point.parse())
+            .collect::<Result<Vec<_>, _>>()
+    })?;
 
     for line in rocks.iter() {
         for pair in line.windows(2) {
@@ -60,115 +27,87 @@ fn parse(contents: &str) -> HashSet<Point> {
             if start.x == end.x && start.y < end.y {
                 // vertical line, down
                 for y in start.y..=end.y {
-                    filled_squares.insert(Point { x: start.x, y });
+                    filled_squares.add(Point::new(start.x, y));
                 }
             } else if start.x == end.x && start.y > end.y {
                 // vertical line, up
                 for y in end.y..=start.y {
-                    filled_squares.insert(Point { x: start.x, y });
+                    filled_squares.add(Point::new(start.x, y));
                 }
             } else if start.x < end.x {
                 // horizontal line, right
                 for x in start.x..=end.x {
-                    filled_squares.insert(Point { x, y: start.y });
+                    filled_squares.add(Point::new(x, start.y));
                 }
             } else {
                 // horizontal line, left
                 for x in end.x..=start.x {
-                    filled_squares.insert(Point { x, y: start.y });
+                    filled_squares.add(Point::new(x, start.y));
                 }
             }
         }
     }
+    Ok(filled_squares)
+}
+
+/// The rectangle around the rocks, which needs there to be some.
+fn rock_bounds(filled_squares: &SparseGrid<u32>) -> Result<Rect<u32>, PuzzleError> {
     filled_squares
+        .bounds()
+        .ok_or_else(|| PuzzleError::malformed("Expected at least one line of rock"))
+}
+
+/// Where the sand moves next, straight down the screen if it can and
+/// otherwise diagonally, or `None` if it comes to rest.
+fn fall(sand: &Point, filled_squares: &SparseGrid<u32>) -> Option<Point> {
+    [Direction8::South, Direction8::SouthWest, Direction8::SouthEast]
+        .into_iter()
+        .filter_map(|direction| Orientation::Screen.step(sand, direction))
+        .find(|point| !filled_squares.contains(point))
 }
 
-fn part_1(contents: &str) -> u64 {
-    let mut filled_squares = parse(contents);
-    let bounds = Bounds::from(&filled_squares);
+fn part_1(contents: &str) -> Result<u64, PuzzleError> {
+    let mut filled_squares = parse(contents)?;
+    let rocks = rock_bounds(&filled_squares)?;
 
     let mut num_sand_particles: u64 = 0;
     'outer: loop {
-        let mut sand = Point { x: 500, y: 0 };
+        let mut sand = Point::new(500, 0);
         'inner: loop {
-            if bounds.check_out_of_bounds(sand) {
+            if check_out_of_bounds(&rocks, sand) {
                 break 'outer;
             }
 
-            let below = Point {
-                x: sand.x,
-                y: sand.y + 1,
-            };
-            if !filled_squares.contains(&below) {
-                sand = below;
+            if let Some(next) = fall(&sand, &filled_squares) {
+                sand = next;
                 continue;
             }
-            let diag_left = Point {
-                x: sand.x - 1,
-                y: sand.y + 1,
-            };
 
-            if !filled_squares.contains(&diag_left) {
-                sand = diag_left;
-                continue;
-            }
-
-            let diag_right = Point {
-                x: sand.x + 1,
-                y: sand.y + 1,
-            };
-            if !filled_squares.contains(&diag_right) {
-                sand = diag_right;
-                continue;
-            }
-
-            filled_squares.insert(sand);
+            filled_squares.add(sand);
             num_sand_particles += 1;
             break 'inner;
         }
     }
-    num_sand_particles
+    Ok(num_sand_particles)
 }
 
-fn part_2(contents: &str) -> u64 {
-    let mut filled_squares = parse(contents);
-    let bounds = Bounds::from(&filled_squares);
-    let floor_y = bounds.max_y + 2;
+fn part_2(contents: &str) -> Result<u64, PuzzleError> {
+    let mut filled_squares = parse(contents)?;
+    let rocks = rock_bounds(&filled_squares)?;
+    let floor_y = rocks.max[1] + 2;
 
     let mut num_sand_particles: u64 = 0;
     'outer: loop {
-        let mut sand = Point { x: 500, y: 0 };
+        let mut sand = Point::new(500, 0);
         'inner: loop {
             if sand.y + 1 == floor_y {
-                filled_squares.insert(sand);
+                filled_squares.add(sand);
                 num_sand_particles += 1;
                 break 'inner;
             }
 
-            let below = Point {
-                x: sand.x,
-                y: sand.y + 1,
-            };
-            if !filled_squares.contains(&below) {
-                sand = below;
-                continue;
-            }
-
-            let diag_left = Point {
-                x: sand.x - 1,
-                y: sand.y + 1,
-            };
-            if !filled_squares.contains(&diag_left) {
-                sand = diag_left;
-                continue;
-            }
-
-            let diag_right = Point {
-                x: sand.x + 1,
-                y: sand.y + 1,
-            };
-            if !filled_squares.contains(&diag_right) {
-                sand = diag_right;
+            if let Some(next) = fall(&sand, &filled_squares) {
+                sand = next;
                 continue;
             }
 
@@ -176,38 +115,38 @@ fn part_2(contents: &str) -> u64 {
                 num_sand_particles += 1;
                 break 'outer;
             } else {
-                filled_squares.insert(sand);
+                filled_squares.add(sand);
                 num_sand_particles += 1;
                 break 'inner;
             }
         }
     }
-    num_sand_particles
+    Ok(num_sand_particles)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 24);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 93);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,7 +1,11 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, PuzzleError};
+use aoc_utils::geometry::{row_coverage, uncovered, Diamond, Point, Point2D, Rect};
+use aoc_utils::puzzle::{run, Puzzle};
 use parse_display::{Display, FromStr};
-use std::cmp::{max, min};
+use serde::Deserialize;
 use std::collections::HashSet;
-use std::{str::FromStr, time::Instant};
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, Display, FromStr)]
 #[display(r"Sensor at x={x}, y={y}")]
@@ -17,182 +21,89 @@ struct Beacon {
     y: i128,
 }
 
-impl Sensor {
-    fn manhattan_distance(&self, other: Beacon) -> i128 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
-    }
-}
-
 fn tuning_frequency(x: i128, y: i128) -> i128 {
     (x * 4_000_000) + y
 }
 
-fn part_1(contents: &str, row_index: i128) -> i128 {
-    let mut beacon_xs: HashSet<i128> = HashSet::new();
-    let mut not_possible_in_row: Vec<(i128, i128)> = Vec::new();
-    for line in contents.lines() {
-        let components = line.split(": ").collect::<Vec<&str>>();
-        let sensor = Sensor::from_str(components[0]).unwrap();
-        let beacon = Beacon::from_str(components[1]).unwrap();
-
-        // record beacon x co-ordinates in row of interest for later
-        if beacon.y == row_index {
-            beacon_xs.insert(beacon.x);
-        }
-
-        // remove locations for this beacon sensor pair
-        let distance = sensor.manhattan_distance(beacon);
-        let y_diff = (sensor.y - row_index).abs();
+/// A sensor's diamond, reaching as far as its closest beacon, and the beacon.
+fn parse_reading(line: &str) -> Result<(Diamond<i128>, Point2D<i128>), PuzzleError> {
+    let (sensor, beacon) = line.split_once(": ").ok_or_else(|| {
+        PuzzleError::malformed(format!("Expected a sensor and a beacon, not {line:?}"))
+    })?;
+    let sensor = Sensor::from_str(sensor)
+        .map_err(|_| PuzzleError::malformed(format!("Expected a sensor, not {sensor:?}")))?;
+    let beacon = Beacon::from_str(beacon).map_err(|_| {
+        PuzzleError::malformed(format!("Expected a beacon, not {beacon:?}"))
+            .at_column(line.len() - beacon.len())
+    })?;
+    let beacon = Point2D::new(beacon.x, beacon.y);
+    Ok((Diamond::through(Point2D::new(sensor.x, sensor.y), &beacon), beacon))
+}
 
-        // doesn't stop anything on row being a beacon
-        if distance < y_diff {
-            continue;
-        }
-        // no equal distances!
-        let max_x_diff = (distance - y_diff).abs();
-        not_possible_in_row.push((sensor.x - max_x_diff, sensor.x + max_x_diff))
-    }
+fn part_1(diamonds: &[Diamond<i128>], beacons: &[Point2D<i128>], row_index: i128) -> i128 {
+    let covered: i128 = row_coverage(diamonds, row_index)
+        .iter()
+        .map(|(start, end)| end - start + 1)
+        .sum();
+
+    // beacons are always within their sensor's diamond, so are in the coverage
+    let beacon_xs: HashSet<i128> = beacons
+        .iter()
+        .filter(|beacon| beacon.y == row_index)
+        .map(|beacon| beacon.x)
+        .collect();
+    covered - beacon_xs.len() as i128
+}
 
-    not_possible_in_row.sort();
-
-    // merge ranges
-    let mut merged_not_possible_in_row: Vec<(i128, i128)> = Vec::new();
-    let mut current_range = not_possible_in_row[0];
-    for next_range in not_possible_in_row.iter().skip(1) {
-        if current_range.1 < next_range.0 {
-            // no overlap with next range, includes everything inclusively
-            merged_not_possible_in_row.push(current_range);
-            current_range = *next_range;
-        } else if current_range.1 < next_range.1 {
-            current_range = (current_range.0, next_range.1);
-        } else {
-            // (1, 3) w/ (2, 2) => (1, 3) case
-            continue;
-        }
-    }
-    merged_not_possible_in_row.push(current_range);
-
-    // remove beacons from ranges
-    let mut split_not_possible_in_row: Vec<(i128, i128)> = Vec::new();
-    for range in merged_not_possible_in_row.iter() {
-        let mut beacons_in_range = beacon_xs
-            .iter()
-            .filter(|x| range.0 <= **x && **x <= range.1)
-            .collect::<Vec<_>>();
-        if beacons_in_range.len() == 0 {
-            split_not_possible_in_row.push(*range);
-            continue;
-        }
+fn part_2(diamonds: &[Diamond<i128>], search_size: i128) -> i128 {
+    let search_area = Rect::from_corners(Point::new([0, 0]), Point::new([search_size; 2]));
+    // the beacon is the only point not covered, so it's next to a diamond
+    uncovered(diamonds, &search_area)
+        .first()
+        .map_or(0, |beacon| tuning_frequency(beacon.x, beacon.y))
+}
 
-        beacons_in_range.sort();
-        if range.0 <= *beacons_in_range[0] - 1 {
-            let first_range = (range.0, *beacons_in_range[0] - 1);
-            split_not_possible_in_row.push(first_range);
-        }
-        let last = beacons_in_range.last().unwrap();
-        if *last + 1 <= range.1 {
-            let last_range = (*last + 1, range.1);
-            split_not_possible_in_row.push(last_range);
-        }
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Row to count the positions which can't contain a beacon in.
+    row_index: i64,
+    /// Largest coordinate the distress beacon can have.
+    search_size: i64,
+}
 
-        for pair in beacons_in_range.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            if start == end || *start == end - 1 {
-                continue;
-            } else {
-                split_not_possible_in_row.push((start + 1, end - 1));
-            }
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            row_index: 2_000_000,
+            search_size: 4_000_000,
         }
     }
-
-    let mut answer = 0;
-    for range in split_not_possible_in_row.iter() {
-        answer += range.1 - range.0 + 1;
-    }
-    answer
 }
 
-fn part_2(contents: &str) -> i128 {
-    let min_x = 0;
-    let max_x = 4_000_000;
-    let mut sensor_data: Vec<(Sensor, i128)> = Vec::new();
-    for line in contents.lines() {
-        let components = line.split(": ").collect::<Vec<&str>>();
-        let sensor = Sensor::from_str(components[0]).unwrap();
-        let beacon = Beacon::from_str(components[1]).unwrap();
-        let distance = sensor.manhattan_distance(beacon);
-        sensor_data.push((sensor, distance))
-    }
-    for row_index in min_x..=max_x {
-        let mut not_possible_in_row: Vec<(i128, i128)> = Vec::new();
-        for (sensor, distance) in sensor_data.iter() {
-            let y_diff = (sensor.y - row_index).abs();
-
-            // doesn't stop anything on row being a beacon
-            if distance < &y_diff {
-                continue;
-            }
-            // no equal distances!
-            let max_x_diff = (distance - y_diff).abs();
-            not_possible_in_row.push((
-                max(min_x, sensor.x - max_x_diff),
-                min(max_x, sensor.x + max_x_diff),
-            ))
-        }
+pub struct Solution;
 
-        not_possible_in_row.sort();
-
-        // merge ranges
-        let mut merged_not_possible_in_row: Vec<(i128, i128)> = Vec::new();
-        let mut current_range = not_possible_in_row[0];
-        for next_range in not_possible_in_row.iter().skip(1) {
-            if current_range.1 < next_range.0 {
-                // no overlap with next range, includes everything inclusively
-                merged_not_possible_in_row.push(current_range);
-                current_range = *next_range;
-            } else if current_range.1 < next_range.1 {
-                current_range = (current_range.0, next_range.1);
-            } else {
-                // (1, 3) w/ (2, 2) => (1, 3) case
-                continue;
-            }
-        }
-        merged_not_possible_in_row.push(current_range);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
 
-        // check if beacon can be on this row
-        // if on row, merging should not make one continuous range
-        if merged_not_possible_in_row.len() > 1 {
-            let x = merged_not_possible_in_row[0].1 + 1;
-            return tuning_frequency(x, row_index);
-        }
-    }
-    0
-}
+    type Params = Params;
+    type Parsed = (Vec<Diamond<i128>>, Vec<Point2D<i128>>, Params);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        let (diamonds, beacons) = parse_lines(input, parse_reading)?.into_iter().unzip();
+        Ok((diamonds, beacons, *params))
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt"), 10), 26);
+    fn part_1(&self, (diamonds, beacons, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(diamonds, beacons, params.row_index.into()).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 56000011);
+    fn part_2(&self, (diamonds, _, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(diamonds, params.search_size.into()).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents, 2_000_000);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
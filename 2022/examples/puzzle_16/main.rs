@@ -1,7 +1,9 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
-use std::thread::current;
-use std::time::Instant;
 
 #[derive(Debug)]
 struct Valve {
@@ -46,9 +48,9 @@ impl Default for ElephantRoute {
 
 fn parse_valves(contents: &str) -> HashMap<String, Valve> {
     let mut valves: HashMap<String, Valve> = HashMap::new();
+    let re = Regex::new(r"Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnels lead to valves (.+)")
+        .unwrap();
     for line in contents.lines() {
-        let re = Regex::new(r"Valve ([A-Z][A-Z]) has flow rate=(\d+); tunnels lead to valves (.+)")
-            .unwrap();
         let captures = re.captures(line).unwrap();
         let name: String = String::from(&captures[1]);
         let valve: Valve = Valve {
@@ -56,7 +58,7 @@ fn parse_valves(contents: &str) -> HashMap<String, Valve> {
             flow_rate: captures[2].parse::<u64>().unwrap(),
             tunnels: captures[3]
                 .split(", ")
-                .map(|x| String::from(x))
+                .map(String::from)
                 .collect::<Vec<String>>(),
         };
         valves.insert(name, valve);
@@ -120,13 +122,10 @@ fn part_1(contents: &str) -> u64 {
                     .collect::<Vec<String>>();
                 vec_opened.sort();
                 let key = (next_valve.to_owned(), vec_opened);
-                match best_per_valve_set_and_current.get(&key) {
-                    Some(flow_rate) => {
-                        if flow_rate >= &route.flow_rate {
-                            continue;
-                        }
+                if let Some(flow_rate) = best_per_valve_set_and_current.get(&key) {
+                    if flow_rate >= &route.flow_rate {
+                        continue;
                     }
-                    _ => {}
                 }
 
                 best_per_valve_set_and_current
@@ -143,21 +142,14 @@ fn part_1(contents: &str) -> u64 {
         }
 
         current_minutes += 1;
-        if current_minutes > 0 {
-            let num_routes = next_routes.len();
-            let num_stored = best_per_valve_set_and_current.len();
-            println!("MINUTES {current_minutes}, num routes {num_routes} num stored {num_stored}");
-        }
 
-        if next_routes.len() == 0 {
+        if next_routes.is_empty() {
             break;
         };
         routes = next_routes;
     }
 
-    best_per_valve_set_and_current
-        .iter()
-        .map(|(_, v)| *v)
+    best_per_valve_set_and_current.values().copied()
         .max()
         .unwrap()
 }
@@ -242,13 +234,10 @@ fn part_2(contents: &str) -> u64 {
                         (route.current.0.to_owned(), next_valve.to_owned()),
                         vec_opened,
                     );
-                    match best_per_valve_set_and_current.get(&key) {
-                        Some(flow_rate) => {
-                            if flow_rate >= &new_flow_rate {
-                                continue;
-                            }
+                    if let Some(flow_rate) = best_per_valve_set_and_current.get(&key) {
+                        if flow_rate >= &new_flow_rate {
+                            continue;
                         }
-                        _ => {}
                     }
 
                     best_per_valve_set_and_current
@@ -282,13 +271,10 @@ fn part_2(contents: &str) -> u64 {
                         (next_valve.to_owned(), route.current.1.to_owned()),
                         vec_opened,
                     );
-                    match best_per_valve_set_and_current.get(&key) {
-                        Some(flow_rate) => {
-                            if flow_rate >= &new_flow_rate {
-                                continue;
-                            }
+                    if let Some(flow_rate) = best_per_valve_set_and_current.get(&key) {
+                        if flow_rate >= &new_flow_rate {
+                            continue;
                         }
-                        _ => {}
                     }
 
                     best_per_valve_set_and_current
@@ -317,13 +303,10 @@ fn part_2(contents: &str) -> u64 {
                         (next_you_valve.to_owned(), next_elephant_valve.to_owned()),
                         vec_opened,
                     );
-                    match best_per_valve_set_and_current.get(&key) {
-                        Some(flow_rate) => {
-                            if flow_rate >= &route.flow_rate {
-                                continue;
-                            }
+                    if let Some(flow_rate) = best_per_valve_set_and_current.get(&key) {
+                        if flow_rate >= &route.flow_rate {
+                            continue;
                         }
-                        _ => {}
                     }
 
                     best_per_valve_set_and_current
@@ -342,17 +325,10 @@ fn part_2(contents: &str) -> u64 {
 
         current_minutes += 1;
         if current_minutes > 0 {
-            let num_routes = next_routes.len();
-            let num_stored = best_per_valve_set_and_current.len();
-            println!("MINUTES {current_minutes}, num routes {num_routes} num stored {num_stored}");
-
             // get the current best flow rate
-            let current_best = best_per_valve_set_and_current
-                .iter()
-                .map(|(_, v)| *v)
+            let current_best = best_per_valve_set_and_current.values().copied()
                 .max()
                 .unwrap() as f64;
-            println!("{current_best}");
 
             // heuristic to truncate the routes we'll consider next minute
             // make bound tighter over time
@@ -364,49 +340,44 @@ fn part_2(contents: &str) -> u64 {
                 1.1
             };
             if current_best > 0.0 {
-                next_routes = next_routes
-                    .into_iter()
-                    .filter(|x| (x.flow_rate as f64) > current_best / factor)
-                    .collect();
+                next_routes.retain(|x| (x.flow_rate as f64) > current_best / factor);
             }
         }
 
-        if next_routes.len() == 0 {
+        if next_routes.is_empty() {
             break;
         };
         routes = next_routes;
     }
 
-    best_per_valve_set_and_current
-        .iter()
-        .map(|(_, v)| *v)
+    best_per_valve_set_and_current.values().copied()
         .max()
         .unwrap()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 16;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 1651);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 1707);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    //let part_1_answer = part_1(contents);
-    //println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,5 +1,9 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::{Point2D, SparseGrid};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashSet;
-use std::time::Instant;
 
 #[derive(Debug)]
 enum Status {
@@ -8,49 +12,7 @@ enum Status {
     MOVE_OK,
 }
 
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct Point {
-    x: i128,
-    y: i128,
-}
-
-impl Point {
-    fn new(x: i128, y: i128) -> Self {
-        Point { x, y }
-    }
-
-    fn down(&mut self) -> Status {
-        if self.y > 0 {
-            self.y -= 1;
-            Status::MOVE_OK
-        } else {
-            Status::FLOOR
-        }
-    }
-
-    fn up(&mut self) -> Status {
-        self.y += 1;
-        Status::MOVE_OK
-    }
-
-    fn left(&mut self) -> Status {
-        if self.x > 0 {
-            self.x -= 1;
-            Status::MOVE_OK
-        } else {
-            Status::WALL
-        }
-    }
-
-    fn right(&mut self) -> Status {
-        if self.x < 6 {
-            self.x += 1;
-            Status::MOVE_OK
-        } else {
-            Status::WALL
-        }
-    }
-}
+type Point = Point2D<i128>;
 
 #[derive(Debug)]
 enum BlockType {
@@ -63,13 +25,14 @@ enum BlockType {
 
 #[derive(Debug)]
 struct Block {
+    #[allow(dead_code)]
     block_type: BlockType,
     points: Vec<Point>,
 }
 
 impl Block {
     fn bottom_at_height_for_index(height: i128, index: usize) -> Self {
-        if index % 5 == 0 {
+        if index.is_multiple_of(5) {
             Block {
                 block_type: BlockType::Horizontal,
                 points: vec![
@@ -124,56 +87,34 @@ impl Block {
         }
     }
 
-    fn down(&mut self) -> Status {
-        let mut new_points: Vec<Point> = Vec::new();
-        for point in self.points.iter() {
-            let mut new_point = point.clone();
-            match new_point.down() {
-                Status::MOVE_OK => new_points.push(new_point),
-                other_status => return other_status,
-            };
+    /// Moves every point by `step` unless one would end up below the floor or
+    /// outside the walls.
+    fn shift(&mut self, step: Point) -> Status {
+        let new_points: Vec<Point> = self.points.iter().map(|&point| point + step).collect();
+        if new_points.iter().any(|point| point.y < 0) {
+            Status::FLOOR
+        } else if new_points.iter().any(|point| !(0..7).contains(&point.x)) {
+            Status::WALL
+        } else {
+            self.points = new_points;
+            Status::MOVE_OK
         }
-        self.points = new_points;
-        Status::MOVE_OK
+    }
+
+    fn down(&mut self) -> Status {
+        self.shift(Point::new(0, -1))
     }
 
     fn up(&mut self) -> Status {
-        let mut new_points: Vec<Point> = Vec::new();
-        for point in self.points.iter() {
-            let mut new_point = point.clone();
-            match new_point.up() {
-                Status::MOVE_OK => new_points.push(new_point),
-                other_status => return other_status,
-            };
-        }
-        self.points = new_points;
-        Status::MOVE_OK
+        self.shift(Point::new(0, 1))
     }
 
     fn left(&mut self) -> Status {
-        let mut new_points: Vec<Point> = Vec::new();
-        for point in self.points.iter() {
-            let mut new_point = point.clone();
-            match new_point.left() {
-                Status::MOVE_OK => new_points.push(new_point),
-                other_status => return other_status,
-            };
-        }
-        self.points = new_points;
-        Status::MOVE_OK
+        self.shift(Point::new(-1, 0))
     }
 
     fn right(&mut self) -> Status {
-        let mut new_points: Vec<Point> = Vec::new();
-        for point in self.points.iter() {
-            let mut new_point = point.clone();
-            match new_point.right() {
-                Status::MOVE_OK => new_points.push(new_point),
-                other_status => return other_status,
-            };
-        }
-        self.points = new_points;
-        Status::MOVE_OK
+        self.shift(Point::new(1, 0))
     }
 
     fn descending_y_coords(&self) -> Vec<i128> {
@@ -181,53 +122,33 @@ impl Block {
         for point in self.points.iter() {
             y_coords.insert(point.y);
         }
-        let mut y_coords_vec = Vec::from_iter(y_coords.into_iter());
+        let mut y_coords_vec = Vec::from_iter(y_coords);
         // sort descending
         y_coords_vec.sort_by(|a, b| b.cmp(a));
         y_coords_vec
     }
 }
 
-fn insert_points(points: &Vec<Point>, rocks: &mut HashSet<Point>, highest_rock: &mut i128) {
+fn insert_points(points: &[Point], rocks: &mut SparseGrid<i128>) {
     for point in points.iter() {
-        if point.y > *highest_rock {
-            *highest_rock = point.y;
-        }
-        rocks.insert(*point);
+        rocks.add(*point);
     }
 }
 
-fn print_rocks(rocks: &HashSet<Point>, block: &Block, highest_rock: &i128) {
-    let mut rocks_fmt: Vec<Vec<char>> = Vec::new();
-
-    // 10 buffer needed for block height + start above current highest block
-    let num_rows = (*highest_rock + 10).max(10);
-    for _ in 0..num_rows {
-        rocks_fmt.push(vec!['.'; 7]);
-    }
-    for rock in rocks.iter() {
-        rocks_fmt[rock.y as usize][rock.x as usize] = '#'
-    }
-    for point in block.points.iter() {
-        rocks_fmt[point.y as usize][point.x as usize] = '@'
-    }
-    for row in rocks_fmt.iter().rev() {
-        for char in row.iter() {
-            print!("{char}");
-        }
-        print!("\n");
-    }
+/// The `y` of the top of the highest rock, or -1 before any have landed.
+fn highest_rock(rocks: &SparseGrid<i128>) -> i128 {
+    rocks.bounds().map_or(-1, |bounds| bounds.max[1])
 }
 
-fn clear_rocks_below_filled_line(rocks: &mut HashSet<Point>, block: &Block) -> Option<i128> {
+fn clear_rocks_below_filled_line(rocks: &mut SparseGrid<i128>, block: &Block) -> Option<i128> {
     for y in block.descending_y_coords() {
         let mut row_points: Vec<Point> = Vec::new();
         for x in 0..7 {
             row_points.push(Point::new(x, y))
         }
 
-        if row_points.iter().all(|p| rocks.contains(&p)) {
-            let to_remove: Vec<Point> = rocks.iter().filter(|p| p.y < y).cloned().collect();
+        if row_points.iter().all(|p| rocks.contains(p)) {
+            let to_remove: Vec<Point> = rocks.points().filter(|p| p.y < y).cloned().collect();
             for rock in to_remove {
                 rocks.remove(&rock);
             }
@@ -238,13 +159,12 @@ fn clear_rocks_below_filled_line(rocks: &mut HashSet<Point>, block: &Block) -> O
 }
 
 fn tetris(contents: &str, rock_index_to_stop_after: usize) -> i128 {
-    let cleaned_contents = contents.replace(" \n", "");
-    let jet_cycle_repeat = cleaned_contents.len();
-    let mut highest_rock = -1;
-    let mut rocks: HashSet<Point> = HashSet::new();
+    let cleaned_contents = contents.trim();
+    let _jet_cycle_repeat = cleaned_contents.len();
+    let mut rocks: SparseGrid<i128> = SparseGrid::new();
     let mut rock_index = 0;
-    let mut block = Block::bottom_at_height_for_index(highest_rock + 4, rock_index);
-    for (step, jet) in cleaned_contents.chars().cycle().enumerate() {
+    let mut block = Block::bottom_at_height_for_index(highest_rock(&rocks) + 4, rock_index);
+    for jet in cleaned_contents.chars().cycle() {
         if rock_index == rock_index_to_stop_after {
             break;
         }
@@ -275,28 +195,27 @@ fn tetris(contents: &str, rock_index_to_stop_after: usize) -> i128 {
             Status::MOVE_OK => {
                 if block.points.iter().any(|p| rocks.contains(p)) {
                     block.up();
-                    insert_points(&block.points, &mut rocks, &mut highest_rock);
+                    insert_points(&block.points, &mut rocks);
                     rock_index += 1;
                     clear_rocks_below_filled_line(&mut rocks, &block);
-                    block = Block::bottom_at_height_for_index(highest_rock + 4, rock_index);
+                    block = Block::bottom_at_height_for_index(highest_rock(&rocks) + 4, rock_index);
                     continue;
                 }
             }
             Status::FLOOR => {
-                insert_points(&block.points, &mut rocks, &mut highest_rock);
+                insert_points(&block.points, &mut rocks);
                 clear_rocks_below_filled_line(&mut rocks, &block);
                 rock_index += 1;
-                block = Block::bottom_at_height_for_index(highest_rock + 4, rock_index);
+                block = Block::bottom_at_height_for_index(highest_rock(&rocks) + 4, rock_index);
                 continue;
             }
             Status::WALL => panic!("Moving down should not hit a wall!"),
         }
     }
-    //print_rocks(&rocks, &block, &highest_rock);
-    highest_rock + 1
+    highest_rock(&rocks) + 1
 }
 
-fn part_2(contents: &str) -> usize {
+fn part_2(_contents: &str) -> usize {
     let blocks_to_stop_after: usize = 1_000_000_000_000;
 
     /* Example
@@ -328,24 +247,29 @@ fn part_2(contents: &str) -> usize {
     pre_repeat_rows + res.0 * rows_per_repeat + 1637 - 340
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 17;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(tetris(input, 2022).into())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(tetris(include_str!("./example.txt"), 20220), 3068);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = tetris(contents, 2022);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
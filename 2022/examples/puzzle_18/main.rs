@@ -1,128 +1,18 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, PuzzleError};
+use aoc_utils::geometry::{BoundingBox, Point3D};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Part, Puzzle, Variant};
 use parse_display::{Display, FromStr};
 use std::collections::HashSet;
-use std::time::Instant;
 
-#[derive(Clone, Copy, Debug, Display, FromStr, PartialEq, Eq, Hash)]
-#[display(r"{x},{y},{z}")]
-struct Cube {
-    x: i8,
-    y: i8,
-    z: i8,
-}
-
-impl Cube {
-    fn manhattan_distance(&self, other: &Cube) -> u8 {
-        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
-    }
-
-    fn get_adjacent_face(&self, other: &Cube) -> Face {
-        // assume that manhattan distance is 1
-        if self.x - other.x == 1 {
-            Face {
-                min_x: self.x,
-                max_x: self.x,
-                min_y: self.y,
-                max_y: self.y + 1,
-                min_z: self.z,
-                max_z: self.z + 1,
-            }
-        } else if self.x - other.x == -1 {
-            Face {
-                min_x: self.x + 1,
-                max_x: self.x + 1,
-                min_y: self.y,
-                max_y: self.y + 1,
-                min_z: self.z,
-                max_z: self.z + 1,
-            }
-        } else if self.y - other.y == 1 {
-            Face {
-                min_x: self.x,
-                max_x: self.x + 1,
-                min_y: self.y,
-                max_y: self.y,
-                min_z: self.z,
-                max_z: self.z + 1,
-            }
-        } else if self.y - other.y == -1 {
-            Face {
-                min_x: self.x,
-                max_x: self.x + 1,
-                min_y: self.y + 1,
-                max_y: self.y + 1,
-                min_z: self.z,
-                max_z: self.z + 1,
-            }
-        } else if self.z - other.z == 1 {
-            Face {
-                min_x: self.x,
-                max_x: self.x + 1,
-                min_y: self.y,
-                max_y: self.y + 1,
-                min_z: self.z,
-                max_z: self.z,
-            }
-        } else if self.z - other.z == -1 {
-            Face {
-                min_x: self.x,
-                max_x: self.x + 1,
-                min_y: self.y,
-                max_y: self.y + 1,
-                min_z: self.z + 1,
-                max_z: self.z + 1,
-            }
-        } else {
-            panic!("Manhattan distance was not 1!")
-        }
-    }
+type Cube = Point3D<i8>;
 
-    fn adjacent_cubes(&self) -> Vec<Self> {
-        vec![
-            Cube {
-                x: self.x + 1,
-                y: self.y,
-                z: self.z,
-            },
-            Cube {
-                x: self.x - 1,
-                y: self.y,
-                z: self.z,
-            },
-            Cube {
-                x: self.x,
-                y: self.y + 1,
-                z: self.z,
-            },
-            Cube {
-                x: self.x,
-                y: self.y - 1,
-                z: self.z,
-            },
-            Cube {
-                x: self.x,
-                y: self.y,
-                z: self.z + 1,
-            },
-            Cube {
-                x: self.x,
-                y: self.y,
-                z: self.z - 1,
-            },
-        ]
-    }
+fn parse_cubes(contents: &str) -> Result<Vec<Cube>, PuzzleError> {
+    parse_lines(contents, |line| line.parse())
 }
 
-fn parse_cubes(contents: &str) -> Vec<Cube> {
-    let mut cubes: Vec<Cube> = Vec::new();
-    for line in contents.lines() {
-        let cube = line.parse().unwrap();
-        cubes.push(cube);
-    }
-    cubes
-}
-
-fn part_1(contents: &str) -> u64 {
-    let cubes = parse_cubes(contents);
+fn part_1(cubes: &[Cube]) -> u64 {
     let mut answer = 0;
     for cube in cubes.iter() {
         let mut num_covered = 0;
@@ -136,6 +26,8 @@ fn part_1(contents: &str) -> u64 {
     answer
 }
 
+type Line = ((i8, i8, i8), (i8, i8, i8));
+
 #[derive(Clone, Copy, Debug, Display, FromStr, PartialEq, Eq, Hash)]
 #[display(r"{min_x},{max_x},{min_y},{max_y},{min_z},{max_z}")]
 struct Face {
@@ -148,8 +40,70 @@ struct Face {
 }
 
 impl Face {
-    fn bounding_lines(&self) -> HashSet<((i8, i8, i8), (i8, i8, i8))> {
-        let mut lines: HashSet<((i8, i8, i8), (i8, i8, i8))> = HashSet::new();
+    /// The face shared by two cubes which are next to each other.
+    fn between(cube: &Cube, other: &Cube) -> Self {
+        // assume that manhattan distance is 1
+        if cube[0] - other[0] == 1 {
+            Face {
+                min_x: cube[0],
+                max_x: cube[0],
+                min_y: cube[1],
+                max_y: cube[1] + 1,
+                min_z: cube[2],
+                max_z: cube[2] + 1,
+            }
+        } else if cube[0] - other[0] == -1 {
+            Face {
+                min_x: cube[0] + 1,
+                max_x: cube[0] + 1,
+                min_y: cube[1],
+                max_y: cube[1] + 1,
+                min_z: cube[2],
+                max_z: cube[2] + 1,
+            }
+        } else if cube[1] - other[1] == 1 {
+            Face {
+                min_x: cube[0],
+                max_x: cube[0] + 1,
+                min_y: cube[1],
+                max_y: cube[1],
+                min_z: cube[2],
+                max_z: cube[2] + 1,
+            }
+        } else if cube[1] - other[1] == -1 {
+            Face {
+                min_x: cube[0],
+                max_x: cube[0] + 1,
+                min_y: cube[1] + 1,
+                max_y: cube[1] + 1,
+                min_z: cube[2],
+                max_z: cube[2] + 1,
+            }
+        } else if cube[2] - other[2] == 1 {
+            Face {
+                min_x: cube[0],
+                max_x: cube[0] + 1,
+                min_y: cube[1],
+                max_y: cube[1] + 1,
+                min_z: cube[2],
+                max_z: cube[2],
+            }
+        } else if cube[2] - other[2] == -1 {
+            Face {
+                min_x: cube[0],
+                max_x: cube[0] + 1,
+                min_y: cube[1],
+                max_y: cube[1] + 1,
+                min_z: cube[2] + 1,
+                max_z: cube[2] + 1,
+            }
+        } else {
+            panic!("Manhattan distance was not 1!")
+        }
+    }
+
+    fn bounding_lines(&self) -> HashSet<Line> {
+        let mut lines: HashSet<Line> = HashSet::new();
         if self.min_x == self.max_x {
             lines.insert((
                 (self.min_x, self.min_y, self.min_z),
@@ -215,69 +169,68 @@ impl Face {
     fn faces_from_cube(cube: &Cube) -> HashSet<Self> {
         HashSet::from_iter(vec![
             Face {
-                min_x: cube.x,
-                max_x: cube.x + 1,
-                min_y: cube.y,
-                max_y: cube.y + 1,
-                min_z: cube.z,
-                max_z: cube.z,
+                min_x: cube[0],
+                max_x: cube[0] + 1,
+                min_y: cube[1],
+                max_y: cube[1] + 1,
+                min_z: cube[2],
+                max_z: cube[2],
             },
             Face {
-                min_x: cube.x,
-                max_x: cube.x + 1,
-                min_y: cube.y,
-                max_y: cube.y + 1,
-                min_z: cube.z + 1,
-                max_z: cube.z + 1,
+                min_x: cube[0],
+                max_x: cube[0] + 1,
+                min_y: cube[1],
+                max_y: cube[1] + 1,
+                min_z: cube[2] + 1,
+                max_z: cube[2] + 1,
             },
             Face {
-                min_x: cube.x,
-                max_x: cube.x,
-                min_y: cube.y,
-                max_y: cube.y + 1,
-                min_z: cube.z,
-                max_z: cube.z + 1,
+                min_x: cube[0],
+                max_x: cube[0],
+                min_y: cube[1],
+                max_y: cube[1] + 1,
+                min_z: cube[2],
+                max_z: cube[2] + 1,
             },
             Face {
-                min_x: cube.x + 1,
-                max_x: cube.x + 1,
-                min_y: cube.y,
-                max_y: cube.y + 1,
-                min_z: cube.z,
-                max_z: cube.z + 1,
+                min_x: cube[0] + 1,
+                max_x: cube[0] + 1,
+                min_y: cube[1],
+                max_y: cube[1] + 1,
+                min_z: cube[2],
+                max_z: cube[2] + 1,
             },
             Face {
-                min_x: cube.x,
-                max_x: cube.x + 1,
-                min_y: cube.y,
-                max_y: cube.y,
-                min_z: cube.z,
-                max_z: cube.z + 1,
+                min_x: cube[0],
+                max_x: cube[0] + 1,
+                min_y: cube[1],
+                max_y: cube[1],
+                min_z: cube[2],
+                max_z: cube[2] + 1,
             },
             Face {
-                min_x: cube.x,
-                max_x: cube.x + 1,
-                min_y: cube.y + 1,
-                max_y: cube.y + 1,
-                min_z: cube.z,
-                max_z: cube.z + 1,
+                min_x: cube[0],
+                max_x: cube[0] + 1,
+                min_y: cube[1] + 1,
+                max_y: cube[1] + 1,
+                min_z: cube[2],
+                max_z: cube[2] + 1,
             },
         ])
     }
 }
 
-fn part_2_no_diagonals(contents: &str) -> usize {
+fn part_2_no_diagonals(cubes: &[Cube]) -> usize {
     /* Interior cubes do not have to be completely surrounded, they can
        have 'diagonal' adjacent cubes which this algorithm fails to deal
        with.
     */
-    let cubes = parse_cubes(contents);
     let mut faces: HashSet<Face> = HashSet::new();
     for cube in cubes.iter() {
         let mut uncovered_cube_faces: HashSet<Face> = Face::faces_from_cube(cube);
         for other_cube in cubes.iter() {
             if cube.manhattan_distance(other_cube) == 1 {
-                let covered_face = cube.get_adjacent_face(other_cube);
+                let covered_face = Face::between(cube, other_cube);
                 uncovered_cube_faces.remove(&covered_face);
             }
         }
@@ -300,7 +253,7 @@ fn part_2_no_diagonals(contents: &str) -> usize {
         }
 
         // create new component, or merge existing ones
-        if components_to_merge.len() == 0 {
+        if components_to_merge.is_empty() {
             connected_components.push(vec![face]);
         } else {
             let mut new_connected_components: Vec<Vec<&Face>> = Vec::new();
@@ -322,14 +275,14 @@ fn part_2_no_diagonals(contents: &str) -> usize {
     connected_components.iter().map(|v| v.len()).max().unwrap()
 }
 
-fn part_2_with_faces(contents: &str) -> u64 {
-    let cubes: HashSet<Cube> = HashSet::from_iter(parse_cubes(contents));
+fn part_2_with_faces(cubes: &[Cube]) -> u64 {
+    let cubes: HashSet<Cube> = cubes.iter().copied().collect();
     let mut faces: HashSet<Face> = HashSet::new();
     for cube in cubes.iter() {
         let mut uncovered_cube_faces: HashSet<Face> = Face::faces_from_cube(cube);
         for other_cube in cubes.iter() {
             if cube.manhattan_distance(other_cube) == 1 {
-                let covered_face = cube.get_adjacent_face(other_cube);
+                let covered_face = Face::between(cube, other_cube);
                 uncovered_cube_faces.remove(&covered_face);
             }
         }
@@ -348,91 +301,106 @@ fn part_2_with_faces(contents: &str) -> u64 {
     */
 
     let mut answer = 0;
-    // dumb search space that only works for small cube-like areas
-    let min_bound = cubes.iter().map(|c| c.x.min(c.y.min(c.z))).min().unwrap() - 2;
-    let max_bound = cubes.iter().map(|c| c.x.max(c.y.max(c.z))).max().unwrap() + 2;
-
-    let mut walking_cubes = vec![Cube {
-        x: min_bound,
-        y: min_bound,
-        z: min_bound,
-    }];
+    // leave room to walk all the way around the outside
+    let bounds = BoundingBox::from_points(cubes.iter().copied()).unwrap().expanded(1);
+
+    let mut walking_cubes = vec![bounds.min];
     let mut visited_cubes: HashSet<Cube> = HashSet::new();
 
-    while walking_cubes.len() > 0 {
-        let next_cube = walking_cubes.pop().unwrap();
+    while let Some(next_cube) = walking_cubes.pop() {
 
-        if cubes.contains(&next_cube) || visited_cubes.contains(&next_cube) {
-            continue;
-        } else if next_cube.x < min_bound
-            || next_cube.x > max_bound
-            || next_cube.y < min_bound
-            || next_cube.y > max_bound
-            || next_cube.z < min_bound
-            || next_cube.z > max_bound
+        if cubes.contains(&next_cube)
+            || visited_cubes.contains(&next_cube)
+            || !bounds.contains(&next_cube)
         {
-            // out of bounds
             continue;
         }
         for face in Face::faces_from_cube(&next_cube).iter() {
-            if faces.contains(&face) {
+            if faces.contains(face) {
                 answer += 1;
             }
         }
         visited_cubes.insert(next_cube);
 
-        for cube in next_cube.adjacent_cubes().into_iter() {
+        for cube in next_cube.face_neighbours().into_iter() {
             walking_cubes.push(cube);
         }
     }
     answer
 }
 
-fn part_2(contents: &str) -> u64 {
-    let cubes: HashSet<Cube> = HashSet::from_iter(parse_cubes(contents));
+fn part_2(cubes: &[Cube]) -> u64 {
+    let cubes: HashSet<Cube> = cubes.iter().copied().collect();
 
     let mut answer = 0;
-    // dumb search space that only works for small cube-like areas
-    let min_bound = cubes.iter().map(|c| c.x.min(c.y.min(c.z))).min().unwrap() - 2;
-    let max_bound = cubes.iter().map(|c| c.x.max(c.y.max(c.z))).max().unwrap() + 2;
-
-    let mut walking_cubes = vec![Cube {
-        x: min_bound,
-        y: min_bound,
-        z: min_bound,
-    }];
+    // leave room to walk all the way around the outside
+    let bounds = BoundingBox::from_points(cubes.iter().copied()).unwrap().expanded(1);
+
+    let mut walking_cubes = vec![bounds.min];
     let mut visited_cubes: HashSet<Cube> = HashSet::new();
 
-    while walking_cubes.len() > 0 {
-        let next_cube = walking_cubes.pop().unwrap();
+    while let Some(next_cube) = walking_cubes.pop() {
 
         if visited_cubes.contains(&next_cube) {
             continue;
         } else if cubes.contains(&next_cube) {
             answer += 1;
             continue;
-        } else if next_cube.x < min_bound
-            || next_cube.x > max_bound
-            || next_cube.y < min_bound
-            || next_cube.y > max_bound
-            || next_cube.z < min_bound
-            || next_cube.z > max_bound
-        {
-            // out of bounds
+        } else if !bounds.contains(&next_cube) {
             continue;
         }
         visited_cubes.insert(next_cube);
 
-        for cube in next_cube.adjacent_cubes().into_iter() {
+        for cube in next_cube.face_neighbours().into_iter() {
             walking_cubes.push(cube);
         }
     }
     answer
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 18;
+
+    type Params = NoParams;
+    type Parsed = Vec<Cube>;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<Vec<Cube>, PuzzleError> {
+        parse_cubes(input)
+    }
+
+    fn part_1(&self, cubes: &Vec<Cube>) -> Result<Answer, PuzzleError> {
+        Ok(part_1(cubes).into())
+    }
+
+    fn part_2(&self, cubes: &Vec<Cube>) -> Result<Answer, PuzzleError> {
+        Ok(part_2(cubes).into())
+    }
+
+    fn variants(&self, part: Part) -> Vec<Variant<Vec<Cube>>> {
+        match part {
+            Part::One => vec![],
+            Part::Two => vec![
+                Variant::new("part_2_with_faces", |cubes| Ok(part_2_with_faces(cubes).into())),
+                // only correct when no interior pockets touch the outside diagonally
+                Variant::unchecked("part_2_no_diagonals", |cubes| {
+                    Ok(part_2_no_diagonals(cubes).into())
+                }),
+            ],
+        }
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::input::load_example;
     use rstest::*;
 
     #[rstest]
@@ -461,55 +429,24 @@ mod tests {
         let c_1: Cube = cube_1.parse().unwrap();
         let c_2: Cube = cube_2.parse().unwrap();
         let f: Face = face.parse().unwrap();
-        assert_eq!(c_1.get_adjacent_face(&c_2), f);
+        assert_eq!(Face::between(&c_1, &c_2), f);
     }
 
     #[test]
     fn test_part_1_small_example() {
-        assert_eq!(part_1("1,1,1\n2,1,1"), 10);
-    }
-
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 64);
+        assert_eq!(part_1(&parse_cubes("1,1,1\n2,1,1").unwrap()), 10);
     }
 
     #[test]
     fn test_part_2_non_diagonal_example_connected_component_algorithm() {
+        let Some(non_diagonal_example) = load_example(2022, 18, "non_diagonal_example.txt") else {
+            return;
+        };
         // single cube completely enclosed, no diagonal case (easy!)
         // connected components approach works here
         assert_eq!(
-            part_2_no_diagonals(include_str!("./non_diagonal_example.txt")),
+            part_2_no_diagonals(&parse_cubes(&non_diagonal_example).unwrap()),
             6 * 9
         );
     }
-
-    #[test]
-    fn test_part_2_non_diagonal_example() {
-        // single cube completely enclosed, no diagonal case (easy!)
-        assert_eq!(part_2(include_str!("./non_diagonal_example.txt")), 6 * 9);
-    }
-
-    #[test]
-    fn test_part_2_minimal_example() {
-        // 1 interior square, 6 exterior cubes attached to each face
-        // 6 faces should be interior, 6 * 5 exposed
-        assert_eq!(part_2(include_str!("./minimal_example.txt")), 30);
-    }
-
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 58);
-    }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
@@ -1,6 +1,9 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use parse_display::{Display, FromStr};
 use std::collections::{HashMap, HashSet};
-use std::time::Instant;
 
 enum Robot {
     ORE,
@@ -68,11 +71,9 @@ impl MiningState {
     /// Weighted score for mining state using blueprint costs
     fn heuristic_score(&self, blueprint: &Blueprint) -> i64 {
         // never want to build more robots of type x than the most expensive cost of type x
-        let ore_costs = vec![
-            blueprint.clay_robot_ore_cost,
+        let ore_costs = [blueprint.clay_robot_ore_cost,
             blueprint.obsidian_robot_ore_cost,
-            blueprint.geode_robot_ore_cost,
-        ];
+            blueprint.geode_robot_ore_cost];
         let max_ore_cost = ore_costs.iter().max().unwrap();
         if self.num_ore_robots > *max_ore_cost {
             return i64::MIN;
@@ -127,7 +128,7 @@ fn simulate(blueprint: Blueprint, total_minutes: u8) -> (MiningState, u8) {
 
         for state in states {
             if state.ore >= blueprint.ore_robot_ore_cost {
-                let mut new_state = state.clone();
+                let mut new_state = state;
                 new_state.num_ore_robots += 1;
                 new_state.ore -= blueprint.ore_robot_ore_cost;
                 new_state.produce(Some(Robot::ORE));
@@ -138,7 +139,7 @@ fn simulate(blueprint: Blueprint, total_minutes: u8) -> (MiningState, u8) {
                 );
             }
             if state.ore >= blueprint.clay_robot_ore_cost {
-                let mut new_state = state.clone();
+                let mut new_state = state;
                 new_state.num_clay_robots += 1;
                 new_state.ore -= blueprint.clay_robot_ore_cost;
                 new_state.produce(Some(Robot::CLAY));
@@ -151,7 +152,7 @@ fn simulate(blueprint: Blueprint, total_minutes: u8) -> (MiningState, u8) {
             if state.ore >= blueprint.obsidian_robot_ore_cost
                 && state.clay >= blueprint.obsidian_robot_clay_cost
             {
-                let mut new_state = state.clone();
+                let mut new_state = state;
                 new_state.num_obsidian_robots += 1;
                 new_state.ore -= blueprint.obsidian_robot_ore_cost;
                 new_state.clay -= blueprint.obsidian_robot_clay_cost;
@@ -165,7 +166,7 @@ fn simulate(blueprint: Blueprint, total_minutes: u8) -> (MiningState, u8) {
             if state.ore >= blueprint.geode_robot_ore_cost
                 && state.obsidian >= blueprint.geode_robot_obsidian_cost
             {
-                let mut new_state = state.clone();
+                let mut new_state = state;
                 new_state.num_geode_robots += 1;
                 new_state.ore -= blueprint.geode_robot_ore_cost;
                 new_state.obsidian -= blueprint.geode_robot_obsidian_cost;
@@ -177,7 +178,7 @@ fn simulate(blueprint: Blueprint, total_minutes: u8) -> (MiningState, u8) {
                 );
             }
             // make nothing case
-            let mut new_state = state.clone();
+            let mut new_state = state;
             new_state.produce(None);
             update_geode_per_state_cache(&mut most_geodes_per_state, &new_state, &mut next_states);
         }
@@ -193,7 +194,7 @@ fn simulate(blueprint: Blueprint, total_minutes: u8) -> (MiningState, u8) {
                 .into_iter()
                 .map(|s| (s.heuristic_score(&blueprint), s))
                 .collect::<Vec<(i64, MiningState)>>();
-            scored_states.sort_by(|a, b| b.0.cmp(&a.0));
+            scored_states.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
             for (_, state) in scored_states.into_iter().take(sample_size) {
                 states.insert(state);
             }
@@ -216,13 +217,8 @@ fn part_1(contents: &str) -> u64 {
 
     let mut answer = 0;
     for blueprint in blueprints {
-        let (state, geodes) = simulate(blueprint, 24);
+        let (_, geodes) = simulate(blueprint, 24);
         let quality_level = (geodes as u64) * (blueprint.index as u64);
-        println!(
-            "Blueprint {} has quality level {} for state {:?}",
-            blueprint.index, quality_level, state
-        );
-        println!("");
         answer += quality_level;
     }
     answer
@@ -237,35 +233,35 @@ fn part_2(contents: &str) -> u64 {
 
     let mut answer: u64 = 1;
     for blueprint in blueprints {
-        let (state, geodes) = simulate(blueprint, 32);
-        println!(
-            "Blueprint {} has best output state {:?}",
-            blueprint.index, state
-        );
-        println!("");
+        let (_, geodes) = simulate(blueprint, 32);
         answer *= geodes as u64;
     }
     answer
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 33);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 19;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 #[derive(PartialEq, Clone)]
 enum Move {
@@ -38,24 +41,28 @@ impl Points for Outcome {
     }
 }
 
-impl From<&str> for Move {
-    fn from(s: &str) -> Self {
+impl TryFrom<&str> for Move {
+    type Error = PuzzleError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s {
-            "A" | "X" => Move::ROCK,
-            "B" | "Y" => Move::PAPER,
-            "C" | "Z" => Move::SCISSORS,
-            _ => panic!("Could not convert string to Move"),
+            "A" | "X" => Ok(Move::ROCK),
+            "B" | "Y" => Ok(Move::PAPER),
+            "C" | "Z" => Ok(Move::SCISSORS),
+            _ => Err(PuzzleError::malformed(format!("Unexpected move {s:?}"))),
         }
     }
 }
 
-impl From<&str> for Outcome {
-    fn from(s: &str) -> Self {
+impl TryFrom<&str> for Outcome {
+    type Error = PuzzleError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s {
-            "X" => Outcome::LOSE,
-            "Y" => Outcome::DRAW,
-            "Z" => Outcome::WIN,
-            _ => panic!("Could not convert string to Outcome"),
+            "X" => Ok(Outcome::LOSE),
+            "Y" => Ok(Outcome::DRAW),
+            "Z" => Ok(Outcome::WIN),
+            _ => Err(PuzzleError::malformed(format!("Unexpected outcome {s:?}"))),
         }
     }
 }
@@ -86,53 +93,58 @@ impl Move {
     }
 }
 
-fn part_1(contents: &str) -> u64 {
-    let mut points: u64 = 0;
-    for line in contents.lines() {
-        let moves = line.split_whitespace().collect::<Vec<&str>>();
-        let their_move = Move::from(moves[0]);
-        let my_move = Move::from(moves[1]);
-        points += my_move.points();
-        points += my_move.compare(&their_move).points();
-    }
-    points
+/// The two columns of a line of the strategy guide, along with the column
+/// the second starts at.
+fn split_round(line: &str) -> Result<(&str, &str, usize), PuzzleError> {
+    let (first, second) = line
+        .split_once(' ')
+        .ok_or_else(|| PuzzleError::malformed("Expected two columns separated by a space"))?;
+    Ok((first, second, first.len() + 1))
 }
 
-fn part_2(contents: &str) -> u64 {
-    let mut points: u64 = 0;
-    for line in contents.lines() {
-        let moves = line.split_whitespace().collect::<Vec<&str>>();
-        let their_move = Move::from(moves[0]);
-        let outcome = Outcome::from(moves[1]);
-        points += outcome.points();
-        points += their_move.move_for_outcome(&outcome).points();
-    }
-    points
+fn part_1(contents: &str) -> Result<u64, PuzzleError> {
+    let points = parse_lines(contents, |line| {
+        let (theirs, mine, column) = split_round(line)?;
+        let their_move = Move::try_from(theirs)?;
+        let my_move = Move::try_from(mine).map_err(|e| e.at_column(column))?;
+        Ok(my_move.points() + my_move.compare(&their_move).points())
+    })?;
+    Ok(points.iter().sum())
+}
+
+fn part_2(contents: &str) -> Result<u64, PuzzleError> {
+    let points = parse_lines(contents, |line| {
+        let (theirs, outcome, column) = split_round(line)?;
+        let their_move = Move::try_from(theirs)?;
+        let outcome = Outcome::try_from(outcome).map_err(|e| e.at_column(column))?;
+        Ok(outcome.points() + their_move.move_for_outcome(&outcome).points())
+    })?;
+    Ok(points.iter().sum())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 15);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 12);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
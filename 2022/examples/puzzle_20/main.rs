@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashMap;
-use std::time::Instant;
 
 #[derive(Debug, Clone, Copy)]
 struct FileNumber {
@@ -22,7 +25,7 @@ fn parse_input(contents: &str) -> HashMap<usize, FileNumber> {
 }
 
 fn get_zero_index(numbers: &HashMap<usize, FileNumber>) -> usize {
-    for (index, number) in numbers.iter() {
+    for (_index, number) in numbers.iter() {
         if number.value == 0 {
             return number.current_index;
         }
@@ -44,6 +47,7 @@ fn get_answer(zero_index: usize, numbers: &HashMap<usize, FileNumber>) -> i128 {
     answer
 }
 
+#[allow(dead_code)]
 fn create_vector(numbers: &HashMap<usize, FileNumber>) -> Vec<i128> {
     let mut vec: Vec<i128> = vec![0; numbers.len()];
     for (_, number) in numbers.iter() {
@@ -71,7 +75,7 @@ fn process(
                     .rem_euclid((length - 1) as i128) as usize;
 
             for (other_start_index, number) in numbers.iter() {
-                let mut new_current_index = number.current_index.clone();
+                let mut new_current_index = number.current_index;
                 if start_index == *other_start_index {
                     new_current_index = number_to_shift_new_index;
                 } else {
@@ -83,7 +87,7 @@ fn process(
                         new_current_index += 1;
                     }
                 }
-                new_current_index = new_current_index % length;
+                new_current_index %= length;
 
                 new_numbers.insert(
                     *other_start_index,
@@ -116,6 +120,33 @@ fn part_2(contents: &str) -> i128 {
     get_answer(zero_index, &numbers)
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 20;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,11 +163,6 @@ mod tests {
         assert_eq!(answer, expected);
     }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 3);
-    }
-
     #[rstest]
     #[case("-1\n-2\n-3\n0", vec![-3, -2, -1, 0])]
     #[case("1\n2\n-3\n0", vec![1, -3, 2, 0])]
@@ -152,20 +178,4 @@ mod tests {
         let numbers = process(input_numbers, 1);
         assert_eq!(create_vector(&numbers), expected);
     }
-
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 1623178306);
-    }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
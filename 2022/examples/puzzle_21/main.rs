@@ -1,7 +1,10 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use either::Either;
 use std::collections::{HashMap, VecDeque};
 use std::fmt;
-use std::time::Instant;
 
 #[derive(Debug, Clone)]
 enum Equation {
@@ -101,7 +104,7 @@ impl Equation {
 fn parse_equations(contents: &str) -> HashMap<String, Equation> {
     let mut equations: HashMap<String, Equation> = HashMap::new();
     for line in contents.lines() {
-        let components = line.trim().split_whitespace().collect::<Vec<&str>>();
+        let components = line.split_whitespace().collect::<Vec<&str>>();
         if line.contains("+") {
             equations.insert(
                 components[0].replace(":", ""),
@@ -148,10 +151,7 @@ fn part_1(contents: &str) -> i128 {
     let mut equations = parse_equations(contents);
     let mut number_keys: VecDeque<String> = VecDeque::new();
     for (key, equation) in equations.iter() {
-        match equation {
-            Equation::NUMBER(_) => number_keys.push_front(key.clone()),
-            _ => (),
-        }
+        if let Equation::NUMBER(_) = equation { number_keys.push_front(key.clone()) }
     }
     while let Some(key) = number_keys.pop_front() {
         let mut new_equations = HashMap::new();
@@ -160,23 +160,17 @@ fn part_1(contents: &str) -> i128 {
             _ => panic!("Could not find number!"),
         };
         for (other_key, equation) in equations {
-            match equation {
-                Equation::NUMBER(_) => {
-                    new_equations.insert(other_key, equation);
-                    continue;
-                }
-                _ => (),
+            if let Equation::NUMBER(_) = equation {
+                new_equations.insert(other_key, equation);
+                continue;
             }
 
             let new_equation = equation
                 .substitute_lhs(&key, number)
                 .substitute_rhs(&key, number)
                 .compute();
-            match new_equation {
-                Equation::NUMBER(_) => {
-                    number_keys.push_back(other_key.clone());
-                }
-                _ => (),
+            if let Equation::NUMBER(_) = new_equation {
+                number_keys.push_back(other_key.clone());
             }
             new_equations.insert(other_key, new_equation);
         }
@@ -208,10 +202,7 @@ fn part_2(contents: &str) -> String {
         .and_modify(|x| *x = Equation::UNKNOWN_NUMBER(String::from("humn")));
 
     for (key, equation) in equations.iter() {
-        match equation {
-            Equation::NUMBER(_) => number_keys.push_front(key.clone()),
-            _ => (),
-        }
+        if let Equation::NUMBER(_) = equation { number_keys.push_front(key.clone()) }
     }
     while let Some(key) = number_keys.pop_front() {
         let mut new_equations = HashMap::new();
@@ -220,23 +211,17 @@ fn part_2(contents: &str) -> String {
             _ => panic!("Could not find number!"),
         };
         for (other_key, equation) in equations {
-            match equation {
-                Equation::NUMBER(_) => {
-                    new_equations.insert(other_key, equation);
-                    continue;
-                }
-                _ => (),
+            if let Equation::NUMBER(_) = equation {
+                new_equations.insert(other_key, equation);
+                continue;
             }
 
             let new_equation = equation
                 .substitute_lhs(&key, number)
                 .substitute_rhs(&key, number)
                 .compute();
-            match new_equation {
-                Equation::NUMBER(_) => {
-                    number_keys.push_back(other_key.clone());
-                }
-                _ => (),
+            if let Equation::NUMBER(_) = new_equation {
+                number_keys.push_back(other_key.clone());
             }
             new_equations.insert(other_key, new_equation);
         }
@@ -257,7 +242,7 @@ fn part_2(contents: &str) -> String {
     let mut keys_to_substitute: VecDeque<String> = VecDeque::from([String::from("root")]);
     let mut final_equation = String::from("root");
     while let Some(key) = keys_to_substitute.pop_front() {
-        let (_, equation) = unknowns.remove_entry(&String::from(key.clone())).unwrap();
+        let (_, equation) = unknowns.remove_entry(&key.clone()).unwrap();
         let equation_string = equation.clone().to_string();
         final_equation = final_equation.replace(&key, &equation_string);
 
@@ -284,24 +269,29 @@ fn part_2(contents: &str) -> String {
     final_equation
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 21;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 152);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,53 +1,33 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::Direction4;
+use aoc_utils::puzzle::{run, Puzzle};
+use serde::Deserialize;
 use std::collections::HashMap;
-use std::f32::consts::E;
-use std::time::Instant;
-
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
-enum Facing {
-    LEFT,
-    RIGHT,
-    UP,
-    DOWN,
-}
 
-enum Direction {
-    LEFT,
-    RIGHT,
+/// An error for stepping onto a tile which is neither open nor a wall.
+fn off_the_map((column_index, row_index): (usize, usize)) -> PuzzleError {
+    PuzzleError::malformed("Expected an open tile or a wall")
+        .on_line(row_index)
+        .at_column(column_index)
 }
 
-impl Default for Facing {
-    fn default() -> Self {
-        Facing::RIGHT
-    }
+/// The path to follow on the line after the map, and the index of that line.
+fn path(contents: &str) -> Result<(usize, &str), PuzzleError> {
+    contents
+        .lines()
+        .enumerate()
+        .skip_while(|(_, x)| !x.is_empty())
+        .nth(1)
+        .ok_or_else(|| PuzzleError::malformed("Expected the path after the map and a blank line"))
 }
 
-impl Facing {
-    fn value(&self) -> usize {
-        match self {
-            Facing::LEFT => 2,
-            Facing::RIGHT => 0,
-            Facing::UP => 3,
-            Facing::DOWN => 1,
-        }
-    }
-    fn rotate(&self, rotation_direction: Direction) -> Facing {
-        match (rotation_direction, self) {
-            (Direction::LEFT, Facing::LEFT) => Facing::DOWN,
-            (Direction::LEFT, Facing::RIGHT) => Facing::UP,
-            (Direction::LEFT, Facing::DOWN) => Facing::RIGHT,
-            (Direction::LEFT, Facing::UP) => Facing::LEFT,
-            (Direction::RIGHT, Facing::LEFT) => Facing::UP,
-            (Direction::RIGHT, Facing::RIGHT) => Facing::DOWN,
-            (Direction::RIGHT, Facing::DOWN) => Facing::LEFT,
-            (Direction::RIGHT, Facing::UP) => Facing::RIGHT,
-        }
-    }
-    fn opposite(&self) -> Self {
-        match self {
-            Facing::LEFT => Facing::RIGHT,
-            Facing::RIGHT => Facing::LEFT,
-            Facing::UP => Facing::DOWN,
-            Facing::DOWN => Facing::UP,
+/// A digit of a number of steps in the path on the given line.
+fn steps(digit: char, line: usize) -> Result<usize, PuzzleError> {
+    match digit.to_digit(10) {
+        Some(steps) => Ok(steps as usize),
+        None => {
+            Err(PuzzleError::malformed(format!("Unexpected {digit:?} in the path")).on_line(line))
         }
     }
 }
@@ -55,15 +35,15 @@ impl Facing {
 fn move_around_maze(
     position: (usize, usize),
     move_by: usize,
-    facing: &Facing,
+    facing: &Direction4,
     maze: &HashMap<(usize, usize), char>,
     maze_bounds: &MazeBounds,
-) -> (usize, usize) {
+) -> Result<(usize, usize), PuzzleError> {
     match facing {
-        Facing::LEFT => {
+        Direction4::West => {
             let mut new_x = position.0;
             let mut left_to_move = move_by;
-            let mut previous_x = position.0;
+            let mut previous_x;
             while left_to_move > 0 {
                 left_to_move -= 1;
                 if maze_bounds.min_per_row.get(&position.1).unwrap() == &new_x {
@@ -77,16 +57,16 @@ fn move_around_maze(
                     Some('.') => {
                         continue;
                     }
-                    Some('#') => return (previous_x, position.1),
-                    _ => panic!("Should not occur!"),
+                    Some('#') => return Ok((previous_x, position.1)),
+                    _ => return Err(off_the_map((new_x, position.1))),
                 }
             }
-            (new_x, position.1)
+            Ok((new_x, position.1))
         }
-        Facing::RIGHT => {
+        Direction4::East => {
             let mut new_x = position.0;
             let mut left_to_move = move_by;
-            let mut previous_x = position.0;
+            let mut previous_x;
             while left_to_move > 0 {
                 left_to_move -= 1;
                 if maze_bounds.max_per_row.get(&position.1).unwrap() == &new_x {
@@ -100,16 +80,16 @@ fn move_around_maze(
                     Some('.') => {
                         continue;
                     }
-                    Some('#') => return (previous_x, position.1),
-                    _ => panic!("Should not occur!"),
+                    Some('#') => return Ok((previous_x, position.1)),
+                    _ => return Err(off_the_map((new_x, position.1))),
                 }
             }
-            (new_x, position.1)
+            Ok((new_x, position.1))
         }
-        Facing::UP => {
+        Direction4::North => {
             let mut new_y = position.1;
             let mut left_to_move = move_by;
-            let mut previous_y = position.1;
+            let mut previous_y;
             while left_to_move > 0 {
                 left_to_move -= 1;
                 if maze_bounds.min_per_column.get(&position.0).unwrap() == &new_y {
@@ -123,16 +103,16 @@ fn move_around_maze(
                     Some('.') => {
                         continue;
                     }
-                    Some('#') => return (position.0, previous_y),
-                    _ => panic!("Should not occur!"),
+                    Some('#') => return Ok((position.0, previous_y)),
+                    _ => return Err(off_the_map((position.0, new_y))),
                 }
             }
-            (position.0, new_y)
+            Ok((position.0, new_y))
         }
-        Facing::DOWN => {
+        Direction4::South => {
             let mut new_y = position.1;
             let mut left_to_move = move_by;
-            let mut previous_y = position.1;
+            let mut previous_y;
             while left_to_move > 0 {
                 left_to_move -= 1;
                 if maze_bounds.max_per_column.get(&position.0).unwrap() == &new_y {
@@ -146,17 +126,19 @@ fn move_around_maze(
                     Some('.') => {
                         continue;
                     }
-                    Some('#') => return (position.0, previous_y),
-                    _ => panic!("Should not occur!"),
+                    Some('#') => return Ok((position.0, previous_y)),
+                    _ => return Err(off_the_map((position.0, new_y))),
                 }
             }
-            (position.0, new_y)
+            Ok((position.0, new_y))
         }
     }
 }
 
-fn password(row_index: usize, column_index: usize, facing: Facing) -> u64 {
-    ((row_index + 1) * 1000 + (column_index + 1) * 4 + facing.value()) as u64
+fn password(row_index: usize, column_index: usize, facing: Direction4) -> u64 {
+    // facing is scored clockwise from right
+    let facing_score = facing.clockwise_from(Direction4::East) as usize;
+    ((row_index + 1) * 1000 + (column_index + 1) * 4 + facing_score) as u64
 }
 
 #[derive(Debug)]
@@ -167,7 +149,7 @@ struct MazeBounds {
     min_per_column: HashMap<usize, usize>,
 }
 
-fn part_1(contents: &str) -> u64 {
+fn part_1(contents: &str) -> Result<u64, PuzzleError> {
     let mut maze: HashMap<(usize, usize), char> = HashMap::new();
     let mut max_per_row: HashMap<usize, usize> = HashMap::new();
     let mut max_per_column: HashMap<usize, usize> = HashMap::new();
@@ -219,50 +201,46 @@ fn part_1(contents: &str) -> u64 {
         max_per_row,
     };
 
-    let mut facing = Facing::default();
-    let mut position = (*maze_bounds.min_per_row.get(&0).unwrap(), 0);
+    let mut facing = Direction4::East;
+    let Some(&start) = maze_bounds.min_per_row.get(&0) else {
+        return Err(PuzzleError::malformed("Expected the map to start on the first line"));
+    };
+    let mut position = (start, 0);
 
-    let mut instructions = contents
-        .lines()
-        .skip_while(|x| !x.is_empty())
-        .skip(1)
-        .next()
-        .unwrap()
-        .chars()
-        .peekable();
+    let (path_line, path) = path(contents)?;
+    let mut instructions = path.chars().peekable();
 
     loop {
         match instructions.peek() {
             Some('L') => {
-                facing = facing.rotate(Direction::LEFT);
+                facing = facing.turn_left();
                 instructions.next();
             }
             Some('R') => {
-                facing = facing.rotate(Direction::RIGHT);
+                facing = facing.turn_right();
                 instructions.next();
             }
             Some(digit) => {
-                let mut move_by: usize = digit.to_digit(10).unwrap() as usize;
+                let mut move_by: usize = steps(*digit, path_line)?;
                 instructions.next();
                 match instructions.peek() {
                     Some('L') | Some('R') | None => {
                         position =
-                            move_around_maze(position, move_by, &facing, &maze, &maze_bounds);
+                            move_around_maze(position, move_by, &facing, &maze, &maze_bounds)?;
                     }
                     Some(next_digit) => {
                         move_by *= 10;
-                        move_by += next_digit.to_digit(10).unwrap() as usize;
+                        move_by += steps(*next_digit, path_line)?;
                         position =
-                            move_around_maze(position, move_by, &facing, &maze, &maze_bounds);
+                            move_around_maze(position, move_by, &facing, &maze, &maze_bounds)?;
                         instructions.next();
                     }
-                    _ => break,
                 }
             }
             _ => break,
         }
     }
-    password(position.1, position.0, facing)
+    Ok(password(position.1, position.0, facing))
 }
 
 enum Action {
@@ -270,36 +248,36 @@ enum Action {
     STOP,
 }
 
-type CurrentPosition = ((usize, usize), Facing);
+type CurrentPosition = ((usize, usize), Direction4);
 
 fn move_around_maze_cube(
     position: (usize, usize),
-    facing: Facing,
+    facing: Direction4,
     maze: &HashMap<(usize, usize), char>,
     cube_mapping: &HashMap<CurrentPosition, CurrentPosition>,
-) -> ((usize, usize), Facing, Action) {
+) -> Result<((usize, usize), Direction4, Action), PuzzleError> {
     let (new_position, new_facing) = match facing {
-        Facing::LEFT => match cube_mapping.get(&(position, facing)) {
-            Some(updated) => updated.clone(),
+        Direction4::West => match cube_mapping.get(&(position, facing)) {
+            Some(updated) => *updated,
             _ => ((position.0 - 1, position.1), facing),
         },
-        Facing::RIGHT => match cube_mapping.get(&(position, facing)) {
-            Some(updated) => updated.clone(),
+        Direction4::East => match cube_mapping.get(&(position, facing)) {
+            Some(updated) => *updated,
             _ => ((position.0 + 1, position.1), facing),
         },
-        Facing::UP => match cube_mapping.get(&(position, facing)) {
-            Some(updated) => updated.clone(),
+        Direction4::North => match cube_mapping.get(&(position, facing)) {
+            Some(updated) => *updated,
             _ => ((position.0, position.1 - 1), facing),
         },
-        Facing::DOWN => match cube_mapping.get(&(position, facing)) {
-            Some(updated) => updated.clone(),
+        Direction4::South => match cube_mapping.get(&(position, facing)) {
+            Some(updated) => *updated,
             _ => ((position.0, position.1 + 1), facing),
         },
     };
     match maze.get(&new_position) {
-        Some('.') => (new_position, new_facing, Action::CONTINUE),
-        Some('#') => (position, facing, Action::STOP),
-        _ => panic!("Should not occur!"),
+        Some('.') => Ok((new_position, new_facing, Action::CONTINUE)),
+        Some('#') => Ok((position, facing, Action::STOP)),
+        _ => Err(off_the_map(new_position)),
     }
 }
 
@@ -318,56 +296,56 @@ fn make_cube_mapping(cube_size: usize) -> HashMap<CurrentPosition, CurrentPositi
 
     // a
     let a_top: Vec<CurrentPosition> = (cube_size..2 * cube_size)
-        .map(|x| ((x, 0), Facing::UP))
+        .map(|x| ((x, 0), Direction4::North))
         .collect::<Vec<_>>();
     let a_left: Vec<CurrentPosition> = (0..cube_size)
-        .map(|y| ((cube_size, y), Facing::LEFT))
+        .map(|y| ((cube_size, y), Direction4::West))
         .collect::<Vec<_>>();
 
     // b
     let b_top: Vec<CurrentPosition> = (2 * cube_size..3 * cube_size)
-        .map(|x| ((x, 0), Facing::UP))
+        .map(|x| ((x, 0), Direction4::North))
         .collect::<Vec<_>>();
     let b_right: Vec<CurrentPosition> = (0..cube_size)
-        .map(|y| ((3 * cube_size - 1, y), Facing::RIGHT))
+        .map(|y| ((3 * cube_size - 1, y), Direction4::East))
         .collect::<Vec<_>>();
     let b_bottom: Vec<CurrentPosition> = (2 * cube_size..3 * cube_size)
-        .map(|x| ((x, cube_size - 1), Facing::DOWN))
+        .map(|x| ((x, cube_size - 1), Direction4::South))
         .collect::<Vec<_>>();
 
     // c
     let c_left: Vec<CurrentPosition> = (cube_size..2 * cube_size)
-        .map(|y| ((cube_size, y), Facing::LEFT))
+        .map(|y| ((cube_size, y), Direction4::West))
         .collect::<Vec<_>>();
     let c_right: Vec<CurrentPosition> = (cube_size..2 * cube_size)
-        .map(|y| ((2 * cube_size - 1, y), Facing::RIGHT))
+        .map(|y| ((2 * cube_size - 1, y), Direction4::East))
         .collect::<Vec<_>>();
 
     // d
     let d_right: Vec<CurrentPosition> = (2 * cube_size..3 * cube_size)
-        .map(|y| ((2 * cube_size - 1, y), Facing::RIGHT))
+        .map(|y| ((2 * cube_size - 1, y), Direction4::East))
         .collect::<Vec<_>>();
     let d_bottom: Vec<CurrentPosition> = (cube_size..2 * cube_size)
-        .map(|x| ((x, 3 * cube_size - 1), Facing::DOWN))
+        .map(|x| ((x, 3 * cube_size - 1), Direction4::South))
         .collect::<Vec<_>>();
 
     // e
     let e_left: Vec<CurrentPosition> = (2 * cube_size..3 * cube_size)
-        .map(|y| ((0, y), Facing::LEFT))
+        .map(|y| ((0, y), Direction4::West))
         .collect::<Vec<_>>();
     let e_top: Vec<CurrentPosition> = (0..cube_size)
-        .map(|x| ((x, 2 * cube_size), Facing::UP))
+        .map(|x| ((x, 2 * cube_size), Direction4::North))
         .collect::<Vec<_>>();
 
     // f
     let f_right: Vec<CurrentPosition> = (3 * cube_size..4 * cube_size)
-        .map(|y| ((cube_size - 1, y), Facing::RIGHT))
+        .map(|y| ((cube_size - 1, y), Direction4::East))
         .collect::<Vec<_>>();
     let f_bottom: Vec<CurrentPosition> = (0..cube_size)
-        .map(|x| ((x, 4 * cube_size - 1), Facing::DOWN))
+        .map(|x| ((x, 4 * cube_size - 1), Direction4::South))
         .collect::<Vec<_>>();
     let f_left: Vec<CurrentPosition> = (3 * cube_size..4 * cube_size)
-        .map(|y| ((0, y), Facing::LEFT))
+        .map(|y| ((0, y), Direction4::West))
         .collect::<Vec<_>>();
 
     // mappings
@@ -383,24 +361,24 @@ fn make_cube_mapping(cube_size: usize) -> HashMap<CurrentPosition, CurrentPositi
     for (side_1, side_2, reverse) in side_pairs {
         let iter = if reverse {
             let reversed_side_2 = side_2.into_iter().rev().collect::<Vec<CurrentPosition>>();
-            side_1.into_iter().zip(reversed_side_2.into_iter())
+            side_1.into_iter().zip(reversed_side_2)
         } else {
-            side_1.into_iter().zip(side_2.into_iter())
+            side_1.into_iter().zip(side_2)
         };
         for (position_1, position_2) in iter {
-            let mut new_position_2 = position_2.clone();
-            new_position_2.1 = position_2.1.opposite();
+            let mut new_position_2 = position_2;
+            new_position_2.1 = position_2.1.reverse();
             cube_mapping.insert(position_1, new_position_2);
 
-            let mut new_position_1 = position_1.clone();
-            new_position_1.1 = position_1.1.opposite();
+            let mut new_position_1 = position_1;
+            new_position_1.1 = position_1.1.reverse();
             cube_mapping.insert(position_2, new_position_1);
         }
     }
     cube_mapping
 }
 
-fn part_2(contents: &str, cube_size: usize) -> u64 {
+fn part_2(contents: &str, cube_size: usize) -> Result<u64, PuzzleError> {
     let mut maze: HashMap<(usize, usize), char> = HashMap::new();
     for (row_index, line) in contents.lines().take_while(|x| !x.is_empty()).enumerate() {
         for (column_index, char) in line.chars().enumerate() {
@@ -410,37 +388,31 @@ fn part_2(contents: &str, cube_size: usize) -> u64 {
         }
     }
 
-    let mut facing = Facing::default();
+    let mut facing = Direction4::East;
     let mut position = (cube_size, 0);
     let cube_mapping = make_cube_mapping(cube_size);
 
-    let mut instructions = contents
-        .lines()
-        .skip_while(|x| !x.is_empty())
-        .skip(1)
-        .next()
-        .unwrap()
-        .chars()
-        .peekable();
+    let (path_line, path) = path(contents)?;
+    let mut instructions = path.chars().peekable();
 
     loop {
         match instructions.peek() {
             Some('L') => {
-                facing = facing.rotate(Direction::LEFT);
+                facing = facing.turn_left();
                 instructions.next();
             }
             Some('R') => {
-                facing = facing.rotate(Direction::RIGHT);
+                facing = facing.turn_right();
                 instructions.next();
             }
             Some(digit) => {
-                let mut move_by: usize = digit.to_digit(10).unwrap() as usize;
+                let mut move_by: usize = steps(*digit, path_line)?;
                 instructions.next();
                 match instructions.peek() {
                     Some('L') | Some('R') | None => {
                         for _ in 0..move_by {
                             let (new_position, new_facing, action) =
-                                move_around_maze_cube(position, facing, &maze, &cube_mapping);
+                                move_around_maze_cube(position, facing, &maze, &cube_mapping)?;
                             position = new_position;
                             facing = new_facing;
                             match action {
@@ -451,10 +423,10 @@ fn part_2(contents: &str, cube_size: usize) -> u64 {
                     }
                     Some(next_digit) => {
                         move_by *= 10;
-                        move_by += next_digit.to_digit(10).unwrap() as usize;
+                        move_by += steps(*next_digit, path_line)?;
                         for _ in 0..move_by {
                             let (new_position, new_facing, action) =
-                                move_around_maze_cube(position, facing, &maze, &cube_mapping);
+                                move_around_maze_cube(position, facing, &maze, &cube_mapping)?;
                             position = new_position;
                             facing = new_facing;
                             match action {
@@ -464,33 +436,50 @@ fn part_2(contents: &str, cube_size: usize) -> u64 {
                         }
                         instructions.next();
                     }
-                    _ => break,
                 }
             }
             _ => break,
         }
     }
-    password(position.1, position.0, facing)
+    Ok(password(position.1, position.0, facing))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Length of each edge of the cube.
+    cube_size: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { cube_size: 50 }
+    }
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 22;
+    const PARSES_INPUT: bool = false;
+
+    type Params = Params;
+    type Parsed = (String, Params);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        Ok((input.to_owned(), *params))
+    }
+
+    fn part_1(&self, (input, _): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 6032);
+    fn part_2(&self, (input, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input, params.cube_size)?.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents, 50);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,148 +1,121 @@
-use num::complex::Complex;
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
-
-#[derive(Debug, Clone, Eq, PartialEq, Hash)]
-enum Direction {
-    NORTH,
-    SOUTH,
-    EAST,
-    WEST,
-    NORTH_WEST,
-    NORTH_EAST,
-    SOUTH_EAST,
-    SOUTH_WEST,
-}
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::{Direction8, Orientation, Point2D, SparseGrid};
+use aoc_utils::puzzle::{run, Puzzle};
+use serde::Deserialize;
+use std::collections::HashMap;
 
-fn neighbours(point: &Complex<i64>) -> HashMap<Direction, Complex<i64>> {
-    HashMap::from_iter(vec![
-        (Direction::EAST, point + Complex::new(1, 0)),
-        (Direction::WEST, point + Complex::new(-1, 0)),
-        (Direction::NORTH, point + Complex::new(0, -1)),
-        (Direction::SOUTH, point + Complex::new(0, 1)),
-        (Direction::SOUTH_EAST, point + Complex::new(1, 1)),
-        (Direction::NORTH_EAST, point + Complex::new(1, -1)),
-        (Direction::NORTH_WEST, point + Complex::new(-1, -1)),
-        (Direction::SOUTH_WEST, point + Complex::new(-1, 1)),
-    ])
+fn neighbours(point: &Point2D<i64>) -> HashMap<Direction8, Point2D<i64>> {
+    Direction8::ALL
+        .into_iter()
+        .map(|direction| (direction, *point + Orientation::Screen.unit_vector(direction)))
+        .collect()
 }
 
 fn check_north(
-    point: &Complex<i64>,
-    has_neighbours: &HashMap<Direction, bool>,
-) -> Option<Complex<i64>> {
+    point: &Point2D<i64>,
+    has_neighbours: &HashMap<Direction8, bool>,
+) -> Option<Point2D<i64>> {
     // If there is no Elf in the N, NE, or NW adjacent positions, the Elf proposes moving north one step.
-    if !has_neighbours.get(&Direction::NORTH).unwrap()
-        && !has_neighbours.get(&Direction::NORTH_EAST).unwrap()
-        && !has_neighbours.get(&Direction::NORTH_WEST).unwrap()
+    if !has_neighbours.get(&Direction8::North).unwrap()
+        && !has_neighbours.get(&Direction8::NorthEast).unwrap()
+        && !has_neighbours.get(&Direction8::NorthWest).unwrap()
     {
-        Some(point + Complex::new(0, -1))
+        Some(*point + Orientation::Screen.unit_vector(Direction8::North))
     } else {
         None
     }
 }
 
 fn check_south(
-    point: &Complex<i64>,
-    has_neighbours: &HashMap<Direction, bool>,
-) -> Option<Complex<i64>> {
+    point: &Point2D<i64>,
+    has_neighbours: &HashMap<Direction8, bool>,
+) -> Option<Point2D<i64>> {
     // If there is no Elf in the S, SE, or SW adjacent positions, the Elf proposes moving south one step.
-    if !has_neighbours.get(&Direction::SOUTH).unwrap()
-        && !has_neighbours.get(&Direction::SOUTH_EAST).unwrap()
-        && !has_neighbours.get(&Direction::SOUTH_WEST).unwrap()
+    if !has_neighbours.get(&Direction8::South).unwrap()
+        && !has_neighbours.get(&Direction8::SouthEast).unwrap()
+        && !has_neighbours.get(&Direction8::SouthWest).unwrap()
     {
-        Some(point + Complex::new(0, 1))
+        Some(*point + Orientation::Screen.unit_vector(Direction8::South))
     } else {
         None
     }
 }
 
 fn check_east(
-    point: &Complex<i64>,
-    has_neighbours: &HashMap<Direction, bool>,
-) -> Option<Complex<i64>> {
+    point: &Point2D<i64>,
+    has_neighbours: &HashMap<Direction8, bool>,
+) -> Option<Point2D<i64>> {
     // If there is no Elf in the E, NE, or SE adjacent positions, the Elf proposes moving east one step.
-    if !has_neighbours.get(&Direction::EAST).unwrap()
-        && !has_neighbours.get(&Direction::NORTH_EAST).unwrap()
-        && !has_neighbours.get(&Direction::SOUTH_EAST).unwrap()
+    if !has_neighbours.get(&Direction8::East).unwrap()
+        && !has_neighbours.get(&Direction8::NorthEast).unwrap()
+        && !has_neighbours.get(&Direction8::SouthEast).unwrap()
     {
-        Some(point + Complex::new(1, 0))
+        Some(*point + Orientation::Screen.unit_vector(Direction8::East))
     } else {
         None
     }
 }
 
 fn check_west(
-    point: &Complex<i64>,
-    has_neighbours: &HashMap<Direction, bool>,
-) -> Option<Complex<i64>> {
+    point: &Point2D<i64>,
+    has_neighbours: &HashMap<Direction8, bool>,
+) -> Option<Point2D<i64>> {
     // If there is no Elf in the W, NW, or SW adjacent positions, the Elf proposes moving west one step.
-    if !has_neighbours.get(&Direction::WEST).unwrap()
-        && !has_neighbours.get(&Direction::SOUTH_WEST).unwrap()
-        && !has_neighbours.get(&Direction::NORTH_WEST).unwrap()
+    if !has_neighbours.get(&Direction8::West).unwrap()
+        && !has_neighbours.get(&Direction8::SouthWest).unwrap()
+        && !has_neighbours.get(&Direction8::NorthWest).unwrap()
     {
-        Some(point + Complex::new(-1, 0))
+        Some(*point + Orientation::Screen.unit_vector(Direction8::West))
     } else {
         None
     }
 }
 
-fn get_answer(grid: HashSet<Complex<i64>>) -> i64 {
-    let min_x = grid.iter().map(|p| p.re).min().unwrap();
-    let max_x = grid.iter().map(|p| p.re).max().unwrap();
-    let min_y = grid.iter().map(|p| p.im).min().unwrap();
-    let max_y = grid.iter().map(|p| p.im).max().unwrap();
-    (max_x - min_x + 1) * (max_y - min_y + 1) - (grid.len() as i64)
+fn get_answer(grid: SparseGrid<i64>) -> u64 {
+    grid.count_empty()
 }
 
-fn print_grid(grid: &HashSet<Complex<i64>>) {
-    let min_x = grid.iter().map(|p| p.re).min().unwrap();
-    let max_x = grid.iter().map(|p| p.re).max().unwrap();
-    let min_y = grid.iter().map(|p| p.im).min().unwrap();
-    let max_y = grid.iter().map(|p| p.im).max().unwrap();
-    let mut grid_vec: Vec<Vec<char>> =
-        vec![vec!['.'; (max_x - min_x + 1) as usize]; (max_y - min_y + 1) as usize];
-    for point in grid.iter() {
-        grid_vec[(point.im - min_y) as usize][(point.re - min_x) as usize] = '#'
-    }
-    for row in grid_vec {
-        for char in row {
-            print!("{char}");
-        }
-        println!("");
-    }
+#[allow(dead_code)]
+fn print_grid(grid: &SparseGrid<i64>) {
+    let ground = grid.render(Orientation::Screen, |elf| match elf {
+        Some(()) => '#',
+        None => '.',
+    });
+    println!("{ground}");
 }
 
-fn simulate(contents: &str, num_rounds: usize) -> (HashSet<Complex<i64>>, usize) {
-    let mut grid: HashSet<Complex<i64>> = HashSet::new();
+fn simulate(contents: &str, num_rounds: usize) -> (SparseGrid<i64>, usize) {
+    let mut grid: SparseGrid<i64> = SparseGrid::new();
     for (y, line) in contents.lines().enumerate() {
         for (x, char) in line.chars().enumerate() {
             if char == '#' {
-                grid.insert(Complex::new(x as i64, y as i64));
+                grid.add(Point2D::new(x as i64, y as i64));
             }
         }
     }
     let num_elves = grid.len();
     let mut rounds_completed = 0;
     for round in 0..num_rounds {
-        let mut new_grid: HashSet<Complex<i64>> = HashSet::with_capacity(num_elves);
-        let mut new_point_counts: HashMap<Complex<i64>, usize> = HashMap::with_capacity(num_elves);
-        let mut potential_moves: HashMap<Complex<i64>, Complex<i64>> =
+        let mut new_grid: SparseGrid<i64> = SparseGrid::new();
+        let mut new_point_counts: HashMap<Point2D<i64>, usize> =
+            HashMap::with_capacity(num_elves);
+        let mut potential_moves: HashMap<Point2D<i64>, Point2D<i64>> =
             HashMap::with_capacity(num_elves);
         let mut no_moves = 0;
 
-        for point in grid.iter() {
-            let neighbours = neighbours(&point);
-            let has_neighbours: HashMap<Direction, bool> =
+        for point in grid.points() {
+            let neighbours = neighbours(point);
+            let has_neighbours: HashMap<Direction8, bool> =
                 HashMap::from_iter(neighbours.into_iter().map(|(d, p)| (d, grid.contains(&p))));
             let possible = if has_neighbours.iter().all(|(_, x)| !x) {
                 None
             } else {
-                let possibles: Vec<Option<Complex<i64>>> = vec![
-                    check_north(&point, &has_neighbours),
-                    check_south(&point, &has_neighbours),
-                    check_west(&point, &has_neighbours),
-                    check_east(&point, &has_neighbours),
+                let possibles: Vec<Option<Point2D<i64>>> = vec![
+                    check_north(point, &has_neighbours),
+                    check_south(point, &has_neighbours),
+                    check_west(point, &has_neighbours),
+                    check_east(point, &has_neighbours),
                 ];
 
                 possibles
@@ -160,10 +133,10 @@ fn simulate(contents: &str, num_rounds: usize) -> (HashSet<Complex<i64>>, usize)
                         .entry(new_point)
                         .and_modify(|x| *x += 1)
                         .or_insert(1);
-                    potential_moves.insert(point.clone(), new_point.clone());
+                    potential_moves.insert(*point, new_point);
                 }
                 _ => {
-                    new_grid.insert(*point);
+                    new_grid.add(*point);
                     no_moves += 1;
                 }
             }
@@ -172,11 +145,11 @@ fn simulate(contents: &str, num_rounds: usize) -> (HashSet<Complex<i64>>, usize)
         for (point, other_point) in potential_moves {
             match new_point_counts.get(&other_point) {
                 Some(c) if c > &1 => {
-                    new_grid.insert(point);
+                    new_grid.add(point);
                     no_moves += 1;
                 }
                 Some(_) => {
-                    new_grid.insert(other_point);
+                    new_grid.add(other_point);
                 }
                 None => panic!("Should not be missing here!"),
             }
@@ -190,7 +163,7 @@ fn simulate(contents: &str, num_rounds: usize) -> (HashSet<Complex<i64>>, usize)
     (grid, rounds_completed)
 }
 
-fn part_1(contents: &str, num_rounds: usize) -> i64 {
+fn part_1(contents: &str, num_rounds: usize) -> u64 {
     let (grid, _) = simulate(contents, num_rounds);
     get_answer(grid)
 }
@@ -200,74 +173,117 @@ fn part_2(contents: &str) -> usize {
     rounds_completed
 }
 
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Rounds to simulate before measuring the empty ground.
+    num_rounds: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { num_rounds: 10 }
+    }
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 23;
+    const PARSES_INPUT: bool = false;
+
+    type Params = Params;
+    type Parsed = (String, Params);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        Ok((input.to_owned(), *params))
+    }
+
+    fn part_1(&self, (input, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input, params.num_rounds).into())
+    }
+
+    fn part_2(&self, (input, _): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rstest::*;
+    use aoc_utils::input::load_example;
 
     #[test]
     fn test_part_1_smaller_example_one_move() {
-        assert_eq!(part_1(include_str!("./smaller_example.txt"), 1), 5);
+        let Some(smaller_example) = load_example(2022, 23, "smaller_example.txt") else {
+            return;
+        };
+        assert_eq!(part_1(&smaller_example, 1), 5);
     }
 
     #[test]
     fn test_part_1_smaller_example_two_moves() {
-        assert_eq!(part_1(include_str!("./smaller_example.txt"), 2), 15);
+        let Some(smaller_example) = load_example(2022, 23, "smaller_example.txt") else {
+            return;
+        };
+        assert_eq!(part_1(&smaller_example, 2), 15);
     }
 
     #[test]
     fn test_part_1_smaller_example_three_moves() {
-        assert_eq!(part_1(include_str!("./smaller_example.txt"), 3), 25);
+        let Some(smaller_example) = load_example(2022, 23, "smaller_example.txt") else {
+            return;
+        };
+        assert_eq!(part_1(&smaller_example, 3), 25);
     }
 
     #[test]
     fn test_part_1_example_one_move() {
+        let Some(example) = load_example(2022, 23, "example.txt") else {
+            return;
+        };
         // 9 x 9 rectangle bounds elves, 22 elves
-        assert_eq!(part_1(include_str!("./example.txt"), 1), 9 * 9 - 22);
+        assert_eq!(part_1(&example, 1), 9 * 9 - 22);
     }
 
     #[test]
     fn test_part_1_example_two_moves() {
+        let Some(example) = load_example(2022, 23, "example.txt") else {
+            return;
+        };
         // 9 x 11 rectangle bounds elves, 22 elves
-        assert_eq!(part_1(include_str!("./example.txt"), 2), 9 * 11 - 22);
+        assert_eq!(part_1(&example, 2), 9 * 11 - 22);
     }
 
     #[test]
     fn test_part_1_example_three_moves() {
+        let Some(example) = load_example(2022, 23, "example.txt") else {
+            return;
+        };
         // 10 x 11 rectangle bounds elves, 22 elves
-        assert_eq!(part_1(include_str!("./example.txt"), 3), 10 * 11 - 22);
+        assert_eq!(part_1(&example, 3), 10 * 11 - 22);
     }
 
     #[test]
     fn test_part_1_example_four_moves() {
+        let Some(example) = load_example(2022, 23, "example.txt") else {
+            return;
+        };
         // 10 x 11 rectangle bounds elves, 22 elves
-        assert_eq!(part_1(include_str!("./example.txt"), 4), 10 * 11 - 22);
+        assert_eq!(part_1(&example, 4), 10 * 11 - 22);
     }
 
     #[test]
     fn test_part_1_example_five_moves() {
+        let Some(example) = load_example(2022, 23, "example.txt") else {
+            return;
+        };
         // 11 x 11 rectangle bounds elves, 22 elves
-        assert_eq!(part_1(include_str!("./example.txt"), 5), 11 * 11 - 22);
+        assert_eq!(part_1(&example, 5), 11 * 11 - 22);
     }
-
-    #[test]
-    fn test_part_1_example_ten_moves() {
-        assert_eq!(part_1(include_str!("./example.txt"), 10), 110);
-    }
-
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 20);
-    }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents, 10);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
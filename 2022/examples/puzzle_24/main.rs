@@ -1,65 +1,17 @@
-use std::collections::{HashMap, HashSet};
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::{Direction4, Point2D};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
+use std::collections::HashSet;
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum WindDirection {
-    LEFT,
-    RIGHT,
-    UP,
-    DOWN,
-}
-
-impl From<char> for WindDirection {
-    fn from(c: char) -> Self {
-        match c {
-            'v' => WindDirection::DOWN,
-            '^' => WindDirection::UP,
-            '>' => WindDirection::RIGHT,
-            '<' => WindDirection::LEFT,
-            _ => panic!("Wind doesn't go in this direction!"),
-        }
-    }
-}
-
-impl Into<char> for &WindDirection {
-    fn into(self) -> char {
-        match self {
-            WindDirection::DOWN => 'v',
-            WindDirection::UP => '^',
-            WindDirection::RIGHT => '>',
-            WindDirection::LEFT => '<',
-        }
-    }
-}
-
-#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl Point {
-    fn new(x: usize, y: usize) -> Self {
-        Self { x, y }
-    }
-
-    fn neighbours(&self) -> Vec<Self> {
-        let mut points: Vec<Point> =
-            vec![Self::new(self.x + 1, self.y), Self::new(self.x, self.y + 1)];
-        if self.x > 0 {
-            points.push(Self::new(self.x - 1, self.y));
-        }
-        if self.y > 0 {
-            points.push(Self::new(self.x, self.y - 1));
-        }
-        points
-    }
-}
+type Point = Point2D<usize>;
 
+#[allow(dead_code)]
 fn print_blizzard(blizzard: &Blizzard, start: &Point, end: &Point, width: &usize, height: &usize) {
     let mut output = vec![vec!['.'; *width]; *height];
     for (blizzard, direction) in blizzard.blizzards.iter() {
-        output[blizzard.y][blizzard.x] = direction.into()
+        output[blizzard.y][blizzard.x] = direction.arrow()
     }
     // borders
     output[0] = vec!['#'; *width];
@@ -75,18 +27,18 @@ fn print_blizzard(blizzard: &Blizzard, start: &Point, end: &Point, width: &usize
         for char in row {
             print!("{char}");
         }
-        println!("")
+        println!()
     }
 }
 
 #[derive(Debug, Clone)]
 struct Blizzard {
-    blizzards: Vec<(Point, WindDirection)>,
+    blizzards: Vec<(Point, Direction4)>,
     occupied: HashSet<Point>,
 }
 
-impl From<Vec<(Point, WindDirection)>> for Blizzard {
-    fn from(blizzards: Vec<(Point, WindDirection)>) -> Self {
+impl From<Vec<(Point, Direction4)>> for Blizzard {
+    fn from(blizzards: Vec<(Point, Direction4)>) -> Self {
         let mut occupied: HashSet<Point> = HashSet::new();
         for (point, _) in blizzards.iter() {
             occupied.insert(*point);
@@ -99,31 +51,31 @@ impl From<Vec<(Point, WindDirection)>> for Blizzard {
 }
 
 fn update_blizzard(blizzard: Blizzard, grid_width: &usize, grid_height: &usize) -> Blizzard {
-    let mut new_blizzards: Vec<(Point, WindDirection)> = Vec::new();
+    let mut new_blizzards: Vec<(Point, Direction4)> = Vec::new();
     for (point, direction) in blizzard.blizzards.into_iter() {
         match direction {
-            WindDirection::LEFT => {
+            Direction4::West => {
                 if point.x == 1 {
                     new_blizzards.push((Point::new(grid_width - 2, point.y), direction));
                 } else {
                     new_blizzards.push((Point::new(point.x - 1, point.y), direction));
                 }
             }
-            WindDirection::RIGHT => {
+            Direction4::East => {
                 if point.x == grid_width - 2 {
                     new_blizzards.push((Point::new(1, point.y), direction));
                 } else {
                     new_blizzards.push((Point::new(point.x + 1, point.y), direction));
                 }
             }
-            WindDirection::UP => {
+            Direction4::North => {
                 if point.y == 1 {
                     new_blizzards.push((Point::new(point.x, grid_height - 2), direction));
                 } else {
                     new_blizzards.push((Point::new(point.x, point.y - 1), direction));
                 }
             }
-            WindDirection::DOWN => {
+            Direction4::South => {
                 if point.y == grid_height - 2 {
                     new_blizzards.push((Point::new(point.x, 1), direction));
                 } else {
@@ -135,9 +87,13 @@ fn update_blizzard(blizzard: Blizzard, grid_width: &usize, grid_height: &usize)
     Blizzard::from(new_blizzards)
 }
 
-fn parse_blizzard(contents: &str) -> (Blizzard, usize, usize) {
-    let mut blizzards: Vec<(Point, WindDirection)> = Vec::new();
-    let grid_width = contents.lines().next().unwrap().len();
+fn parse_blizzard(contents: &str) -> Result<(Blizzard, usize, usize), PuzzleError> {
+    let mut blizzards: Vec<(Point, Direction4)> = Vec::new();
+    let grid_width = contents
+        .lines()
+        .next()
+        .ok_or_else(|| PuzzleError::malformed("Expected a map of the valley"))?
+        .len();
     let mut grid_height = 0;
     for (row_index, line) in contents.lines().enumerate() {
         grid_height += 1;
@@ -145,12 +101,13 @@ fn parse_blizzard(contents: &str) -> (Blizzard, usize, usize) {
             if char == '#' || char == '.' {
                 continue;
             }
-            let direction = WindDirection::from(char);
+            let direction = Direction4::try_from(char)
+                .map_err(|e| e.on_line(row_index).at_column(column_index))?;
             blizzards.push((Point::new(column_index, row_index), direction));
         }
     }
     let blizzard = Blizzard::from(blizzards);
-    (blizzard, grid_width, grid_height)
+    Ok((blizzard, grid_width, grid_height))
 }
 
 fn shortest_path(
@@ -161,16 +118,16 @@ fn shortest_path(
     grid_height: &usize,
 ) -> (Blizzard, usize) {
     let mut blizzard = start_blizzard.clone();
-    let mut positions: HashSet<Point> = HashSet::from_iter(vec![start.clone()]);
+    let mut positions: HashSet<Point> = HashSet::from_iter(vec![start]);
     let mut num_steps = 0;
-    'outer: while positions.len() > 0 {
+    'outer: while !positions.is_empty() {
         blizzard = update_blizzard(blizzard, grid_width, grid_height);
         let mut new_positions: HashSet<Point> = HashSet::new();
         for position in positions {
             if !blizzard.occupied.contains(&position) {
                 new_positions.insert(position);
             }
-            for neighbour in position.neighbours().into_iter() {
+            for neighbour in position.adjacent_neighbours().into_iter() {
                 if neighbour == end {
                     num_steps += 1;
                     break 'outer;
@@ -190,16 +147,16 @@ fn shortest_path(
     (blizzard, num_steps)
 }
 
-fn part_1(contents: &str) -> usize {
-    let (blizzard, grid_width, grid_height) = parse_blizzard(contents);
+fn part_1(contents: &str) -> Result<usize, PuzzleError> {
+    let (blizzard, grid_width, grid_height) = parse_blizzard(contents)?;
     let start = Point::new(1, 0);
     let end = Point::new(grid_width - 2, grid_height - 1);
     let (_, num_steps) = shortest_path(blizzard, start, end, &grid_width, &grid_height);
-    num_steps
+    Ok(num_steps)
 }
 
-fn part_2(contents: &str) -> usize {
-    let (blizzard, grid_width, grid_height) = parse_blizzard(contents);
+fn part_2(contents: &str) -> Result<usize, PuzzleError> {
+    let (blizzard, grid_width, grid_height) = parse_blizzard(contents)?;
 
     let mut answer = 0;
     let start = Point::new(1, 0);
@@ -208,8 +165,8 @@ fn part_2(contents: &str) -> usize {
     // leg 1
     let (blizzard, num_steps) = shortest_path(
         blizzard,
-        start.clone(),
-        end.clone(),
+        start,
+        end,
         &grid_width,
         &grid_height,
     );
@@ -218,53 +175,48 @@ fn part_2(contents: &str) -> usize {
     // leg 2
     let (blizzard, num_steps) = shortest_path(
         blizzard,
-        end.clone(),
-        start.clone(),
+        end,
+        start,
         &grid_width,
         &grid_height,
     );
     answer += num_steps;
 
     // leg 3
-    let (blizzard, num_steps) = shortest_path(
+    let (_blizzard, num_steps) = shortest_path(
         blizzard,
-        start.clone(),
-        end.clone(),
+        start,
+        end,
         &grid_width,
         &grid_height,
     );
     answer += num_steps;
-    answer
+    Ok(answer)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 24;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
 
-    #[test]
-    fn test_part_1_minimal_example() {
-        assert_eq!(part_1(include_str!("./minimal_example.txt")), 10);
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
     }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 18);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 54);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,4 +1,8 @@
-use std::{ops::Sub, time::Instant};
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
+use std::ops::Sub;
 
 fn parse(line: &str) -> i128 {
     let mut line_sum = 0;
@@ -18,7 +22,7 @@ fn parse(line: &str) -> i128 {
 
 fn to_snafu(number: i128) -> String {
     let mut snafu = String::from("");
-    let mut n = number.clone();
+    let mut n = number;
     while n > 0 {
         let modulus = n % 5;
         n /= 5;
@@ -30,7 +34,7 @@ fn to_snafu(number: i128) -> String {
             4 => "-",
             _ => panic!("Not this!"),
         };
-        snafu.push_str(&char);
+        snafu.push_str(char);
         if modulus >= 3 {
             n += 1;
         }
@@ -46,6 +50,30 @@ fn puzzle(contents: &str) -> String {
     to_snafu(answer)
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 25;
+    const HAS_PART_2: bool = false;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(puzzle(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,12 +93,3 @@ mod tests {
         assert_eq!(to_snafu(input), expected);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let answer = puzzle(contents);
-    println!("Answer is: {}", answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
-}
@@ -1,39 +1,24 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::BoundingBox;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
-#[derive(Debug)]
-struct Range {
-    low: u8,
-    high: u8,
-}
-
-impl Range {
-    fn contained_in(&self, other: &Range) -> bool {
-        return other.low <= self.low && self.high <= other.high;
-    }
-
-    fn overlaps(&self, other: &Range) -> bool {
-        return self.low <= other.high && self.high >= other.low
-            || other.low <= self.high && other.high >= self.low;
-    }
-}
+/// A range of sections, both ends included.
+type Range = BoundingBox<u8, 1>;
 
-impl From<&str> for Range {
-    fn from(s: &str) -> Range {
-        let raw_bounds: Vec<&str> = s.split("-").collect();
-        Range {
-            low: raw_bounds[0].parse::<u8>().unwrap(),
-            high: raw_bounds[1].parse::<u8>().unwrap(),
-        }
-    }
+fn parse_range(s: &str) -> Range {
+    let (low, high) = s.split_once("-").unwrap();
+    Range::from_corners(low.parse().unwrap(), high.parse().unwrap())
 }
 
 fn part_1(contents: &str) -> u64 {
     let mut redundant = 0;
     for line in contents.lines() {
         let pairs: Vec<&str> = line.split(",").collect();
-        let range_1 = Range::from(pairs[0]);
-        let range_2 = Range::from(pairs[1]);
-        if range_1.contained_in(&range_2) || range_2.contained_in(&range_1) {
+        let range_1 = parse_range(pairs[0]);
+        let range_2 = parse_range(pairs[1]);
+        if range_2.contains_box(&range_1) || range_1.contains_box(&range_2) {
             redundant += 1;
         }
     }
@@ -44,38 +29,38 @@ fn part_2(contents: &str) -> u64 {
     let mut overlap = 0;
     for line in contents.lines() {
         let pairs: Vec<&str> = line.split(",").collect();
-        let range_1 = Range::from(pairs[0]);
-        let range_2 = Range::from(pairs[1]);
-        if range_1.overlaps(&range_2) {
+        let range_1 = parse_range(pairs[0]);
+        let range_2 = parse_range(pairs[1]);
+        if range_1.intersects(&range_2) {
             overlap += 1;
         }
     }
     overlap
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 2);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 4);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,11 +1,16 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::VecDeque;
-use std::time::Instant;
 
 #[derive(Debug)]
 struct Instruction {
     num_to_move: u8,
     from_stack_index: usize,
     to_stack_index: usize,
+    /// Index of the line of the input the instruction is on.
+    line: usize,
 }
 
 fn parse_stacks(contents: &str, num_stacks: usize) -> Vec<VecDeque<char>> {
@@ -16,10 +21,10 @@ fn parse_stacks(contents: &str, num_stacks: usize) -> Vec<VecDeque<char>> {
     }
 
     for line in contents.lines().take_while(|x| x.contains("[")) {
-        for stack_index in 0..num_stacks {
+        for (stack_index, stack) in stacks.iter_mut().enumerate() {
             match line.chars().nth(1 + stack_index * 4) {
                 Some(' ') => {}
-                Some(c) => stacks[stack_index].push_back(c),
+                Some(c) => stack.push_back(c),
                 None => {}
             }
         }
@@ -27,30 +32,48 @@ fn parse_stacks(contents: &str, num_stacks: usize) -> Vec<VecDeque<char>> {
     stacks
 }
 
-fn parse_instructions(contents: &str) -> Vec<Instruction> {
-    let mut instructions: Vec<Instruction> = Vec::new();
-    for line in contents.lines().skip_while(|x| !x.starts_with("move")) {
-        let cleaned_line = line
-            .replace("move ", "")
-            .replace("from ", "")
-            .replace("to ", "");
-
-        let raw_numbers: Vec<&str> = cleaned_line.split_whitespace().collect();
-
-        let num_to_move = raw_numbers[0].parse::<u8>().unwrap();
-        let from_stack_index = raw_numbers[1].parse::<usize>().unwrap() - 1;
-        let to_stack_index = raw_numbers[2].parse::<usize>().unwrap() - 1;
-
-        instructions.push(Instruction {
-            num_to_move,
-            from_stack_index,
-            to_stack_index,
-        })
-    }
-    instructions
+fn parse_instruction(line: &str, index: usize) -> Result<Instruction, PuzzleError> {
+    let cleaned_line = line
+        .replace("move ", "")
+        .replace("from ", "")
+        .replace("to ", "");
+
+    let raw_numbers: Vec<&str> = cleaned_line.split_whitespace().collect();
+    let [num_to_move, from_stack, to_stack] = raw_numbers[..] else {
+        return Err(PuzzleError::malformed(
+            "Expected an instruction like \"move 1 from 2 to 1\"",
+        ));
+    };
+    // stacks are numbered from 1
+    let stack_index = |text: &str| match parse_number::<usize>(text)? {
+        0 => Err(PuzzleError::malformed("There is no stack 0")),
+        number => Ok(number - 1),
+    };
+
+    Ok(Instruction {
+        num_to_move: parse_number(num_to_move)?,
+        from_stack_index: stack_index(from_stack)?,
+        to_stack_index: stack_index(to_stack)?,
+        line: index,
+    })
+}
+
+fn parse_instructions(contents: &str) -> Result<Vec<Instruction>, PuzzleError> {
+    contents
+        .lines()
+        .enumerate()
+        .skip_while(|(_, x)| !x.starts_with("move"))
+        .map(|(index, line)| parse_instruction(line, index).map_err(|e| e.on_line(index)))
+        .collect()
 }
 
-fn get_num_stacks(instructions: &Vec<Instruction>) -> usize {
+/// An error for moving more crates off a stack than are on it.
+fn empty_stack(instruction: &Instruction) -> PuzzleError {
+    let stack = instruction.from_stack_index + 1;
+    PuzzleError::malformed(format!("Stack {stack} runs out of crates")).on_line(instruction.line)
+}
+
+fn get_num_stacks(instructions: &[Instruction]) -> usize {
     let mut max_stack_index: usize = 0;
     for instruction in instructions.iter() {
         if instruction.from_stack_index > max_stack_index {
@@ -63,19 +86,24 @@ fn get_num_stacks(instructions: &Vec<Instruction>) -> usize {
     max_stack_index + 1
 }
 
-fn get_stack_tops(stacks: Vec<VecDeque<char>>) -> String {
+fn get_stack_tops(stacks: Vec<VecDeque<char>>) -> Result<String, PuzzleError> {
     let mut top = String::from("");
-    for stack in stacks.iter() {
-        match stack.get(0) {
+    for (stack_index, stack) in stacks.iter().enumerate() {
+        match stack.front() {
             Some(c) => top.push(*c),
-            None => panic!("Stack was empty after all moves"),
+            None => {
+                let stack = stack_index + 1;
+                return Err(PuzzleError::malformed(format!(
+                    "Stack {stack} is empty after all moves"
+                )));
+            }
         }
     }
-    top
+    Ok(top)
 }
 
-fn part_1(contents: &str) -> String {
-    let instructions: Vec<Instruction> = parse_instructions(contents);
+fn part_1(contents: &str) -> Result<String, PuzzleError> {
+    let instructions: Vec<Instruction> = parse_instructions(contents)?;
     let num_stacks: usize = get_num_stacks(&instructions);
     let mut stacks: Vec<VecDeque<char>> = parse_stacks(contents, num_stacks);
 
@@ -83,15 +111,15 @@ fn part_1(contents: &str) -> String {
         for _ in 0..instruction.num_to_move {
             match stacks[instruction.from_stack_index].pop_front() {
                 Some(c) => stacks[instruction.to_stack_index].push_front(c),
-                None => panic!("Could not pop from stack"),
+                None => return Err(empty_stack(instruction)),
             }
         }
     }
     get_stack_tops(stacks)
 }
 
-fn part_2(contents: &str) -> String {
-    let instructions: Vec<Instruction> = parse_instructions(contents);
+fn part_2(contents: &str) -> Result<String, PuzzleError> {
+    let instructions: Vec<Instruction> = parse_instructions(contents)?;
     let num_stacks: usize = get_num_stacks(&instructions);
     let mut stacks: Vec<VecDeque<char>> = parse_stacks(contents, num_stacks);
 
@@ -101,43 +129,40 @@ fn part_2(contents: &str) -> String {
         for _ in 0..instruction.num_to_move {
             match stacks[instruction.from_stack_index].pop_front() {
                 Some(c) => temp_stack.push_front(c),
-                None => panic!("Could not pop from stack"),
+                None => return Err(empty_stack(instruction)),
             }
         }
-        for _ in 0..instruction.num_to_move {
-            match temp_stack.pop_front() {
-                Some(c) => stacks[instruction.to_stack_index].push_front(c),
-                None => panic!("Could not pop from temp stack"),
-            }
+        while let Some(c) = temp_stack.pop_front() {
+            stacks[instruction.to_stack_index].push_front(c);
         }
     }
 
     get_stack_tops(stacks)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), String::from("CMZ"));
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), String::from("MCD"));
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,44 +1,67 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use itertools::Itertools;
 use std::collections::HashSet;
-use std::time::Instant;
 
 fn part_1(contents: &str) -> u32 {
     let chunk_size: usize = 4;
-    let mut end_index: u32 = chunk_size as u32;
-    for (a, b, c, d) in contents.chars().tuple_windows::<(_, _, _, _)>() {
+    for (end_index, (a, b, c, d)) in
+        (chunk_size as u32..).zip(contents.chars().tuple_windows::<(_, _, _, _)>())
+    {
         let unique: HashSet<char> = HashSet::from([a, b, c, d]);
         if unique.len() == chunk_size {
             return end_index;
         }
-        end_index += 1;
     }
     panic!("Should not get here!")
 }
 
 fn part_2(contents: &str) -> u32 {
     let chunk_size: usize = 14;
-    let mut end_index: u32 = chunk_size as u32;
     let chars: Vec<char> = contents.chars().collect();
-    for char_group in chars.windows(chunk_size) {
+    for (end_index, char_group) in (chunk_size as u32..).zip(chars.windows(chunk_size)) {
         let unique: HashSet<&char> = HashSet::from_iter(char_group.iter());
         if unique.len() == chunk_size {
             return end_index;
         }
-        end_index += 1;
     }
     panic!("Should not get here!")
 }
 
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::*;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 7);
-    }
-
     #[rstest]
     #[case("bvwbjplbgvbhsrlpgdmjqwftvncz", 5)]
     #[case("nppdvjthqldpwncqszvftbrmjlhg", 6)]
@@ -47,20 +70,4 @@ mod tests {
     fn test_part_1_examples(#[case] input: &str, #[case] expected: u32) {
         assert_eq!(part_1(input), expected);
     }
-
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 1);
-    }
-}
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
 }
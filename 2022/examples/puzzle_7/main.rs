@@ -1,5 +1,8 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashMap;
-use std::time::Instant;
 
 fn get_folder_sizes(contents: &str) -> HashMap<String, u64> {
     let mut folder_sizes: HashMap<String, u64> = HashMap::new();
@@ -37,7 +40,7 @@ fn get_folder_sizes(contents: &str) -> HashMap<String, u64> {
 fn part_1(contents: &str) -> u64 {
     let folder_sizes = get_folder_sizes(contents);
     let mut answer: u64 = 0;
-    for (key, size) in folder_sizes {
+    for (_key, size) in folder_sizes {
         if size < 100_000 {
             answer += size
         }
@@ -51,7 +54,7 @@ fn part_2(contents: &str) -> u64 {
     let unused_space_required: u64 = 30_000_000;
     let current_space_used = *folder_sizes.get("/").unwrap();
     let mut minimal_folder_size_to_remove: u64 = u64::MAX;
-    for (folder, size) in folder_sizes {
+    for (_folder, size) in folder_sizes {
         if total_space - (current_space_used - size) > unused_space_required
             && size < minimal_folder_size_to_remove
         {
@@ -61,29 +64,29 @@ fn part_2(contents: &str) -> u64 {
     minimal_folder_size_to_remove
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 95437);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 24933642);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,187 +1,54 @@
-use std::collections::HashSet;
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::{viewing_distances, visible_from_edge, Direction4, Grid};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
-fn parse_grid(contents: &str) -> Vec<Vec<u32>> {
-    let mut grid: Vec<Vec<u32>> = Vec::new();
-    for line in contents.lines() {
-        let row: Vec<u32> = line
-            .chars()
-            .collect::<Vec<char>>()
-            .iter()
-            .map(|x| x.to_digit(10).unwrap())
-            .collect();
-        grid.push(row);
-    }
-    grid
-}
-
-enum Direction {
-    LEFT,
-    RIGHT,
-    UP,
-    DOWN,
-}
-
-struct LineOfTrees<'a> {
-    trees: &'a Vec<&'a u32>,
-    row_index: usize,
-    column_index: usize,
-    direction: Direction,
-}
-
-// Looking into the forest from a specific direction along a line
-fn num_visible_in_direction(line: &LineOfTrees, visible: &mut HashSet<(usize, usize)>) {
-    // start tree is on the boundary so always visible
-    visible.insert((line.row_index, line.column_index));
-    let mut min = line.trees[0];
-    for (index, tree) in line.trees.iter().skip(1).enumerate() {
-        if tree > &min {
-            min = *tree;
-            match line.direction {
-                Direction::RIGHT => {
-                    visible.insert((line.row_index, line.column_index + index + 1));
-                }
-                Direction::LEFT => {
-                    visible.insert((line.row_index, line.column_index - index - 1));
-                }
-                Direction::DOWN => {
-                    visible.insert((line.row_index + index + 1, line.column_index));
-                }
-                Direction::UP => {
-                    visible.insert((line.row_index - index - 1, line.column_index));
-                }
-            }
-        }
-    }
+fn parse_grid(contents: &str) -> Result<Grid<u32>, PuzzleError> {
+    Grid::parse(contents, |c| {
+        c.to_digit(10).ok_or_else(|| PuzzleError::malformed(format!("Expected a digit, not {c:?}")))
+    })
 }
 
-// Looking from a specific tree in a line in a given direction
-fn num_visible_in_direction_from_tree(line_of_trees: &LineOfTrees) -> u64 {
-    let mut direction_score = 0;
-    let height = &line_of_trees.trees[0];
-    for tree in line_of_trees.trees.iter().skip(1) {
-        direction_score += 1;
-        if tree >= height {
-            break;
-        }
-    }
-    direction_score
+fn part_1(grid: &Grid<u32>) -> u64 {
+    let edges = Direction4::ALL.map(|direction| visible_from_edge(grid, direction));
+    grid.bounds()
+        .points()
+        .filter(|&tree| edges.iter().any(|visible| visible[tree]))
+        .count() as u64
 }
 
-fn part_1(contents: &str) -> u64 {
-    let grid = parse_grid(contents);
-    let mut visible: HashSet<(usize, usize)> = HashSet::new();
-    for (row_index, row) in grid.iter().enumerate() {
-        let to_right = LineOfTrees {
-            trees: &row.iter().map(|x| x).collect(),
-            row_index: row_index,
-            column_index: 0,
-            direction: Direction::RIGHT,
-        };
-        num_visible_in_direction(&to_right, &mut visible);
-
-        let to_left = LineOfTrees {
-            trees: &row.iter().rev().collect(),
-            row_index: row_index,
-            column_index: row.len() - 1,
-            direction: Direction::LEFT,
-        };
-        num_visible_in_direction(&to_left, &mut visible);
-    }
-    // ignore first and last columns as left/right cover them
-    for column_index in 1..grid[0].len() - 1 {
-        let to_bottom = LineOfTrees {
-            trees: &grid.iter().map(|x| &x[column_index]).collect(),
-            row_index: 0,
-            column_index: column_index,
-            direction: Direction::DOWN,
-        };
-        num_visible_in_direction(&to_bottom, &mut visible);
-
-        let to_top = LineOfTrees {
-            trees: &grid.iter().rev().map(|x| &x[column_index]).collect(),
-            row_index: grid.len() - 1,
-            column_index: column_index,
-            direction: Direction::UP,
-        };
-        num_visible_in_direction(&to_top, &mut visible);
-    }
-    visible.len() as u64
+fn part_2(grid: &Grid<u32>) -> Result<u64, PuzzleError> {
+    let distances = Direction4::ALL.map(|direction| viewing_distances(grid, direction));
+    grid.bounds()
+        .points()
+        .map(|tree| distances.iter().map(|distance| distance[tree] as u64).product())
+        .max()
+        .ok_or_else(|| PuzzleError::malformed("Expected at least one tree"))
 }
 
-fn part_2(contents: &str) -> u64 {
-    let grid = parse_grid(contents);
-    let mut best_scenic_score: u64 = 0;
-    for row_index in 1..grid.len() - 1 {
-        for column_index in 1..grid[row_index].len() - 1 {
-            let mut scenic_score = 1;
-            let to_right = LineOfTrees {
-                trees: &grid[row_index][column_index..].iter().collect(),
-                row_index: row_index,
-                column_index: column_index,
-                direction: Direction::RIGHT,
-            };
-            scenic_score *= num_visible_in_direction_from_tree(&to_right);
-
-            let to_left = LineOfTrees {
-                trees: &grid[row_index][0..=column_index].iter().rev().collect(),
-                row_index: row_index,
-                column_index: column_index,
-                direction: Direction::LEFT,
-            };
-            scenic_score *= num_visible_in_direction_from_tree(&to_left);
+pub struct Solution;
 
-            let to_bottom = LineOfTrees {
-                trees: &grid[row_index..].iter().map(|x| &x[column_index]).collect(),
-                row_index: row_index,
-                column_index: column_index,
-                direction: Direction::DOWN,
-            };
-            scenic_score *= num_visible_in_direction_from_tree(&to_bottom);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
 
-            let to_top = LineOfTrees {
-                trees: &grid[0..=row_index]
-                    .iter()
-                    .map(|x| &x[column_index])
-                    .rev()
-                    .collect(),
-                row_index: row_index,
-                column_index: column_index,
-                direction: Direction::UP,
-            };
-            scenic_score *= num_visible_in_direction_from_tree(&to_top);
+    type Params = NoParams;
+    type Parsed = Grid<u32>;
 
-            if scenic_score > best_scenic_score {
-                best_scenic_score = scenic_score;
-            }
-        }
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<Grid<u32>, PuzzleError> {
+        parse_grid(input)
     }
-    best_scenic_score
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 21);
+    fn part_1(&self, grid: &Grid<u32>) -> Result<Answer, PuzzleError> {
+        Ok(part_1(grid).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 8);
+    fn part_2(&self, grid: &Grid<u32>) -> Result<Answer, PuzzleError> {
+        Ok(part_2(grid)?.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,50 +1,35 @@
-use core::num;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, parse_number, PuzzleError};
+use aoc_utils::geometry::Direction4;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashSet;
-use std::time::Instant;
+use std::str::FromStr;
 
 #[derive(Debug)]
-enum Direction {
-    UP,
-    DOWN,
-    LEFT,
-    RIGHT,
-}
-
-impl From<&str> for Direction {
-    fn from(raw: &str) -> Self {
-        match raw {
-            "D" => Direction::DOWN,
-            "U" => Direction::UP,
-            "L" => Direction::LEFT,
-            "R" => Direction::RIGHT,
-            _ => panic!("Can't parse direction!"),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Command {
-    direction: Direction,
+pub struct Command {
+    direction: Direction4,
     steps: i32,
 }
 
-impl From<Vec<&str>> for Command {
-    fn from(raw: Vec<&str>) -> Self {
-        let direction = Direction::from(raw[0]);
-        let steps = raw[1].parse::<i32>().unwrap();
-        Command { direction, steps }
+impl FromStr for Command {
+    type Err = PuzzleError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (direction, steps) = line.split_once(' ').ok_or_else(|| {
+            PuzzleError::malformed(format!("Expected a direction and steps, not {line:?}"))
+        })?;
+        Ok(Command {
+            direction: direction.parse()?,
+            steps: parse_number(steps).map_err(|e| e.at_column(direction.len() + 1))?,
+        })
     }
 }
 
-impl Direction {
-    fn make_move(&self, knot: &mut (i32, i32)) {
-        match self {
-            Direction::DOWN => knot.1 -= 1,
-            Direction::UP => knot.1 += 1,
-            Direction::LEFT => knot.0 -= 1,
-            Direction::RIGHT => knot.0 += 1,
-        };
-    }
+fn make_move(direction: Direction4, knot: &mut (i32, i32)) {
+    let step = direction.unit_vector::<i32>();
+    knot.0 += step.x;
+    knot.1 += step.y;
 }
 
 fn update_knot_from_delta(delta: (i32, i32), knot: (i32, i32)) -> (i32, i32) {
@@ -63,17 +48,16 @@ fn update_knot_from_delta(delta: (i32, i32), knot: (i32, i32)) -> (i32, i32) {
     }
 }
 
-fn knot_mover(contents: &str, num_knots: usize) -> u64 {
+fn knot_mover(commands: &[Command], num_knots: usize) -> u64 {
     let mut knots: Vec<(i32, i32)> = Vec::new();
     for _ in 0..num_knots {
         knots.push((0, 0));
     }
     let mut tail_knot_positions: HashSet<(i32, i32)> = HashSet::from([(0, 0)]);
 
-    for line in contents.lines() {
-        let command = Command::from(line.split_whitespace().collect::<Vec<&str>>());
+    for command in commands {
         for _ in 0..command.steps {
-            command.direction.make_move(&mut knots[0]);
+            make_move(command.direction, &mut knots[0]);
 
             for index in 0..num_knots - 1 {
                 let previous_knot = knots[index];
@@ -87,34 +71,28 @@ fn knot_mover(contents: &str, num_knots: usize) -> u64 {
     tail_knot_positions.len() as u64
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use rstest::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type Params = NoParams;
+    type Parsed = Vec<Command>;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(knot_mover(include_str!("./example_1.txt"), 2), 13);
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<Vec<Command>, PuzzleError> {
+        parse_lines(input, |line| line.parse())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(knot_mover(include_str!("./example_1.txt"), 10), 1);
+    fn part_1(&self, commands: &Vec<Command>) -> Result<Answer, PuzzleError> {
+        Ok(knot_mover(commands, 2).into())
     }
 
-    #[test]
-    fn test_part_2_larger_example() {
-        assert_eq!(knot_mover(include_str!("./example_2.txt"), 10), 36);
+    fn part_2(&self, commands: &Vec<Command>) -> Result<Answer, PuzzleError> {
+        Ok(knot_mover(commands, 10).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = knot_mover(contents, 2);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = knot_mover(contents, 10);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
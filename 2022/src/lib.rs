@@ -0,0 +1,83 @@
+use aoc_utils::puzzle::Solver;
+
+#[path = "../examples/puzzle_1/main.rs"]
+pub mod puzzle_1;
+#[path = "../examples/puzzle_2/main.rs"]
+pub mod puzzle_2;
+#[path = "../examples/puzzle_3/main.rs"]
+pub mod puzzle_3;
+#[path = "../examples/puzzle_4/main.rs"]
+pub mod puzzle_4;
+#[path = "../examples/puzzle_5/main.rs"]
+pub mod puzzle_5;
+#[path = "../examples/puzzle_6/main.rs"]
+pub mod puzzle_6;
+#[path = "../examples/puzzle_7/main.rs"]
+pub mod puzzle_7;
+#[path = "../examples/puzzle_8/main.rs"]
+pub mod puzzle_8;
+#[path = "../examples/puzzle_9/main.rs"]
+pub mod puzzle_9;
+#[path = "../examples/puzzle_10/main.rs"]
+pub mod puzzle_10;
+#[path = "../examples/puzzle_11/main.rs"]
+pub mod puzzle_11;
+#[path = "../examples/puzzle_12/main.rs"]
+pub mod puzzle_12;
+#[path = "../examples/puzzle_13/main.rs"]
+pub mod puzzle_13;
+#[path = "../examples/puzzle_14/main.rs"]
+pub mod puzzle_14;
+#[path = "../examples/puzzle_15/main.rs"]
+pub mod puzzle_15;
+#[path = "../examples/puzzle_16/main.rs"]
+pub mod puzzle_16;
+#[path = "../examples/puzzle_17/main.rs"]
+pub mod puzzle_17;
+#[path = "../examples/puzzle_18/main.rs"]
+pub mod puzzle_18;
+#[path = "../examples/puzzle_19/main.rs"]
+pub mod puzzle_19;
+#[path = "../examples/puzzle_20/main.rs"]
+pub mod puzzle_20;
+#[path = "../examples/puzzle_21/main.rs"]
+pub mod puzzle_21;
+#[path = "../examples/puzzle_22/main.rs"]
+pub mod puzzle_22;
+#[path = "../examples/puzzle_23/main.rs"]
+pub mod puzzle_23;
+#[path = "../examples/puzzle_24/main.rs"]
+pub mod puzzle_24;
+#[path = "../examples/puzzle_25/main.rs"]
+pub mod puzzle_25;
+
+/// Every solution for the year, in day order.
+pub fn puzzles() -> Vec<&'static dyn Solver> {
+    vec![
+        &puzzle_1::Solution,
+        &puzzle_2::Solution,
+        &puzzle_3::Solution,
+        &puzzle_4::Solution,
+        &puzzle_5::Solution,
+        &puzzle_6::Solution,
+        &puzzle_7::Solution,
+        &puzzle_8::Solution,
+        &puzzle_9::Solution,
+        &puzzle_10::Solution,
+        &puzzle_11::Solution,
+        &puzzle_12::Solution,
+        &puzzle_13::Solution,
+        &puzzle_14::Solution,
+        &puzzle_15::Solution,
+        &puzzle_16::Solution,
+        &puzzle_17::Solution,
+        &puzzle_18::Solution,
+        &puzzle_19::Solution,
+        &puzzle_20::Solution,
+        &puzzle_21::Solution,
+        &puzzle_22::Solution,
+        &puzzle_23::Solution,
+        &puzzle_24::Solution,
+        &puzzle_25::Solution,
+    ]
+}
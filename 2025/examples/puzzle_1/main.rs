@@ -1,12 +1,29 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
-fn part_1(contents: &str) -> u32 {
+/// The direction of a rotation and the number of clicks.
+fn parse_rotation(line: &str) -> Result<(char, i32), PuzzleError> {
+    let mut chars = line.chars();
+    let instruction = chars
+        .next()
+        .ok_or_else(|| PuzzleError::malformed("Expected a rotation"))?;
+    let value = parse_number(chars.as_str()).map_err(|e| e.at_column(1))?;
+    Ok((instruction, value))
+}
+
+fn unknown_instruction(instruction: char, index: usize) -> PuzzleError {
+    PuzzleError::malformed(format!("Unknown instruction {instruction:?}, expected L or R"))
+        .on_line(index)
+        .at_column(0)
+}
+
+fn part_1(contents: &str) -> Result<u32, PuzzleError> {
     let mut position: i32 = 50;
     let mut zero_visits: u32 = 0;
-    let mut lines = contents.lines();
-    while let Some(line) = lines.next() {
-        let instruction = line.chars().next().unwrap();
-        let value: i32 = line[1..].parse().unwrap();
+    for (index, line) in contents.lines().enumerate() {
+        let (instruction, value) = parse_rotation(line).map_err(|e| e.on_line(index))?;
         match instruction {
             'R' => {
                 position = (position + value) % 100;
@@ -14,22 +31,20 @@ fn part_1(contents: &str) -> u32 {
             'L' => {
                 position = (position - value) % 100;
             }
-            _ => panic!("Unknown instruction"),
+            _ => return Err(unknown_instruction(instruction, index)),
         }
         if position == 0 {
             zero_visits += 1;
         }
     }
-    return zero_visits
+    Ok(zero_visits)
 }
 
-fn part_2(contents: &str) -> u32 {
+fn part_2(contents: &str) -> Result<u32, PuzzleError> {
     let mut position: i32 = 50;
     let mut zero_visits: u32 = 0;
-    let mut lines = contents.lines();
-    while let Some(line) = lines.next() {
-        let instruction = line.chars().next().unwrap();
-        let value: i32 = line[1..].parse().unwrap();
+    for (index, line) in contents.lines().enumerate() {
+        let (instruction, value) = parse_rotation(line).map_err(|e| e.on_line(index))?;
         match instruction {
             'R' => {
                 let to_zero = (100 - position) % 100;
@@ -52,34 +67,35 @@ fn part_2(contents: &str) -> u32 {
                 }
                 position = (position - value) % 100;
             }
-            _ => panic!("Unknown instruction"),
+            _ => return Err(unknown_instruction(instruction, index)),
         }
     }
-    return zero_visits
+    Ok(zero_visits)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 3);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 6);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,6 +1,8 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use itertools::Itertools;
-use nalgebra::{DMatrix, SVD};
 use microlp::{Problem, OptimizationDirection, ComparisonOp};
 
 
@@ -10,13 +12,11 @@ fn find_minimal_presses(line: &str) -> u32 {
     let raw_buttons = &components[1..components.len() - 1];
 
     // Parse the target lights
-    let mut power: u32 = 0;
     let mut lights: u32 = 0;
-    for c in raw_lights[1..raw_lights.len() - 1].chars() {
+    for (power, c) in (0..).zip(raw_lights[1..raw_lights.len() - 1].chars()) {
         if c == '#' {
             lights += 2u32.pow(power)
         }
-        power += 1;
     }
 
     // Parse the buttons we can press
@@ -38,7 +38,7 @@ fn find_minimal_presses(line: &str) -> u32 {
             return length as u32
         }
     }
-    return 0;
+    0
     
     /* Slower iniital implementation
     // General idea:
@@ -83,7 +83,7 @@ fn joltages(line: &str) -> f64 {
     let jolts = raw_jolts[1..raw_jolts.len() - 1].split(",").map(|n| n.parse::<i32>().unwrap()).collect::<Vec<i32>>();
 
     let num_rows = jolts.len();
-    let num_columns = raw_buttons.len();
+    let _num_columns = raw_buttons.len();
     let max_presses = jolts.iter().max().unwrap();
 
     let mut problem = Problem::new(OptimizationDirection::Minimize);
@@ -93,7 +93,7 @@ fn joltages(line: &str) -> f64 {
     ).collect();
 
     let mut constraints = vec![vec![]; num_rows];
-    for (variable_index, raw_button) in raw_buttons.into_iter().enumerate() {
+    for (variable_index, raw_button) in raw_buttons.iter().enumerate() {
         for c in raw_button[1..raw_button.len() - 1].split(",") {
             let row_index = c.parse::<usize>().unwrap();
             constraints[row_index].push((variables[variable_index], 1.0))
@@ -105,7 +105,7 @@ fn joltages(line: &str) -> f64 {
     });
     let solution = problem.solve().unwrap();
     //println!("{:?}", solution.objective());
-    return solution.objective();
+    solution.objective()
 
     /* Pseudo-inverse approach won't give integer solutions
     let jolts = raw_jolts[1..raw_jolts.len() - 1].split(",").map(|n| n.parse::<f64>().unwrap()).collect::<Vec<f64>>();
@@ -128,16 +128,44 @@ fn joltages(line: &str) -> f64 {
 }
 
 fn part_1(contents: &str) -> u32 {
-    return contents.lines().map(|line| find_minimal_presses(line)).sum();
+    contents.lines().map(find_minimal_presses).sum()
 }
 
 fn part_2(contents: &str) -> f64 {
-    return contents.lines().map(|line| joltages(line)).sum();
+    contents.lines().map(joltages).sum()
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
+    }
+
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(Answer::try_from(part_2(input))?)
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::input::load_example;
 
     #[test]
     fn test_part_1_single_line_1() {
@@ -159,11 +187,6 @@ mod tests {
         assert_eq!(find_minimal_presses("[##.#####..] (1,9) (0,3,7,8,9) (5,8,9) (0,1,3,7,9) (0,3,4,5) (2,3,5) (0,3,4,8) (2,3,4,5,6,7,8,9) (2,6) (0,1,2,3,6,8,9) {55,28,32,72,32,43,27,34,73,75}"), 8);
     }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 7);
-    }
-
     #[test]
     fn test_part_2_single_line_1() {
         assert_eq!(joltages("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"), 10.0);
@@ -181,18 +204,9 @@ mod tests {
 
     #[test]
     fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 33.0);
+        let Some(example) = load_example(2025, 10, "example.txt") else {
+            return;
+        };
+        assert_eq!(part_2(&example), 33.0);
     }
-
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve puzzle", duration);
-}
\ No newline at end of file
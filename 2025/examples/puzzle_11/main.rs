@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::{HashMap, HashSet, VecDeque};
 
 fn part_1(contents: &str) -> u32 {
@@ -22,11 +25,11 @@ fn part_1(contents: &str) -> u32 {
     }
     
     let start_index = string_to_index.get(start).unwrap();
-    let end_index = string_to_index.get(end).unwrap();
+    let _end_index = string_to_index.get(end).unwrap();
     let mut answer = 0;
     let mut queue = VecDeque::new();
     queue.push_front((start_index, HashSet::from([start_index]), 1));
-    while queue.len() > 0 {
+    while !queue.is_empty() {
         let (index, seen, num_paths) = queue.pop_front().unwrap();
         let to_nodes = connections.get(index);
         match to_nodes {
@@ -40,7 +43,7 @@ fn part_1(contents: &str) -> u32 {
             }
         }
     }
-    return answer;
+    answer
 }
 
 fn part_2(contents: &str) -> u64 {
@@ -70,7 +73,7 @@ fn part_2(contents: &str) -> u64 {
     let mut current: HashMap<(usize, (bool, bool)), u64> = HashMap::from([
         ((*start_index, (false, false)), 1)
     ]);
-    while current.len() > 0 {
+    while !current.is_empty() {
         let mut next: HashMap<(usize, (bool, bool)), u64> = HashMap::new();
         for ((index, (seen_dac, seen_fft)), num_paths) in current.iter() {
             let to_nodes = connections.get(index);
@@ -100,31 +103,32 @@ fn part_2(contents: &str) -> u64 {
         }
         current = next;
     }
-    return answer;
+    answer
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example_1.txt")), 5);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 11;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example_2.txt")), 2);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
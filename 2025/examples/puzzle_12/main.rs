@@ -1,41 +1,49 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_number, PuzzleError};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
-fn solve_line(line: &str, sizes: &Vec<u32>) -> u32 {
-    let comps: Vec<&str> = line.split(": ").collect();
-    let grid_size: Vec<u32> = comps[0].split("x").map(|x| x.parse::<u32>().unwrap()).collect();
-    let counts: Vec<u32> = comps[1].split(" ").map(|x| x.parse::<u32>().unwrap()).collect();
+fn solve_line(line: &str, sizes: &[u32]) -> Result<u32, PuzzleError> {
+    let Some((grid, counts)) = line.split_once(": ") else {
+        return Err(PuzzleError::malformed(format!("Expected a region, not {line:?}")));
+    };
+    let grid_size: Vec<u32> = grid.split("x").map(parse_number).collect::<Result<_, _>>()?;
+    let counts: Vec<u32> = counts.split(" ").map(parse_number).collect::<Result<_, _>>()?;
+    if grid_size.len() != 2 || counts.len() > sizes.len() {
+        return Err(PuzzleError::malformed(format!("Expected a region, not {line:?}")));
+    }
     
     // Too small check - if there are more squares required than the grid size
     // then even perfect tesselatation into the grid would not give enough space
     let area = grid_size[0] * grid_size[1];
     let minimal_required_area: u32 = counts.iter().enumerate().map(|(i, c)| c * sizes[i]).sum();
     if minimal_required_area > area {
-        return 0;
+        return Ok(0);
     }
 
     // Big enough check - the grid is naively big enough if we can put each
     // block into a separate 3x3 space without having to do any tessalation 
     let total_tiles = counts.iter().sum();
     if (grid_size[0] / 3) * (grid_size[1] / 3) >= total_tiles {
-        return 1;
+        return Ok(1);
     }
-    panic!("This is too hard to solve...");
+    Err(PuzzleError::unsupported("This is too hard to solve..."))
 }
 
-fn part_1(contents: &str) -> u32 {
+fn part_1(contents: &str) -> Result<u32, PuzzleError> {
     let lines: Vec<&str> = contents.lines().collect();
     let mut sizes: Vec<u32> = Vec::new();
     let mut current_size = 0;
     let mut first_solve = false;
     let mut answer = 0;
-    for line in lines.iter().skip(1) {
+    for (index, line) in lines.iter().enumerate().skip(1) {
         if line.is_empty() { continue }
         else if line.contains('x') {
             if !first_solve {
                 sizes.push(current_size);
                 first_solve = true;
             }
-            answer += solve_line(line, &sizes);
+            answer += solve_line(line, &sizes).map_err(|e| e.on_line(index))?;
         } else if line.contains(':') {
             sizes.push(current_size);
             current_size = 0;
@@ -47,14 +55,56 @@ fn part_1(contents: &str) -> u32 {
             }
         }     
     }
-    return answer;
+    Ok(answer)
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve puzzle", duration);
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 12;
+    const HAS_PART_2: bool = false;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_line() {
+        let sizes = [7, 7];
+        assert_eq!(solve_line("4x4: 2 1", &sizes), Ok(0));
+        assert_eq!(solve_line("6x6: 2 2", &sizes), Ok(1));
+        assert_eq!(
+            solve_line("5x5: 2 1", &sizes),
+            Err(PuzzleError::unsupported("This is too hard to solve..."))
+        );
+        assert!(solve_line("4x4 2 1", &sizes).is_err());
+        assert!(solve_line("4xfour: 2 1", &sizes).is_err());
+    }
+
+    #[test]
+    fn test_unsupported_region_is_reported_with_its_line() {
+        let input = "0:\n###\n#..\n###\n\n4x4: 1\n5x5: 3\n";
+        assert_eq!(
+            part_1(input).unwrap_err().to_string(),
+            "Unsupported input at line 7: This is too hard to solve..."
+        );
+    }
 }
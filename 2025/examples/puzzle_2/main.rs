@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 fn part_1(contents: &str) -> u64 {
     let mut answer: u64 = 0;
@@ -37,7 +40,7 @@ fn part_1(contents: &str) -> u64 {
         };
     });
 
-    return answer
+    answer
 }
 
 fn part_2(contents: &str) -> u64 {
@@ -57,8 +60,8 @@ fn part_2(contents: &str) -> u64 {
             // To cover the 95-115 case we need to deal with 95-99 and 100-115
             // separately since both 99 and 111 are valid mirrors with a single
             // digit being repeated.
-            let num_start_repeats = (start_str.len() / digit_len) as usize;
-            let num_end_repeats = (end_str.len() / digit_len) as usize;
+            let num_start_repeats = start_str.len() / digit_len ;
+            let num_end_repeats = end_str.len() / digit_len ;
             if (start_str.len() % digit_len == 0) && (num_start_repeats > 1) {
                 let mirror_start: u64 = start_str[..digit_len].parse().unwrap();
                 let mirror_end: u64 = if start_str.len() == end_str.len() {
@@ -100,31 +103,32 @@ fn part_2(contents: &str) -> u64 {
         answer += mirrors.iter().sum::<u64>();
     });
 
-    return answer
+    answer
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 1227775554);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 4174379265);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
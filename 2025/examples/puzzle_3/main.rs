@@ -1,10 +1,12 @@
-use std::time::Instant;
-use std::ops::IndexMut;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 fn part_1(contents: &str) -> u32 {
     let mut answer: u32 = 0;
-    let mut lines = contents.lines();
-    while let Some(line) = lines.next() {
+    let lines = contents.lines();
+    for line in lines {
         let max_index = line.len() - 1;
         let digits: Vec<_> = line.chars().collect();
         let mut current_index: usize = 1;
@@ -12,7 +14,7 @@ fn part_1(contents: &str) -> u32 {
         let mut current_end = digits[1];
         while current_index <= max_index {
             let digit = digits[current_index];
-            if (digit > current_start) && (current_index <= max_index - 1) {
+            if (digit > current_start) && (current_index < max_index) {
                 current_start = digit;
                 current_end = digits[current_index + 1];
             } else if digit > current_end {
@@ -22,14 +24,14 @@ fn part_1(contents: &str) -> u32 {
         }
         answer += current_start.to_digit(10).unwrap() * 10 + current_end.to_digit(10).unwrap();
     }
-    return answer;
+    answer
 }
 
 fn part_2(contents: &str) -> u64 {
     let voltage_size = 12;
     let mut answer: u64 = 0;
-    let mut lines = contents.lines();
-    while let Some(line) = lines.next() {
+    let lines = contents.lines();
+    for line in lines {
         let max_index = line.len() - 1;
         let digits: Vec<_> = line.chars().collect();
         let mut current_index: usize = 1;
@@ -63,31 +65,32 @@ fn part_2(contents: &str) -> u64 {
 
         answer += best;
     }
-    return answer;
+    answer
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 357);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 3121910778619);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
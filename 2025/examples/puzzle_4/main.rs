@@ -1,64 +1,51 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::automaton::{Automaton, Square8};
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::{BitGrid, Grid, Point2D};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Part, Puzzle, Variant};
 use std::collections::HashSet;
 
-fn parse(contents: &str) -> HashSet<(i32, i32)> {
-    let lines: Vec<&str> = contents.lines().collect();    
-    let mut locations = HashSet::new();
-    let mut row = 0;
-    for line in lines {
-        for (col, c) in line.chars().enumerate() {
-            if c == '@' {
-                locations.insert((row as i32, col as i32));
-            }
-        }
-        row += 1;
-    }
-    return locations;
+/// The rolls as points, for the approaches which look them up in a set.
+fn locations(grid: &Grid<u8>) -> HashSet<Point2D<i32>> {
+    grid.find_all(&1)
+        .map(|point| Point2D::new(point.x as i32, point.y as i32))
+        .collect()
 }
 
-fn part_1(contents: &str) -> u32 {
-    let locations = parse(contents);
+fn part_1(grid: &Grid<u8>) -> u32 {
+    let locations = locations(grid);
     let mut answer = 0;
     for location in &locations {
-        let (row, col) = location;
-        let mut neighbours = 0;
-        let combos = [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)];
-        for (dr, dc) in &combos {
-            let new_row = row + dr;
-            let new_col = col + dc;
-            if locations.contains(&(new_row, new_col)) {
-                neighbours += 1;
-            }
-        }
+        let neighbours = location
+            .neighbours()
+            .iter()
+            .filter(|neighbour| locations.contains(neighbour))
+            .count();
         if neighbours <= 3 {
             answer += 1;
         }
     }
-    return answer;
+    answer
 }
 
-fn part_2(contents: &str) -> u32 {
-    let mut locations = parse(contents);
+fn part_2(grid: &Grid<u8>) -> u32 {
+    let mut locations = locations(grid);
     let mut answer = 0;
     let mut size = locations.len();
-    let combos = [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (-1, 1), (1, -1), (1, 1)];
     loop {
-        let mut new_locations: HashSet<(i32, i32)> = HashSet::new();
+        let mut new_locations: HashSet<Point2D<i32>> = HashSet::new();
 
         for location in &locations {
-            let (row, col) = location;
-            let mut neighbours = 0;
-            for (dr, dc) in &combos {
-                let new_row = row + dr;
-                let new_col = col + dc;
-                if locations.contains(&(new_row, new_col)) {
-                    neighbours += 1;
-                }
-            }
+            let neighbours = location
+                .neighbours()
+                .iter()
+                .filter(|neighbour| locations.contains(neighbour))
+                .count();
             if neighbours <= 3 {
                 answer += 1;
             } else {
-                new_locations.insert((*row, *col));
+                new_locations.insert(*location);
             }
         }
         if new_locations.len() == size {
@@ -68,67 +55,31 @@ fn part_2(contents: &str) -> u32 {
         locations = new_locations;
     }
 
-    return answer;
+    answer
 }
 
-
-fn parse_fast(lines: Vec<&str>, num_rows: usize, num_cols: usize) -> Vec<u8> {
-    // Store locations as flat vector for speed
-    let mut locations = vec![0u8; num_rows * num_cols];
-    let mut row = 0;
-    for line in lines {
-        for (col, c) in line.chars().enumerate() {
-            if c == '@' {
-                locations[row * num_cols + col] = 1;
-            }
-        }
-        row += 1;
-    }
-    return locations;
+fn parse(contents: &str) -> Result<Grid<u8>, PuzzleError> {
+    // Store locations as a flat grid for speed
+    Grid::parse(contents, |c| Ok(u8::from(c == '@')))
 }
 
-fn part_1_fast(contents: &str) -> u32 {
-    let lines: Vec<&str> = contents.lines().collect();    
-    let num_rows = lines.len();
-    let num_cols = lines[0].len();
-    let locations = parse_fast(lines, num_rows, num_cols);
-
+fn part_1_fast(locations: &Grid<u8>) -> u32 {
     let mut answer = 0;
-    for (index, _) in locations.iter().enumerate().filter(|&(_, &v)| v == 1) {;
-        let row = index / num_cols;
-        let col = index % num_cols;
-        let mut neighbours = 0;
-        let combos = [(-1isize, 0isize), (1, 0), (0, -1), (0, 1),
-                      (-1, -1), (-1, 1), (1, -1), (1, 1)];
-        for (dr, dc) in &combos {
-            let new_row = row as isize + dr;
-            let new_col = col as isize + dc;
-            // Bounds check using signed values
-            if new_row < 0 || new_col < 0 || new_row >= num_rows as isize || new_col >= num_cols as isize {
-                continue;
-            }
-            let neighbour_index = (new_row as usize) * num_cols + (new_col as usize);
-            if locations[neighbour_index] == 1 {
-                neighbours += 1;
-            }
-        }
+    for location in locations.find_all(&1) {
+        let neighbours = locations
+            .neighbours(&location)
+            .filter(|neighbour| locations[*neighbour] == 1)
+            .count();
         if neighbours <= 3 {
             answer += 1;
         }
     }
-    return answer;
+    answer
 }
 
-fn part_2_fast(contents: &str) -> u32 {
-    let lines: Vec<&str> = contents.lines().collect();    
-    let num_rows = lines.len();
-    let num_cols = lines[0].len();
-    let mut locations = parse_fast(lines, num_rows, num_cols);
-
-    let combos = [
-        (-1isize, 0isize), (1, 0), (0, -1), (0, 1),
-        (-1, -1), (-1, 1), (1, -1), (1, 1)
-    ];
+fn part_2_fast(grid: &Grid<u8>) -> u32 {
+    let bounds = grid.bounds();
+    let mut locations = grid.cells().to_vec();
 
     let mut answer = 0;
     let mut mask = locations.iter().enumerate().filter(|&(_, &v)| v == 1).map(|(i, _)| i).collect::<Vec<usize>>();
@@ -139,22 +90,10 @@ fn part_2_fast(contents: &str) -> u32 {
         let mut new_mask = Vec::new();
         
         for index in mask {
-            let row = index / num_cols;
-            let col = index % num_cols;
-            let mut neighbours = 0;
-            
-            for (dr, dc) in &combos {
-                let new_row = row as isize + dr;
-                let new_col = col as isize + dc;
-                // Bounds check using signed values
-                if new_row < 0 || new_col < 0 || new_row >= num_rows as isize || new_col >= num_cols as isize {
-                    continue;
-                }
-                let neighbour_index = (new_row as usize) * num_cols + (new_col as usize);
-                if locations[neighbour_index] == 1 {
-                    neighbours += 1;
-                }
-            }
+            let neighbours = bounds
+                .neighbours(&bounds.point(index))
+                .filter(|neighbour| locations[bounds.index(neighbour)] == 1)
+                .count();
             if neighbours <= 3 {
                 answer += 1;
                 new_locations[index] = 0;
@@ -168,40 +107,23 @@ fn part_2_fast(contents: &str) -> u32 {
         locations = new_locations;
         mask = new_mask;
     }
-    return answer;
+    answer
 }
 
-fn part_2_faster(contents: &str) -> u32 {
-    let lines: Vec<&str> = contents.lines().collect();    
-    let num_rows = lines.len();
-    let num_cols = lines[0].len();
-    let mut locations = parse_fast(lines, num_rows, num_cols);
-
-    // From (0, 0) = index 0 these are the offsets to check for neighbours in 
-    // a flat vector. We precompute these for every location to avoid
-    // recalculating them every iteration.
-    let combos = [
-        (-1isize, 0isize), (1, 0), (0, -1), (0, 1),
-        (-1, -1), (-1, 1), (1, -1), (1, 1)
-    ];
-
-    let mut indexes_to_check: Vec<Vec<usize>> = Vec::new();
-    for index in 0..locations.len() {
-        let row = index / num_cols;
-        let col = index % num_cols;
-        let mut neighbour_indexes: Vec<usize> = Vec::new();
-        for (dr, dc) in &combos {
-            let new_row = row as isize + dr;
-            let new_col = col as isize + dc;
-            // Bounds check using signed values
-            if new_row < 0 || new_col < 0 || new_row >= num_rows as isize || new_col >= num_cols as isize {
-                continue;
-            }
-            let neighbour_index = (new_row as usize) * num_cols + (new_col as usize);
-            neighbour_indexes.push(neighbour_index);
-        }
-        indexes_to_check.push(neighbour_indexes);
-    }
+fn part_2_faster(grid: &Grid<u8>) -> u32 {
+    let bounds = grid.bounds();
+    let mut locations = grid.cells().to_vec();
+
+    // The indexes of the neighbours of every location in the flat vector.
+    // We precompute these to avoid recalculating them every iteration.
+    let indexes_to_check: Vec<Vec<usize>> = (0..bounds.len())
+        .map(|index| {
+            bounds
+                .neighbours(&bounds.point(index))
+                .map(|neighbour| bounds.index(&neighbour))
+                .collect()
+        })
+        .collect();
 
     let mut answer = 0;
     let mut mask = locations.iter().enumerate().filter(|&(_, &v)| v == 1).map(|(i, _)| i).collect::<Vec<usize>>();
@@ -233,41 +155,77 @@ fn part_2_faster(contents: &str) -> u32 {
         locations = new_locations;
         mask = new_mask;
     }
-    return answer;
+    answer
+}
+
+fn part_2_automaton(grid: &Grid<u8>) -> u32 {
+    let rolls = grid.iter().map(|(point, &roll)| (point, roll == 1));
+    // A roll is removed when it has fewer than four neighbours, all at once
+    let mut automaton = Automaton::new(Square8, rolls, |roll, neighbours: &[bool]| {
+        roll && neighbours.iter().filter(|&&other| other).count() > 3
+    });
+    automaton.run_to_fixed_point();
+    (automaton.populations()[0] - automaton.population()) as u32
+}
+
+fn part_2_bitboard(grid: &Grid<u8>) -> u32 {
+    let mut rolls = BitGrid::from_grid(grid, |&roll| roll == 1);
+
+    let mut answer = 0;
+    loop {
+        // Every roll with fewer than four neighbours is removed at once
+        let removable = rolls.and(&rolls.neighbour_counts().matching(|count| count < 4));
+        let removed = removable.count() as u32;
+        if removed == 0 {
+            break;
+        }
+        answer += removed;
+        rolls = rolls.and_not(&removable);
+    }
+    answer
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 13);
+    type Params = NoParams;
+    type Parsed = Grid<u8>;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<Grid<u8>, PuzzleError> {
+        parse(input)
     }
 
-    #[test]
-    fn test_part_1_fast_example() {
-        assert_eq!(part_1_fast(include_str!("./example.txt")), 13);
+    fn part_1(&self, grid: &Grid<u8>) -> Result<Answer, PuzzleError> {
+        Ok(part_1_fast(grid).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 43);
+    fn part_2(&self, grid: &Grid<u8>) -> Result<Answer, PuzzleError> {
+        Ok(part_2_bitboard(grid).into())
     }
 
-    #[test]
-    fn test_part_2_fast_example() {
-        assert_eq!(part_2_fast(include_str!("./example.txt")), 43);
+    fn name(&self, part: Part) -> &'static str {
+        match part {
+            Part::One => "part_1_fast",
+            Part::Two => "part_2_bitboard",
+        }
+    }
+
+    fn variants(&self, part: Part) -> Vec<Variant<Grid<u8>>> {
+        match part {
+            Part::One => vec![Variant::new("part_1", |grid| Ok(part_1(grid).into()))],
+            Part::Two => vec![
+                Variant::new("part_2", |grid| Ok(part_2(grid).into())),
+                Variant::new("part_2_fast", |grid| Ok(part_2_fast(grid).into())),
+                Variant::new("part_2_automaton", |grid| Ok(part_2_automaton(grid).into())),
+                Variant::new("part_2_faster", |grid| Ok(part_2_faster(grid).into())),
+            ],
+        }
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1_fast(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2_faster(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,4 +1,7 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 fn part_1(contents: &str) -> u32 {
     let lines = contents.lines();
@@ -23,7 +26,7 @@ fn part_1(contents: &str) -> u32 {
             }
         }
     }
-    return answer;
+    answer
 }
 
 fn part_2(contents: &str) -> u64 {
@@ -66,7 +69,7 @@ fn part_2(contents: &str) -> u64 {
                 let new_end = std::cmp::max(end, r_end);
                 ranges[i] = (new_start, new_end);
                 // Now do additional merges if necessary
-                let mut j = i + 1;
+                let j = i + 1;
                 while j < ranges.len() {
                     let (next_start, next_end) = ranges[j];
                     if new_end + 1 >= next_start {
@@ -81,31 +84,32 @@ fn part_2(contents: &str) -> u64 {
         }
     }
     // Guaranteed to be non-overlapping ranges so can just sum the sizes
-    return ranges.into_iter().map(|(start, end)| end - start + 1).sum::<u64>();
+    ranges.into_iter().map(|(start, end)| end - start + 1).sum::<u64>()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 3);
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 14);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,8 +1,11 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
 fn part_1(contents: &str) -> u64 {
     let lines: Vec<&str> = contents.lines().collect();
-    let num_lines = lines.len();
+    let _num_lines = lines.len();
     let num_eqs = lines[0].split(" ").collect::<Vec<_>>().into_iter().filter(|x| !x.is_empty()).collect::<Vec<_>>().len();
     let mut equations: Vec<Vec<u64>> = vec![Vec::new(); num_eqs];
     let mut answer = 0;
@@ -35,7 +38,7 @@ fn part_1(contents: &str) -> u64 {
             });
         }
     }
-    return answer;
+    answer
 }
 
 fn part_2(contents: &str) -> u64 {
@@ -47,8 +50,8 @@ fn part_2(contents: &str) -> u64 {
     let lines_bytes: Vec<&[u8]> = lines.iter().map(|s| s.as_bytes()).collect();
     for col in 0..num_cols {
         let mut buffer: Vec<u8> = Vec::with_capacity(num_lines);
-        for row in (0..num_lines) {
-            buffer.push(lines_bytes[row][col]);
+        for row_bytes in lines_bytes.iter() {
+            buffer.push(row_bytes[col]);
         }
         let s = unsafe { String::from_utf8_unchecked(buffer) };
         flipped.push(s);
@@ -59,7 +62,7 @@ fn part_2(contents: &str) -> u64 {
     let mut op = '+';
     let blank_line = " ".repeat(num_lines);
     for col in 0..num_cols {
-        let mut row = flipped[col].as_str();
+        let row = flipped[col].as_str();
         if *row == blank_line {
             continue;
         } else if row.contains('*') {
@@ -88,31 +91,32 @@ fn part_2(contents: &str) -> u64 {
             continue;
         }
     }
-    return answer;
+    answer
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 4277556);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 3263827);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
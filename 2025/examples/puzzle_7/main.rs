@@ -1,11 +1,14 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 use std::collections::HashSet;
 use std::collections::HashMap;
 
 fn part_1(contents: &str) -> u64 {
     let lines: Vec<&str> = contents.lines().collect();
     let num_rows = lines.len();
-    let num_cols = lines[0].len();
+    let _num_cols = lines[0].len();
     let mut start = (0, 0);
     let mut splitters = HashSet::new();
     for (row, line) in lines.iter().enumerate() {
@@ -37,13 +40,13 @@ fn part_1(contents: &str) -> u64 {
         beams = new_beams;
     }
 
-    return answer;
+    answer
 }
 
 fn part_2(contents: &str) -> u64 {
     let lines: Vec<&str> = contents.lines().collect();
     let num_rows = lines.len();
-    let num_cols = lines[0].len();
+    let _num_cols = lines[0].len();
     let mut start = (0, 0);
     let mut splitters = HashSet::new();
     for (row, line) in lines.iter().enumerate() {
@@ -56,7 +59,7 @@ fn part_2(contents: &str) -> u64 {
         }
     }
 
-    let mut answer = 0;
+    let _answer = 0;
     let mut current_row = 1;
     // Maintain counts as we split
     let mut beams = HashMap::from([(start, 1)]);
@@ -74,51 +77,32 @@ fn part_2(contents: &str) -> u64 {
         current_row += 1;
         beams = new_beams;
     }
-    return beams.values().map(|v| *v).sum();
+    beams.values().copied().sum()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
 
-    #[test]
-    fn test_part_1_example_1() {
-        assert_eq!(part_1(include_str!("./example_1.txt")), 21);
-    }
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+    const PARSES_INPUT: bool = false;
 
-    #[test]
-    fn test_part_1_example_2() {
-        assert_eq!(part_1(include_str!("./example_2.txt")), 2);
-    }
-
-    #[test]
-    fn test_part_1_example_3() {
-        assert_eq!(part_1(include_str!("./example_3.txt")), 3);
-    }
+    type Params = NoParams;
+    type Parsed = String;
 
-    #[test]
-    fn test_part_2_example_1() {
-        assert_eq!(part_2(include_str!("./example_1.txt")), 40);
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
     }
 
-    #[test]
-    fn test_part_2_example_2() {
-        assert_eq!(part_2(include_str!("./example_2.txt")), 3);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input).into())
     }
 
-    #[test]
-    fn test_part_2_example_3() {
-        assert_eq!(part_2(include_str!("./example_3.txt")), 4);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input).into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve this puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
@@ -1,4 +1,8 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::geometry::Point3D;
+use aoc_utils::puzzle::{run, Puzzle};
+use serde::Deserialize;
 use std::collections::HashMap;
 use std::collections::BinaryHeap;
 use std::cmp::Reverse;
@@ -11,8 +15,7 @@ struct DSU {
 
 impl DSU {
     fn new(n: usize) -> Self {
-        let mut parent = vec![0usize; n];
-        for i in 0..n { parent[i] = i; }
+        let parent: Vec<usize> = (0..n).collect();
         DSU { parent, size: vec![1usize; n] }
     }
     fn find(&mut self, mut x: usize) -> usize {
@@ -39,39 +42,37 @@ impl DSU {
             self.parent[rb] = ra;
             self.size[ra] += self.size[rb];
         }
-        return true
+        true
     }
 
     fn comp_size(&mut self, x: usize) -> usize {
         let r = self.find(x);
-        return self.size[r]
+        self.size[r]
     }
 }
 
 
-fn get_coords_and_distances(contents: &str) -> (Vec<[i64; 3]>, BinaryHeap<Reverse<(i64, (usize, usize))>>) {
+type Distances = BinaryHeap<Reverse<(i64, (usize, usize))>>;
+
+fn get_coords_and_distances(contents: &str) -> (Vec<Point3D<i64>>, Distances) {
     let lines: Vec<&str> = contents.lines().collect();
-    let mut coords: Vec<[i64; 3]> = Vec::new();
+    let mut coords: Vec<Point3D<i64>> = Vec::new();
     for line in lines {
-        let coord_v = line.split(",").map(|s| s.parse::<i64>().unwrap()).collect::<Vec<_>>();
-        let coord: [i64; 3] = [coord_v[0], coord_v[1], coord_v[2]];
-        coords.push(coord);
+        coords.push(line.parse().unwrap());
     }
 
     let mut distances = BinaryHeap::new();
-    for i in 0..coords.len() {
-        let coord_1 = &coords[i];
-        for j in i+1..coords.len() {
-            let coord_2 = &coords[j];
-            let dist = (coord_1[0] - coord_2[0]).pow(2) + (coord_1[1] - coord_2[1]).pow(2) + (coord_1[2] - coord_2[2]).pow(2);
+    for (i, coord_1) in coords.iter().enumerate() {
+        for (j, coord_2) in coords.iter().enumerate().skip(i + 1) {
+            let dist = coord_1.euclidean_distance_squared(coord_2);
             distances.push(Reverse((dist, (i, j))));
         }
     }
-    return (coords, distances);
+    (coords, distances)
 }
 
 fn part_1(
-    coords: Vec<[i64; 3]>,
+    coords: Vec<Point3D<i64>>,
     mut distances: BinaryHeap<Reverse<(i64, (usize, usize))>>,
     num_pairs: usize
 ) -> u64 {
@@ -91,13 +92,13 @@ fn part_1(
         *group_sizes.entry(g).or_default() += 1;
     }
     let mut largest: Vec<_> = group_sizes.into_iter().map(|p| p.1).collect();
-    largest.sort_by(|a, b| b.cmp(&a));
+    largest.sort_by(|a, b| b.cmp(a));
 
-    return (largest[0] as u64) * (largest[1] as u64) * (largest[2] as u64);
+    (largest[0] as u64) * (largest[1] as u64) * (largest[2] as u64)
 }
 
 fn part_2(
-    coords: Vec<[i64; 3]>,
+    coords: Vec<Point3D<i64>>,
     mut distances: BinaryHeap<Reverse<(i64, (usize, usize))>>,
 ) -> u64 {
     let num_coords = coords.len();
@@ -110,32 +111,69 @@ fn part_2(
             return (coords[i][0] * coords[j][0]) as u64;
         } 
     }
-    return 0;
+    0
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Closest pairs of junction boxes to connect.
+    num_pairs: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { num_pairs: 1000 }
+    }
+}
+
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+
+    type Params = Params;
+    type Parsed = (Vec<Point3D<i64>>, Distances, Params);
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Self::Parsed, PuzzleError> {
+        let (coords, distances) = get_coords_and_distances(input);
+        Ok((coords, distances, *params))
+    }
+
+    fn part_1(&self, (coords, distances, params): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_1(coords.clone(), distances.clone(), params.num_pairs).into())
+    }
+
+    fn part_2(&self, (coords, distances, _): &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Ok(part_2(coords.clone(), distances.clone()).into())
+    }
+}
+
+pub fn main() {
+    run(&Solution);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_utils::input::load_example;
 
     #[test]
     fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt"), 10), 40);
+        let Some(example) = load_example(2025, 8, "example.txt") else {
+            return;
+        };
+        let (coords, distances) = get_coords_and_distances(&example);
+        assert_eq!(part_1(coords, distances, 10), 40);
     }
 
     #[test]
     fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 25272);
+        let Some(example) = load_example(2025, 8, "example.txt") else {
+            return;
+        };
+        let (coords, distances) = get_coords_and_distances(&example);
+        assert_eq!(part_2(coords, distances), 25272);
     }
 }
-
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let (coords, distances) = get_coords_and_distances(contents);
-    let part_1_answer = part_1(coords.clone(), distances.clone(), 1000);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(coords.clone(), distances.clone());
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve puzzle", duration);
-}
\ No newline at end of file
@@ -1,116 +1,70 @@
-use std::time::Instant;
+use aoc_utils::answer::Answer;
+use aoc_utils::error::{parse_lines, PuzzleError};
+use aoc_utils::geometry::{Point, Polygon, Rect};
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
 
+fn parse(contents: &str) -> Result<Vec<Point<i64, 2>>, PuzzleError> {
+    parse_lines(contents, |line| line.parse())
+}
 
-fn part_1(contents: &str) -> i64 {
-    let lines: Vec<&str> = contents.lines().collect();
-    let mut coords: Vec<[i64; 2]> = Vec::new();
-    for line in lines {
-        let coord_v = line.split(",").map(|s| s.parse::<i64>().unwrap()).collect::<Vec<_>>();
-        let coord: [i64; 2] = [coord_v[0], coord_v[1]];
-        coords.push(coord);
-    }
+fn part_1(contents: &str) -> Result<u64, PuzzleError> {
+    let coords = parse(contents)?;
 
     let mut max_area = 0;
-    for i in 0..coords.len() {
-        let coord_1 = &coords[i];
-        for j in i+1..coords.len() {
-            let coord_2 = &coords[j];
-            let area = ((coord_1[0] - coord_2[0]).abs() + 1) * ((coord_1[1] - coord_2[1]).abs() + 1);
+    for (i, coord_1) in coords.iter().enumerate() {
+        for coord_2 in coords.iter().skip(i + 1) {
+            let area = Rect::from_corners(*coord_1, *coord_2).area();
             if area > max_area {
                 max_area = area;
             }
         }
     }
-    return max_area;
+    Ok(max_area)
 }
 
-fn part_2(contents: &str) -> i64 {
-    let lines: Vec<&str> = contents.lines().collect();
-    let mut coords: Vec<[i64; 2]> = Vec::new();
-    for line in lines {
-        let coord_v = line.split(",").map(|s| s.parse::<i64>().unwrap()).collect::<Vec<_>>();
-        let coord: [i64; 2] = [coord_v[0], coord_v[1]];
-        coords.push(coord);
-    }
+fn part_2(contents: &str) -> Result<u64, PuzzleError> {
+    let coords = parse(contents)?;
+    // the red tiles are the corners of a polygon with only horizontal and
+    // vertical edges, which lets us check whole rectangles at once
+    let tiles = Polygon::new(coords.clone()).compress()?;
 
     let mut max_area = 0;
-    for i in 0..coords.len() {
-        let coord_1 = &coords[i];
-        for j in i+1..coords.len() {
-            let coord_2 = &coords[j];
-            let area = ((coord_1[0] - coord_2[0]).abs() + 1) * ((coord_1[1] - coord_2[1]).abs() + 1);
-            if area <= max_area {
-                // no point checking in this case
-                continue
-            }
-
-            let min_x = std::cmp::min(coord_1[0], coord_2[0]);
-            let max_x = std::cmp::max(coord_1[0], coord_2[0]);
-            let min_y = std::cmp::min(coord_1[1], coord_2[1]);
-            let max_y = std::cmp::max(coord_1[1], coord_2[1]);
-            
-            // check if this rectangle is valid by confirming no border line is
-            // intersected with
-            let mut valid = true;
-            for k in 0..coords.len() {
-                // these two points define a line segment on the border
-                let b1 = &coords[k];
-                let b2 = if k == coords.len() - 1 { &coords[0] } else { &coords[k + 1] };
-
-                if (b1[0] == b2[0]) && (min_x < b1[0]) && (b1[0] < max_x) {
-                    // vertical case
-                    if (b1[1] < b2[1]) && (b1[1] < max_y) && (b2[1] > min_y) {
-                        valid = false;
-                        break;
-                    } else if (b2[1] < b1[1]) && (b2[1] < max_y) && (b1[1] > min_y) {
-                        valid = false;
-                        break;
-                    }
-                } else if (b1[1] == b2[1]) && (min_y < b1[1]) && (b1[1] < max_y) {
-                    // horizontal case
-                    if (b1[0] < b2[0]) && (b1[0] < max_x) && (b2[0] > min_x) {
-                        valid = false;
-                        break;
-                    } else if (b2[0] < b1[0]) && (b2[0] < max_x) && (b1[0] > min_x){
-                        valid = false;
-                        break;
-                    }
-                }
-            }
-            if !valid {
-                //println!("Invalid {:?} --> {:?}", coord_1, coord_2);
-                continue
+    for (i, coord_1) in coords.iter().enumerate() {
+        for coord_2 in coords.iter().skip(i + 1) {
+            let rect = Rect::from_corners(*coord_1, *coord_2);
+            let area = rect.area();
+            if area > max_area && tiles.contains_rect(&rect) {
+                max_area = area;
             }
-
-            //println!("VALID {:?} --> {:?} ({})", coord_1, coord_2, area);
-            max_area = area;
         }
     }
-    return max_area;
+    Ok(max_area)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+    const PARSES_INPUT: bool = false;
+
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
 
-    #[test]
-    fn test_part_1_example() {
-        assert_eq!(part_1(include_str!("./example.txt")), 50);
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_1(input)?.into())
     }
 
-    #[test]
-    fn test_part_2_example() {
-        assert_eq!(part_2(include_str!("./example.txt")), 24);
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        Ok(part_2(input)?.into())
     }
 }
 
-fn main() {
-    let start = Instant::now();
-    let contents = include_str!("./input.txt");
-    let part_1_answer = part_1(contents);
-    println!("Answer for part 1 is: {}", part_1_answer);
-    let part_2_answer = part_2(contents);
-    println!("Answer for part 2 is: {}", part_2_answer);
-    let duration = start.elapsed();
-    println!("Took {:?} to solve puzzle", duration);
+pub fn main() {
+    run(&Solution);
 }
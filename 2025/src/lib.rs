@@ -0,0 +1,44 @@
+use aoc_utils::puzzle::Solver;
+
+#[path = "../examples/puzzle_1/main.rs"]
+pub mod puzzle_1;
+#[path = "../examples/puzzle_2/main.rs"]
+pub mod puzzle_2;
+#[path = "../examples/puzzle_3/main.rs"]
+pub mod puzzle_3;
+#[path = "../examples/puzzle_4/main.rs"]
+pub mod puzzle_4;
+#[path = "../examples/puzzle_5/main.rs"]
+pub mod puzzle_5;
+#[path = "../examples/puzzle_6/main.rs"]
+pub mod puzzle_6;
+#[path = "../examples/puzzle_7/main.rs"]
+pub mod puzzle_7;
+#[path = "../examples/puzzle_8/main.rs"]
+pub mod puzzle_8;
+#[path = "../examples/puzzle_9/main.rs"]
+pub mod puzzle_9;
+#[path = "../examples/puzzle_10/main.rs"]
+pub mod puzzle_10;
+#[path = "../examples/puzzle_11/main.rs"]
+pub mod puzzle_11;
+#[path = "../examples/puzzle_12/main.rs"]
+pub mod puzzle_12;
+
+/// Every solution for the year, in day order.
+pub fn puzzles() -> Vec<&'static dyn Solver> {
+    vec![
+        &puzzle_1::Solution,
+        &puzzle_2::Solution,
+        &puzzle_3::Solution,
+        &puzzle_4::Solution,
+        &puzzle_5::Solution,
+        &puzzle_6::Solution,
+        &puzzle_7::Solution,
+        &puzzle_8::Solution,
+        &puzzle_9::Solution,
+        &puzzle_10::Solution,
+        &puzzle_11::Solution,
+        &puzzle_12::Solution,
+    ]
+}
@@ -0,0 +1,141 @@
+use crate::catch_panic;
+use aoc_utils::bench::{Settings, Timing};
+use aoc_utils::puzzle::{Part, Solver};
+use aoc_utils::{input, params};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+#[derive(Serialize)]
+struct Record {
+    year: u16,
+    day: u8,
+    phase: String,
+    min_ns: u128,
+    median_ns: u128,
+    p95_ns: u128,
+}
+
+impl Record {
+    fn new(year: u16, day: u8, timing: &Timing) -> Self {
+        Self {
+            year,
+            day,
+            phase: timing.phase.clone(),
+            min_ns: timing.stats.min.as_nanos(),
+            median_ns: timing.stats.median.as_nanos(),
+            p95_ns: timing.stats.p95.as_nanos(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Report {
+    warmup: usize,
+    runs: usize,
+    results: Vec<Record>,
+}
+
+/// Benchmarks each puzzle with a local input, printing a table per puzzle or
+/// a single JSON report which can be kept to track timings over time, written
+/// to `output` when given.
+pub fn bench(
+    solvers: &[&dyn Solver],
+    parts: &[Part],
+    settings: &Settings,
+    json: bool,
+    output: Option<&Path>,
+) -> ExitCode {
+    let mut results = Vec::new();
+    let mut num_failed = 0;
+    for solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+        let input = match input::load(year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{year} day {day}: skipped, {e}");
+                continue;
+            }
+        };
+
+        let result = params::for_input(year, day, None).and_then(|params| {
+            catch_panic(|| {
+                solver
+                    .bench(&input, &params, parts, settings)
+                    .map_err(|e| e.to_string())
+            })
+        });
+        let timings = match result {
+            Ok(timings) => timings,
+            Err(e) => {
+                eprintln!("{year} day {day}: failed, {e}");
+                num_failed += 1;
+                continue;
+            }
+        };
+        if !json {
+            println!("--- {year} day {day} ---");
+            let width = timings
+                .iter()
+                .map(|timing| timing.phase.len())
+                .max()
+                .unwrap();
+            for timing in timings.iter() {
+                let stats = timing.stats;
+                println!(
+                    "{:<width$} min {:>12?} median {:>12?} p95 {:>12?}",
+                    timing.phase, stats.min, stats.median, stats.p95
+                );
+            }
+        }
+        results.extend(timings.iter().map(|timing| Record::new(year, day, timing)));
+    }
+
+    if json {
+        let report = Report {
+            warmup: settings.warmup,
+            runs: settings.runs,
+            results,
+        };
+        let report = serde_json::to_string_pretty(&report).unwrap();
+        match output {
+            Some(path) => {
+                if let Err(e) = fs::write(path, report + "\n") {
+                    eprintln!("Could not write the report to {}: {e}", path.display());
+                    return ExitCode::FAILURE;
+                }
+            }
+            None => println!("{report}"),
+        }
+    }
+    if num_failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::bench::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn test_record_json() {
+        let timing = Timing {
+            phase: String::from("part_2"),
+            stats: Stats {
+                runs: 3,
+                min: Duration::from_micros(1),
+                median: Duration::from_micros(2),
+                p95: Duration::from_micros(3),
+            },
+        };
+        assert_eq!(
+            serde_json::to_string(&Record::new(2025, 4, &timing)).unwrap(),
+            r#"{"year":2025,"day":4,"phase":"part_2","min_ns":1000,"median_ns":2000,"p95_ns":3000}"#
+        );
+    }
+}
@@ -0,0 +1,240 @@
+use aoc_utils::answer::Answer;
+use aoc_utils::puzzle::Part;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{fs, io};
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part_2: Option<Answer>,
+}
+
+impl DayAnswers {
+    fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+
+    fn set(&mut self, part: Part, answer: Answer) {
+        match part {
+            Part::One => self.part_1 = Some(answer),
+            Part::Two => self.part_2 = Some(answer),
+        }
+    }
+}
+
+/// Known correct answers for the real inputs, checked in as `answers.toml`
+/// with a `[<year>.<day>]` table per puzzle. Years and days are kept as
+/// numbers so the tables are saved in puzzle order, but TOML keys are
+/// always strings so they're converted on the way in and out.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(try_from = "BTreeMap<String, BTreeMap<String, DayAnswers>>")]
+pub struct Ledger(BTreeMap<u16, BTreeMap<u8, DayAnswers>>);
+
+impl TryFrom<BTreeMap<String, BTreeMap<String, DayAnswers>>> for Ledger {
+    type Error = String;
+
+    fn try_from(years: BTreeMap<String, BTreeMap<String, DayAnswers>>) -> Result<Self, String> {
+        years
+            .into_iter()
+            .map(|(year, days)| {
+                let days = days
+                    .into_iter()
+                    .map(|(day, answers)| Ok((parse_key(&day, "day")?, answers)))
+                    .collect::<Result<_, String>>()?;
+                Ok((parse_key(&year, "year")?, days))
+            })
+            .collect::<Result<_, String>>()
+            .map(Self)
+    }
+}
+
+impl Serialize for Ledger {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.0
+                .iter()
+                .map(|(year, days)| (year.to_string(), StringKeys(days))),
+        )
+    }
+}
+
+/// Writes a map with its keys as strings, keeping their numeric order.
+struct StringKeys<'a, K, V>(&'a BTreeMap<K, V>);
+
+impl<K: Display, V: Serialize> Serialize for StringKeys<'_, K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().map(|(key, value)| (key.to_string(), value)))
+    }
+}
+
+fn parse_key<T: FromStr>(key: &str, kind: &str) -> Result<T, String> {
+    key.parse().map_err(|_| format!("Invalid {kind} \"{key}\""))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Mismatch { expected: Answer },
+    New,
+}
+
+impl Ledger {
+    pub fn default_path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .join("answers.toml")
+    }
+
+    /// A missing ledger is treated as empty so that it can be started from scratch.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(path, contents).map_err(|e| format!("Could not write {}: {e}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Answer> {
+        self.0.get(&year)?.get(&day)?.get(part)
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: Answer) {
+        self.0
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .set(part, answer)
+    }
+
+    /// Adds an empty table for a new puzzle, to be filled in by `verify --record`.
+    pub fn add_puzzle(&mut self, year: u16, day: u8) {
+        self.0.entry(year).or_default().entry(day).or_default();
+    }
+
+    pub fn check(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Outcome {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Outcome::Correct,
+            Some(expected) => Outcome::Mismatch {
+                expected: expected.clone(),
+            },
+            None => Outcome::New,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEDGER: &str = r#"
+[2020.15]
+part_1 = 203
+part_2 = 9007186
+
+[2022.25]
+part_1 = "2=-1=0"
+"#;
+
+    #[test]
+    fn test_get() {
+        let ledger = Ledger::parse(LEDGER).unwrap();
+        assert_eq!(
+            ledger.get(2020, 15, Part::Two),
+            Some(&Answer::Number(9007186))
+        );
+        assert_eq!(
+            ledger.get(2022, 25, Part::One),
+            Some(&Answer::from("2=-1=0"))
+        );
+        assert_eq!(ledger.get(2022, 25, Part::Two), None);
+        assert_eq!(ledger.get(2019, 1, Part::One), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let ledger = Ledger::parse(LEDGER).unwrap();
+        assert_eq!(
+            ledger.check(2020, 15, Part::One, &Answer::from(203)),
+            Outcome::Correct
+        );
+        assert_eq!(
+            ledger.check(2020, 15, Part::One, &Answer::from(204)),
+            Outcome::Mismatch {
+                expected: Answer::Number(203)
+            }
+        );
+        assert_eq!(
+            ledger.check(2020, 16, Part::One, &Answer::from(1)),
+            Outcome::New
+        );
+    }
+
+    #[test]
+    fn test_record_round_trip() {
+        let mut ledger = Ledger::default();
+        ledger.record(2022, 25, Part::One, Answer::from("2=-1=0"));
+        ledger.record(2020, 15, Part::Two, Answer::from(9007186));
+        ledger.record(2020, 15, Part::One, Answer::from(203));
+        let serialised = toml::to_string(&ledger).unwrap();
+        assert_eq!(Ledger::parse(&serialised).unwrap(), ledger);
+        assert_eq!(
+            Ledger::parse(&serialised).unwrap(),
+            Ledger::parse(LEDGER).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_add_puzzle() {
+        let mut ledger = Ledger::parse(LEDGER).unwrap();
+        ledger.add_puzzle(2025, 13);
+        ledger.add_puzzle(2020, 15);
+        let serialised = toml::to_string(&ledger).unwrap();
+        assert!(serialised.contains("[2025.13]"));
+        let ledger = Ledger::parse(&serialised).unwrap();
+        assert_eq!(ledger.get(2020, 15, Part::One), Some(&Answer::Number(203)));
+        assert_eq!(
+            ledger.check(2025, 13, Part::One, &Answer::from(1)),
+            Outcome::New
+        );
+    }
+
+    #[test]
+    fn test_days_saved_in_order() {
+        let mut ledger = Ledger::default();
+        ledger.add_puzzle(2020, 10);
+        ledger.add_puzzle(2020, 2);
+        let serialised = toml::to_string(&ledger).unwrap();
+        assert!(serialised.find("[2020.2]") < serialised.find("[2020.10]"));
+    }
+
+    #[test]
+    fn test_invalid_day() {
+        assert!(Ledger::parse("[2020.first]\npart_1 = 1\n").is_err());
+    }
+
+    #[test]
+    fn test_missing_ledger_is_empty() {
+        let ledger = Ledger::load(Path::new("/no/such/answers.toml")).unwrap();
+        assert_eq!(ledger, Ledger::default());
+    }
+}
@@ -0,0 +1,284 @@
+mod bench;
+mod ledger;
+mod registry;
+mod scaffold;
+mod verify;
+
+use aoc_utils::bench::Settings;
+use aoc_utils::input;
+use aoc_utils::params::{self, Table};
+use aoc_utils::puzzle::{report, Part, Solver};
+use clap::{Args, Parser, Subcommand};
+use ledger::Ledger;
+use registry::Registry;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    about = "Run Advent of Code solutions across every Rust year"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the selected puzzles against their inputs
+    Run(RunArgs),
+    /// Check answers for the selected puzzles, every puzzle by default, against the ledger
+    Verify(VerifyArgs),
+    /// Time parsing and each part of the selected puzzles over repeated runs
+    Bench(BenchArgs),
+    /// Create a puzzle from the template and register it with its year and the ledger
+    New(NewArgs),
+}
+
+#[derive(Args)]
+struct Selection {
+    /// Year to run, e.g. 2022
+    #[arg(value_name = "YEAR")]
+    year_arg: Option<u16>,
+
+    /// Day to run, every day of the selected year(s) when omitted
+    #[arg(value_name = "DAY")]
+    day_arg: Option<u8>,
+
+    #[arg(long, conflicts_with = "year_arg")]
+    year: Option<u16>,
+
+    #[arg(long, conflicts_with = "day_arg")]
+    day: Option<u8>,
+
+    /// Only run a single part, 1 or 2
+    #[arg(long, value_parser = parse_part)]
+    part: Option<Part>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Run every day of the selected year(s), or every year if none is given
+    #[arg(long, conflicts_with_all = ["day_arg", "day"])]
+    all: bool,
+
+    /// Read the input from this file, or `-` for stdin, instead of the puzzle folder
+    #[arg(long, value_name = "PATH")]
+    input: Option<String>,
+
+    /// Override a puzzle parameter given for the input in `inputs.toml`,
+    /// e.g. `--param row_index=10`
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_assignment)]
+    params: Vec<(String, toml::Value)>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Ledger of known answers, `answers.toml` at the root of the repository by default
+    #[arg(long, value_name = "PATH")]
+    ledger: Option<PathBuf>,
+
+    /// Save answers which are not in the ledger yet
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Bench every day of the selected year(s), or every year if none is given
+    #[arg(long, conflicts_with_all = ["day_arg", "day"])]
+    all: bool,
+
+    /// Untimed runs of each phase before measuring
+    #[arg(long, default_value_t = Settings::default().warmup)]
+    warmup: usize,
+
+    /// Timed runs of each phase
+    #[arg(long, default_value_t = Settings::default().runs)]
+    runs: usize,
+
+    /// Print the results as JSON instead of a table
+    #[arg(long)]
+    json: bool,
+
+    /// Write the JSON report to this file instead of stdout, where solutions
+    /// may print too
+    #[arg(long, value_name = "PATH", requires = "json")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Year of the puzzle, the latest year by default
+    year: Option<u16>,
+
+    /// Day of the puzzle, the day after the latest one of the year by default
+    day: Option<u8>,
+}
+
+impl Selection {
+    fn year(&self) -> Option<u16> {
+        self.year.or(self.year_arg)
+    }
+
+    fn day(&self) -> Option<u8> {
+        self.day.or(self.day_arg)
+    }
+
+    fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    let number = value.parse::<u8>().map_err(|e| e.to_string())?;
+    Part::try_from(number)
+}
+
+/// Registered puzzles matching the selection, where `all` allows selecting
+/// everything when neither a year nor a day is given.
+fn select(selection: &Selection, all: bool) -> Result<Vec<&'static dyn Solver>, String> {
+    if selection.year().is_none() && selection.day().is_none() && !all {
+        return Err(String::from(
+            "Nothing to run, pass a year and/or day or use --all",
+        ));
+    }
+
+    let registry = Registry::all();
+    let solvers = registry.select(selection.year(), selection.day());
+    if solvers.is_empty() {
+        return Err(format!(
+            "No registered puzzles match the selection, available years are {:?}",
+            registry.years()
+        ));
+    }
+    Ok(solvers)
+}
+
+/// Solves a puzzle, treating a panic as a failure so that the rest of the
+/// selection still runs. The panic message has already been printed by then.
+fn catch_panic<T>(solve: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(solve))
+        .unwrap_or_else(|_| Err(String::from("The solution panicked")))
+}
+
+/// Parameters from the manifest for the input being run, with any overrides.
+fn input_params(args: &RunArgs, year: u16, day: u8) -> Result<Table, String> {
+    let mut table = params::for_input(year, day, args.input.as_deref())?;
+    table.extend(args.params.iter().cloned());
+    Ok(table)
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let selection = &args.selection;
+    let solvers = match select(selection, args.all) {
+        Ok(solvers) => solvers,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if (args.input.is_some() || !args.params.is_empty()) && solvers.len() > 1 {
+        eprintln!("--input and --param can only be used when a single puzzle is selected");
+        return ExitCode::FAILURE;
+    }
+
+    let parts = selection.parts();
+    let mut num_failed = 0;
+    for solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+        println!("--- {year} day {day} ---");
+        let result = input::load_from(args.input.as_deref(), year, day)
+            .map_err(|e| e.to_string())
+            .and_then(|input| Ok((input, input_params(args, year, day)?)))
+            .and_then(|(input, params)| {
+                catch_panic(|| report(solver, &input, &params, &parts).map_err(|e| e.to_string()))
+            });
+        if let Err(e) = result {
+            eprintln!("{e}");
+            num_failed += 1;
+        }
+    }
+
+    if num_failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new(args: &NewArgs) -> ExitCode {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let year = args
+        .year
+        .unwrap_or_else(|| *Registry::all().years().last().unwrap());
+    let result = scaffold::YearCrate::open(root, year).and_then(|year_crate| {
+        let day = args
+            .day
+            .unwrap_or_else(|| year_crate.days().iter().max().map_or(1, |day| day + 1));
+        let puzzle_dir = scaffold::create(root, &Ledger::default_path(), year, day)?;
+        Ok((day, puzzle_dir))
+    });
+    match result {
+        Ok((day, puzzle_dir)) => {
+            println!("Created {year} day {day} in {}", puzzle_dir.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => match select(&args.selection, true) {
+            Ok(solvers) => {
+                let path = args.ledger.clone().unwrap_or_else(Ledger::default_path);
+                verify::verify(&solvers, &args.selection.parts(), &path, args.record)
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Bench(args) => match select(&args.selection, args.all) {
+            Ok(solvers) => {
+                let settings = Settings {
+                    warmup: args.warmup,
+                    runs: args.runs.max(1),
+                };
+                let output = args.output.as_deref();
+                bench::bench(
+                    &solvers,
+                    &args.selection.parts(),
+                    &settings,
+                    args.json,
+                    output,
+                )
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::New(args) => new(args),
+    }
+}
@@ -0,0 +1,197 @@
+use aoc_utils::puzzle::Solver;
+
+/// Every registered solution across the Rust years, ordered by year then day.
+pub struct Registry {
+    solvers: Vec<&'static dyn Solver>,
+}
+
+impl Registry {
+    pub fn new(mut solvers: Vec<&'static dyn Solver>) -> Self {
+        solvers.sort_by_key(|solver| (solver.year(), solver.day()));
+        Self { solvers }
+    }
+
+    pub fn all() -> Self {
+        Self::new(
+            [
+                advent_of_code_2019::puzzles(),
+                advent_of_code_2020::puzzles(),
+                advent_of_code_2022::puzzles(),
+                advent_of_code_2025::puzzles(),
+            ]
+            .concat(),
+        )
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.solvers.iter().map(|solver| solver.year()).collect();
+        years.dedup();
+        years
+    }
+
+    /// Solvers matching the given year and day, where `None` matches anything.
+    pub fn select(&self, year: Option<u16>, day: Option<u8>) -> Vec<&'static dyn Solver> {
+        self.solvers
+            .iter()
+            .filter(|solver| year.is_none_or(|year| solver.year() == year))
+            .filter(|solver| day.is_none_or(|day| solver.day() == day))
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::input::{self, example_files};
+    use aoc_utils::manifest::Manifest;
+    use aoc_utils::puzzle::Part;
+    use std::env;
+
+    /// Reads an example, noting it as missing if it isn't there.
+    fn load_example(year: u16, day: u8, file: &str, missing: &mut Vec<String>) -> Option<String> {
+        match input::read(&input::path(year, day, file)) {
+            Ok(contents) => Some(contents),
+            Err(e) => {
+                missing.push(format!("{year} day {day}: {e}"));
+                None
+            }
+        }
+    }
+
+    /// Examples aren't checked in, so on a fresh clone the missing ones are
+    /// only listed, but with `AOC_INPUT_DIR` pointing at a full set of inputs
+    /// and examples, as in CI, every example has to be there.
+    fn check_missing(missing: &[String]) {
+        if missing.is_empty() {
+            return;
+        }
+        let list = missing.join("\n");
+        if env::var_os(input::INPUT_DIR_VAR).is_some() {
+            panic!("{} examples are missing:\n{list}", missing.len());
+        }
+        eprintln!("Skipped {} missing examples:\n{list}", missing.len());
+    }
+
+    #[test]
+    fn test_years() {
+        assert_eq!(Registry::all().years(), vec![2019, 2020, 2022, 2025]);
+    }
+
+    #[test]
+    fn test_select_single_puzzle() {
+        let selected = Registry::all().select(Some(2022), Some(17));
+        assert_eq!(selected.len(), 1);
+        assert_eq!((selected[0].year(), selected[0].day()), (2022, 17));
+    }
+
+    #[test]
+    fn test_select_whole_year_in_day_order() {
+        let days: Vec<u8> = Registry::all()
+            .select(Some(2025), None)
+            .iter()
+            .map(|solver| solver.day())
+            .collect();
+        assert_eq!(days, (1..=12).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_no_duplicate_registrations() {
+        let registry = Registry::all();
+        let mut keys: Vec<(u16, u8)> = registry
+            .select(None, None)
+            .iter()
+            .map(|solver| (solver.year(), solver.day()))
+            .collect();
+        let num_registered = keys.len();
+        keys.dedup();
+        assert_eq!(keys.len(), num_registered);
+    }
+
+    #[test]
+    fn test_manifest_params_are_valid() {
+        for solver in Registry::all().select(None, None) {
+            let (year, day) = (solver.year(), solver.day());
+            let manifest = Manifest::load(year, day).unwrap();
+            for file in manifest.files() {
+                if let Err(e) = solver.check_params(&manifest.params(file)) {
+                    panic!("{year} day {day} {file}: {e}");
+                }
+            }
+        }
+    }
+
+    /// Every example listed with answers in a puzzle's `inputs.toml` is
+    /// solved, so adding an example only needs a manifest entry.
+    #[test]
+    fn test_examples_give_expected_answers() {
+        let mut failures: Vec<String> = Vec::new();
+        let mut missing: Vec<String> = Vec::new();
+        for solver in Registry::all().select(None, None) {
+            let (year, day) = (solver.year(), solver.day());
+            let manifest = Manifest::load(year, day).unwrap();
+            for file in manifest.files() {
+                let parts: Vec<Part> = solver
+                    .parts()
+                    .iter()
+                    .filter(|part| manifest.expected(file, **part).is_some())
+                    .copied()
+                    .collect();
+                if parts.is_empty() {
+                    continue;
+                }
+                let Some(example) = load_example(year, day, file, &mut missing) else {
+                    continue;
+                };
+                for (part, answer) in solver
+                    .solve(&example, &manifest.params(file), &parts)
+                    .unwrap()
+                {
+                    let expected = manifest.expected(file, part).unwrap();
+                    if &answer != expected {
+                        failures.push(format!(
+                            "{year} day {day} {file} part {part}: expected {expected} but got {answer}"
+                        ));
+                    }
+                }
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+        check_missing(&missing);
+    }
+
+    #[test]
+    fn test_variants_agree_on_examples() {
+        let mut disagreements: Vec<String> = Vec::new();
+        let mut missing: Vec<String> = Vec::new();
+        for solver in Registry::all().select(None, None) {
+            let (year, day) = (solver.year(), solver.day());
+            if Part::ALL
+                .iter()
+                .all(|part| solver.variants(*part).is_empty())
+            {
+                continue;
+            }
+            let manifest = Manifest::load(year, day).unwrap();
+            // the examples in the manifest, along with any others lying around
+            let mut files: Vec<String> = manifest.files().cloned().collect();
+            files.extend(example_files(year, day));
+            files.sort();
+            files.dedup();
+            for file in files {
+                let Some(example) = load_example(year, day, &file, &mut missing) else {
+                    continue;
+                };
+                let params = manifest.params(&file);
+                for disagreement in solver
+                    .cross_check(&example, &params, solver.parts())
+                    .unwrap()
+                {
+                    disagreements.push(format!("{year} day {day} {file} {disagreement}"));
+                }
+            }
+        }
+        assert!(disagreements.is_empty(), "{}", disagreements.join("\n"));
+        check_missing(&missing);
+    }
+}
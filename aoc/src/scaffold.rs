@@ -0,0 +1,281 @@
+use crate::ledger::Ledger;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const MAIN_TEMPLATE: &str = r#"use aoc_utils::answer::Answer;
+use aoc_utils::error::PuzzleError;
+use aoc_utils::params::NoParams;
+use aoc_utils::puzzle::{run, Puzzle};
+
+fn part_1(_contents: &str) -> Result<Answer, PuzzleError> {
+    Err(PuzzleError::unsupported("Part 1 hasn't been solved yet"))
+}
+{part_2_fn}
+pub struct Solution;
+
+impl Puzzle for Solution {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+{has_part_2}
+    type Params = NoParams;
+    type Parsed = String;
+
+    fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<Answer, PuzzleError> {
+        part_1(input)
+    }
+{part_2_method}}
+
+pub fn main() {
+    run(&Solution);
+}
+"#;
+
+const PART_2_FN: &str = r#"
+fn part_2(_contents: &str) -> Result<Answer, PuzzleError> {
+    Err(PuzzleError::unsupported("Part 2 hasn't been solved yet"))
+}
+"#;
+
+const PART_2_METHOD: &str = r#"
+    fn part_2(&self, input: &String) -> Result<Answer, PuzzleError> {
+        part_2(input)
+    }
+"#;
+
+const INPUTS_TEMPLATE: &str = r#"# Parameters and answers from the puzzle description for each example, e.g.
+# params = { row_index = 10 }
+# part_1 = 26
+["example.txt"]
+"#;
+
+/// The calendar was shortened to 12 days from 2025.
+pub fn num_days(year: u16) -> u8 {
+    if year >= 2025 {
+        12
+    } else {
+        25
+    }
+}
+
+/// A year's crate, with each puzzle in `examples/puzzle_<day>/main.rs`
+/// registered as a module of `src/lib.rs`.
+pub struct YearCrate {
+    dir: PathBuf,
+    days: Vec<u8>,
+}
+
+impl YearCrate {
+    pub fn open(root: &Path, year: u16) -> Result<Self, String> {
+        let dir = root.join(year.to_string());
+        let lib_path = dir.join("src").join("lib.rs");
+        let contents = fs::read_to_string(&lib_path)
+            .map_err(|_| format!("{year} has no Rust crate of puzzles at {}", dir.display()))?;
+        let days: Vec<u8> = contents
+            .lines()
+            .filter_map(|line| line.strip_prefix("pub mod puzzle_")?.strip_suffix(';'))
+            .map(|day| {
+                day.parse()
+                    .map_err(|_| format!("Unexpected module puzzle_{day}"))
+            })
+            .collect::<Result<_, _>>()?;
+        // anything else in the file would be lost when it's rewritten
+        if render_lib(&days) != contents {
+            return Err(format!(
+                "{} isn't laid out as generated, register puzzles in it by hand",
+                lib_path.display()
+            ));
+        }
+        Ok(Self { dir, days })
+    }
+
+    pub fn days(&self) -> &[u8] {
+        &self.days
+    }
+
+    fn puzzle_dir(&self, day: u8) -> PathBuf {
+        self.dir.join("examples").join(format!("puzzle_{day}"))
+    }
+}
+
+fn render_lib(days: &[u8]) -> String {
+    let mut lib = String::from("use aoc_utils::puzzle::Solver;\n\n");
+    for day in days {
+        lib.push_str(&format!(
+            "#[path = \"../examples/puzzle_{day}/main.rs\"]\npub mod puzzle_{day};\n"
+        ));
+    }
+    lib.push_str("\n/// Every solution for the year, in day order.\n");
+    lib.push_str("pub fn puzzles() -> Vec<&'static dyn Solver> {\n    vec![\n");
+    for day in days {
+        lib.push_str(&format!("        &puzzle_{day}::Solution,\n"));
+    }
+    lib.push_str("    ]\n}\n");
+    lib
+}
+
+fn render_main(year: u16, day: u8) -> String {
+    let has_part_2 = day < num_days(year);
+    MAIN_TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{part_2_fn}", if has_part_2 { PART_2_FN } else { "" })
+        .replace(
+            "{part_2_method}",
+            if has_part_2 { PART_2_METHOD } else { "" },
+        )
+        .replace(
+            "{has_part_2}",
+            if has_part_2 {
+                ""
+            } else {
+                "    const HAS_PART_2: bool = false;\n"
+            },
+        )
+}
+
+/// Creates a puzzle from the template with empty input and example files,
+/// then registers it with its year's crate and in the ledger. Nothing is
+/// written if the puzzle's folder already exists or it's already registered.
+pub fn create(root: &Path, ledger_path: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    if !(1..=num_days(year)).contains(&day) {
+        return Err(format!(
+            "{year} has days 1 to {}, not {day}",
+            num_days(year)
+        ));
+    }
+    let year_crate = YearCrate::open(root, year)?;
+    let puzzle_dir = year_crate.puzzle_dir(day);
+    if puzzle_dir.exists() {
+        return Err(format!("Path {} already exists!", puzzle_dir.display()));
+    }
+    if year_crate.days.contains(&day) {
+        return Err(format!("{year} day {day} is already registered"));
+    }
+    let mut ledger = Ledger::load(ledger_path)?;
+
+    let files = [
+        ("main.rs", render_main(year, day)),
+        ("inputs.toml", String::from(INPUTS_TEMPLATE)),
+        ("input.txt", String::new()),
+        ("example.txt", String::new()),
+    ];
+    fs::create_dir_all(&puzzle_dir)
+        .map_err(|e| format!("Could not create {}: {e}", puzzle_dir.display()))?;
+    for (name, contents) in files {
+        let path = puzzle_dir.join(name);
+        fs::write(&path, contents)
+            .map_err(|e| format!("Could not write {}: {e}", path.display()))?;
+    }
+
+    let mut days = year_crate.days.clone();
+    days.push(day);
+    days.sort();
+    let lib_path = year_crate.dir.join("src").join("lib.rs");
+    fs::write(&lib_path, render_lib(&days))
+        .map_err(|e| format!("Could not write {}: {e}", lib_path.display()))?;
+
+    ledger.add_puzzle(year, day);
+    ledger.save(ledger_path)?;
+    Ok(puzzle_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::puzzle::Part;
+    use std::env;
+
+    /// A copy of a year's crate with the given puzzles, in a fresh directory.
+    fn setup(name: &str, year: u16, days: &[u8]) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc_scaffold_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let year_dir = root.join(year.to_string());
+        fs::create_dir_all(year_dir.join("src")).unwrap();
+        fs::write(year_dir.join("src").join("lib.rs"), render_lib(days)).unwrap();
+        for day in days {
+            fs::create_dir_all(year_dir.join("examples").join(format!("puzzle_{day}"))).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn test_every_year_is_laid_out_as_generated() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        for year in [2019, 2020, 2022, 2025] {
+            let year_crate = YearCrate::open(root, year).unwrap();
+            assert!(!year_crate.days().is_empty());
+        }
+        assert!(YearCrate::open(root, 2024).is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        let root = setup("create", 2099, &[1, 3]);
+        let ledger_path = root.join("answers.toml");
+        let puzzle_dir = create(&root, &ledger_path, 2099, 2).unwrap();
+
+        let main = fs::read_to_string(puzzle_dir.join("main.rs")).unwrap();
+        assert!(main.contains("const YEAR: u16 = 2099;\n    const DAY: u8 = 2;\n"));
+        assert!(main.contains("fn part_2("));
+        assert!(!main.contains("HAS_PART_2"));
+        for file in ["inputs.toml", "input.txt", "example.txt"] {
+            assert!(puzzle_dir.join(file).exists());
+        }
+
+        let year_crate = YearCrate::open(&root, 2099).unwrap();
+        assert_eq!(year_crate.days(), &[1, 2, 3]);
+        let ledger = Ledger::load(&ledger_path).unwrap();
+        assert_eq!(ledger, {
+            let mut expected = Ledger::default();
+            expected.add_puzzle(2099, 2);
+            expected
+        });
+        assert_eq!(ledger.get(2099, 2, Part::One), None);
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_final_day_has_a_single_part() {
+        let main = render_main(2025, 12);
+        assert!(main.contains("const HAS_PART_2: bool = false;"));
+        assert!(!main.contains("fn part_2("));
+        assert!(render_main(2020, 24).contains("fn part_2("));
+    }
+
+    #[test]
+    fn test_refuses_to_clobber() {
+        let root = setup("clobber", 2099, &[1]);
+        let ledger_path = root.join("answers.toml");
+        let lib = fs::read_to_string(root.join("2099/src/lib.rs")).unwrap();
+
+        assert!(create(&root, &ledger_path, 2099, 1)
+            .unwrap_err()
+            .contains("already exists"));
+        fs::create_dir_all(root.join("2099/examples/puzzle_2")).unwrap();
+        assert!(create(&root, &ledger_path, 2099, 2).is_err());
+        assert!(create(&root, &ledger_path, 2099, 26).is_err());
+        assert!(create(&root, &ledger_path, 2098, 1).is_err());
+
+        assert_eq!(
+            fs::read_to_string(root.join("2099/src/lib.rs")).unwrap(),
+            lib
+        );
+        assert!(!ledger_path.exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_refuses_hand_edited_lib() {
+        let root = setup("hand_edited", 2099, &[1]);
+        let lib_path = root.join("2099/src/lib.rs");
+        let lib = fs::read_to_string(&lib_path).unwrap();
+        fs::write(&lib_path, lib.replace("pub mod", "mod")).unwrap();
+        assert!(create(&root, &root.join("answers.toml"), 2099, 2).is_err());
+        assert!(!root.join("2099/examples/puzzle_2").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,122 @@
+use crate::catch_panic;
+use crate::ledger::{Ledger, Outcome};
+use aoc_utils::puzzle::{Part, Solver};
+use aoc_utils::{input, params};
+use std::path::Path;
+use std::process::ExitCode;
+
+#[derive(Default)]
+struct Summary {
+    correct: usize,
+    mismatched: usize,
+    new: usize,
+    without_input: usize,
+    failed: usize,
+    disagreements: usize,
+}
+
+/// Solves each puzzle against its local input and compares every answer with
+/// the ledger, optionally recording answers the ledger doesn't know about yet.
+/// Variants of each part are checked against the default implementation too.
+pub fn verify(
+    solvers: &[&dyn Solver],
+    parts: &[Part],
+    ledger_path: &Path,
+    record: bool,
+) -> ExitCode {
+    let mut ledger = match Ledger::load(ledger_path) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut summary = Summary::default();
+    for solver in solvers {
+        let (year, day) = (solver.year(), solver.day());
+        let input = match input::load(year, day) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{year} day {day}: skipped, {e}");
+                summary.without_input += 1;
+                continue;
+            }
+        };
+
+        let checked = params::for_input(year, day, None).and_then(|params| {
+            catch_panic(|| {
+                let answers = solver
+                    .solve(&input, &params, parts)
+                    .map_err(|e| e.to_string())?;
+                let disagreements = solver
+                    .cross_check(&input, &params, parts)
+                    .map_err(|e| e.to_string())?;
+                Ok((answers, disagreements))
+            })
+        });
+        let (answers, disagreements) = match checked {
+            Ok(checked) => checked,
+            Err(e) => {
+                println!("{year} day {day}: FAILED, {e}");
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        for (part, answer) in answers {
+            match ledger.check(year, day, part, &answer) {
+                Outcome::Correct => {
+                    println!("{year} day {day} part {part}: ok");
+                    summary.correct += 1;
+                }
+                Outcome::Mismatch { expected } => {
+                    println!("{year} day {day} part {part}: MISMATCH, expected {expected} but got {answer}");
+                    summary.mismatched += 1;
+                }
+                Outcome::New => {
+                    println!("{year} day {day} part {part}: new answer {answer}");
+                    summary.new += 1;
+                    if record {
+                        ledger.record(year, day, part, answer);
+                    }
+                }
+            }
+        }
+
+        for disagreement in disagreements {
+            println!("{year} day {day} VARIANT DISAGREEMENT, {disagreement}");
+            summary.disagreements += 1;
+        }
+    }
+
+    println!(
+        "{} correct, {} mismatched, {} new, {} without input, {} failed, {} variant disagreements",
+        summary.correct,
+        summary.mismatched,
+        summary.new,
+        summary.without_input,
+        summary.failed,
+        summary.disagreements
+    );
+
+    if record && summary.new > 0 {
+        if let Err(e) = ledger.save(ledger_path) {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+        println!(
+            "Recorded {} new answers in {}",
+            summary.new,
+            ledger_path.display()
+        );
+    } else if summary.new > 0 {
+        println!("Pass --record to save the new answers");
+    }
+
+    if summary.mismatched > 0 || summary.failed > 0 || summary.disagreements > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
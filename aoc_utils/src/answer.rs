@@ -0,0 +1,212 @@
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+use std::fmt::{self, Display, Formatter};
+
+/// The answer to a part, normalised so that answers can be compared however
+/// a solution happened to compute them.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// Multi-line output, e.g. letters drawn in pixels, without the trailing newline.
+    Grid(String),
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Grid(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) | Answer::Grid(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+/// The few `u128` answers too big for an `i128` are kept as their digits.
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+/// Strings which are just a number are treated as that number, so that
+/// `"203"` and `203` are the same answer.
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.contains('\n') {
+            return Answer::Grid(value.trim_end_matches('\n').to_owned());
+        }
+        match value.parse::<i128>() {
+            Ok(number) if number.to_string() == value => Answer::Number(number),
+            _ => Answer::Text(value),
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_owned())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AnswerError {
+    NonIntegral(f64),
+}
+
+impl Display for AnswerError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerError::NonIntegral(value) => {
+                write!(f, "{value} is not a whole number so can't be an answer")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswerError {}
+
+/// Floats from e.g. a linear program are accepted when they are within
+/// rounding error of a whole number.
+impl TryFrom<f64> for Answer {
+    type Error = AnswerError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        let rounded = value.round();
+        if value.is_finite() && (value - rounded).abs() < 1e-6 {
+            Ok(Answer::Number(rounded as i128))
+        } else {
+            Err(AnswerError::NonIntegral(value))
+        }
+    }
+}
+
+/// Numbers are written as TOML integers where they fit, anything else as a string.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(number) => match i64::try_from(*number) {
+                Ok(number) => serializer.serialize_i64(number),
+                Err(_) => serializer.serialize_str(&number.to_string()),
+            },
+            Answer::Text(text) | Answer::Grid(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+struct AnswerVisitor;
+
+impl Visitor<'_> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "an integer or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Answer, E> {
+        Ok(Answer::from(value))
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(Answer::from(203_u32), Answer::Number(203))]
+    #[case(Answer::from(u64::MAX), Answer::Number(u64::MAX as i128))]
+    #[case(Answer::from(-5_i128), Answer::Number(-5))]
+    #[case(Answer::from(7_u128), Answer::Number(7))]
+    #[case(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()))]
+    #[case(Answer::from("203"), Answer::Number(203))]
+    #[case(Answer::from("0123"), Answer::Text(String::from("0123")))]
+    #[case(Answer::from("2=-1=0"), Answer::Text(String::from("2=-1=0")))]
+    #[case(
+        Answer::from("mxmxvkd,sqjhc"),
+        Answer::Text(String::from("mxmxvkd,sqjhc"))
+    )]
+    #[case(Answer::from("#..#\n####\n"), Answer::Grid(String::from("#..#\n####")))]
+    fn test_from(#[case] answer: Answer, #[case] expected: Answer) {
+        assert_eq!(answer, expected);
+    }
+
+    #[rstest]
+    #[case(33.0, Ok(Answer::Number(33)))]
+    #[case(32.9999999999, Ok(Answer::Number(33)))]
+    #[case(-2.0, Ok(Answer::Number(-2)))]
+    #[case(33.5, Err(AnswerError::NonIntegral(33.5)))]
+    fn test_try_from_float(#[case] value: f64, #[case] expected: Result<Answer, AnswerError>) {
+        assert_eq!(Answer::try_from(value), expected);
+    }
+
+    #[test]
+    fn test_non_finite_float() {
+        assert!(Answer::try_from(f64::NAN).is_err());
+        assert!(Answer::try_from(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::Number(-7).to_string(), "-7");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert!(Answer::from("#.\n.#").is_multiline());
+        assert!(!Answer::from("CMZ").is_multiline());
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Answers {
+        part_1: Answer,
+        part_2: Answer,
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let answers = Answers {
+            part_1: Answer::Number(i64::MAX as i128 + 1),
+            part_2: Answer::from("#.\n.#"),
+        };
+        let serialised = toml::to_string(&answers).unwrap();
+        assert_eq!(toml::from_str::<Answers>(&serialised).unwrap(), answers);
+
+        let answers: Answers = toml::from_str("part_1 = 203\npart_2 = \"203\"").unwrap();
+        assert_eq!(answers.part_1, answers.part_2);
+        assert!(toml::from_str::<Answers>("part_1 = 1.5\npart_2 = 1").is_err());
+    }
+}
@@ -0,0 +1,300 @@
+//! Cellular automata, where every cell changes at once each generation
+//! according to its own state and those of its neighbours.
+//!
+//! Only the cells that aren't in the default state are stored, so the space
+//! can be unbounded, and only the cells next to a change can change in the
+//! following generation, so quiet regions cost nothing. For this to work the
+//! rule has to keep a default cell with default neighbours as it is.
+
+use crate::geometry::{Direction4, Direction8, Point, Point2D, SightLines};
+use crate::hex::Hex;
+use num::PrimInt;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Which cells count as the neighbours of each cell. Neighbours have to go
+/// both ways, as a change is passed on to the neighbours of the cell that
+/// changed.
+pub trait Topology<C> {
+    fn neighbours(&self, cell: C) -> impl Iterator<Item = C>;
+}
+
+/// The four cells sharing an edge on a square grid.
+#[derive(Clone, Copy, Debug)]
+pub struct Square4;
+
+/// The eight cells sharing an edge or a corner on a square grid.
+#[derive(Clone, Copy, Debug)]
+pub struct Square8;
+
+/// The six cells sharing an edge on a hex grid.
+#[derive(Clone, Copy, Debug)]
+pub struct Hexagonal;
+
+/// The cells sharing a face, edge or corner in any number of dimensions.
+#[derive(Clone, Copy, Debug)]
+pub struct Moore;
+
+impl<T: PrimInt> Topology<Point2D<T>> for Square4 {
+    fn neighbours(&self, cell: Point2D<T>) -> impl Iterator<Item = Point2D<T>> {
+        Direction4::ALL
+            .into_iter()
+            .filter_map(move |direction| direction.step(&cell))
+    }
+}
+
+impl<T: PrimInt> Topology<Point2D<T>> for Square8 {
+    fn neighbours(&self, cell: Point2D<T>) -> impl Iterator<Item = Point2D<T>> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| direction.step(&cell))
+    }
+}
+
+impl Topology<Hex> for Hexagonal {
+    fn neighbours(&self, cell: Hex) -> impl Iterator<Item = Hex> {
+        cell.neighbours().into_iter()
+    }
+}
+
+impl<T: PrimInt, const N: usize> Topology<Point<T, N>> for Moore {
+    fn neighbours(&self, cell: Point<T, N>) -> impl Iterator<Item = Point<T, N>> {
+        cell.neighbours().into_iter()
+    }
+}
+
+/// The first cells seen in each direction, such as seats seen across the floor.
+impl Topology<Point2D<usize>> for SightLines {
+    fn neighbours(&self, cell: Point2D<usize>) -> impl Iterator<Item = Point2D<usize>> {
+        self.visible(&cell)
+    }
+}
+
+/// A cellular automaton with cells of type `C` in states `S`, where `S::default()`
+/// is the state of every cell not given a state, and a rule giving the next
+/// state of a cell from its state and those of its neighbours.
+pub struct Automaton<Top, C, S, R> {
+    topology: Top,
+    rule: R,
+    cells: HashMap<C, S>,
+    /// The cells that could change in the next generation.
+    frontier: HashSet<C>,
+    /// The number of cells not in the default state in each generation so far.
+    populations: Vec<usize>,
+}
+
+impl<Top, C, S, R> Automaton<Top, C, S, R>
+where
+    Top: Topology<C>,
+    C: Copy + Eq + Hash,
+    S: Copy + Default + Eq,
+    R: Fn(S, &[S]) -> S,
+{
+    pub fn new(topology: Top, cells: impl IntoIterator<Item = (C, S)>, rule: R) -> Self {
+        let cells: HashMap<C, S> = cells
+            .into_iter()
+            .filter(|(_, state)| *state != S::default())
+            .collect();
+        let mut frontier = HashSet::new();
+        for &cell in cells.keys() {
+            frontier.insert(cell);
+            frontier.extend(topology.neighbours(cell));
+        }
+        let populations = vec![cells.len()];
+        Self {
+            topology,
+            rule,
+            cells,
+            frontier,
+            populations,
+        }
+    }
+
+    pub fn state(&self, cell: &C) -> S {
+        self.cells.get(cell).copied().unwrap_or_default()
+    }
+
+    /// Every cell not in the default state.
+    pub fn cells(&self) -> impl Iterator<Item = (&C, &S)> + '_ {
+        self.cells.iter()
+    }
+
+    /// The number of cells in a state other than the default one.
+    pub fn count(&self, state: S) -> usize {
+        self.cells.values().filter(|&&other| other == state).count()
+    }
+
+    /// The number of cells not in the default state.
+    pub fn population(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// The population of every generation so far, starting with the first.
+    pub fn populations(&self) -> &[usize] {
+        &self.populations
+    }
+
+    /// The number of generations that have passed.
+    pub fn generation(&self) -> usize {
+        self.populations.len() - 1
+    }
+
+    /// Moves on a generation, returning whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        let mut changes = Vec::new();
+        let mut neighbours = Vec::new();
+        for &cell in self.frontier.iter() {
+            let state = self.state(&cell);
+            neighbours.clear();
+            neighbours.extend(
+                self.topology
+                    .neighbours(cell)
+                    .map(|other| self.state(&other)),
+            );
+            let next = (self.rule)(state, &neighbours);
+            if next != state {
+                changes.push((cell, next));
+            }
+        }
+        self.frontier.clear();
+        for &(cell, state) in changes.iter() {
+            self.frontier.insert(cell);
+            self.frontier.extend(self.topology.neighbours(cell));
+            if state == S::default() {
+                self.cells.remove(&cell);
+            } else {
+                self.cells.insert(cell, state);
+            }
+        }
+        self.populations.push(self.cells.len());
+        !changes.is_empty()
+    }
+
+    /// Moves on a number of generations, which is quick once nothing changes.
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Moves on until a generation where nothing changes, returning the
+    /// number of generations before that.
+    pub fn run_to_fixed_point(&mut self) -> usize {
+        let start = self.generation();
+        while self.step() {}
+        self.generation() - start - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{Grid, Point3D};
+
+    /// Conway's game of life.
+    fn life(alive: bool, neighbours: &[bool]) -> bool {
+        let count = neighbours.iter().filter(|&&other| other).count();
+        count == 3 || (alive && count == 2)
+    }
+
+    fn cells<C: Copy>(points: &[C]) -> impl Iterator<Item = (C, bool)> + '_ {
+        points.iter().map(|&point| (point, true))
+    }
+
+    #[test]
+    fn test_blinker() {
+        let blinker = [(0, 1), (1, 1), (2, 1)].map(|(x, y)| Point2D::new(x, y));
+        let mut automaton = Automaton::new(Square8, cells(&blinker), life);
+        automaton.step();
+        assert!(automaton.state(&Point2D::new(1, 0)));
+        assert!(!automaton.state(&Point2D::new(0, 1)));
+        automaton.run(3);
+        assert_eq!(automaton.generation(), 4);
+        let mut alive: Vec<_> = automaton.cells().map(|(point, _)| *point).collect();
+        alive.sort();
+        assert_eq!(alive, blinker);
+        assert_eq!(automaton.populations(), &[3; 5]);
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        // a block stays as it is, and the lone cells either side die off
+        let block = [(0, 0), (1, 0), (0, 1), (1, 1), (5, 5), (-5, -5)];
+        let points = block.map(|(x, y)| Point2D::<i32>::new(x, y));
+        let mut automaton = Automaton::new(Square8, cells(&points), life);
+        assert_eq!(automaton.run_to_fixed_point(), 1);
+        assert_eq!(automaton.populations(), &[6, 4, 4]);
+        assert!(automaton.frontier.is_empty());
+        // cells on the edge of an unsigned grid have fewer neighbours
+        let corner = [(0, 0), (1, 0), (0, 1)].map(|(x, y)| Point2D::<usize>::new(x, y));
+        let mut automaton = Automaton::new(Square4, cells(&corner), life);
+        automaton.step();
+        assert_eq!(automaton.population(), 1);
+        let mut automaton = Automaton::new(Square8, cells(&corner), life);
+        automaton.step();
+        assert_eq!(automaton.population(), 4);
+    }
+
+    #[test]
+    fn test_conway_cubes() {
+        // the example for 2020 day 17
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        let points = glider.map(|(x, y)| Point3D::new([x, y, 0]));
+        let mut automaton = Automaton::new(Moore, cells(&points), life);
+        automaton.run(6);
+        assert_eq!(automaton.populations(), &[5, 11, 21, 38, 58, 101, 112]);
+    }
+
+    #[test]
+    fn test_hexagonal() {
+        // a black tile with two neighbours on opposite sides
+        let tiles = [Hex::new(-1, 0), Hex::ORIGIN, Hex::new(1, 0)];
+        let flip = |black: bool, neighbours: &[bool]| {
+            let count = neighbours.iter().filter(|&&other| other).count();
+            if black {
+                count == 1 || count == 2
+            } else {
+                count == 2
+            }
+        };
+        let mut automaton = Automaton::new(Hexagonal, cells(&tiles), flip);
+        automaton.step();
+        assert_eq!(automaton.population(), 7);
+        assert!(automaton.state(&Hex::new(0, 1)) && automaton.state(&Hex::new(0, -1)));
+    }
+
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    enum Seat {
+        #[default]
+        Floor,
+        Empty,
+        Occupied,
+    }
+
+    #[test]
+    fn test_sight_lines() {
+        let seats = "L.LL.LL.LL\nLLLLLLL.LL\nL.L.L..L..\nLLLL.LL.LL\nL.LL.LL.LL\n\
+                     L.LLLLL.LL\n..L.L.....\nLLLLLLLLLL\nL.LLLLLL.L\nL.LLLLL.LL";
+        let grid = Grid::parse(seats, |c| {
+            Ok(if c == 'L' { Seat::Empty } else { Seat::Floor })
+        })
+        .unwrap();
+        let sight_lines = SightLines::new(&grid, |&seat| seat == Seat::Floor);
+        let rule = |seat: Seat, neighbours: &[Seat]| {
+            let occupied = neighbours
+                .iter()
+                .filter(|&&other| other == Seat::Occupied)
+                .count();
+            match seat {
+                Seat::Empty if occupied == 0 => Seat::Occupied,
+                Seat::Occupied if occupied >= 5 => Seat::Empty,
+                _ => seat,
+            }
+        };
+        let cells = grid.iter().map(|(point, &seat)| (point, seat));
+        let mut automaton = Automaton::new(sight_lines, cells, rule);
+        assert_eq!(automaton.run_to_fixed_point(), 6);
+        assert_eq!(automaton.count(Seat::Occupied), 26);
+        assert_eq!(automaton.population(), 71);
+    }
+}
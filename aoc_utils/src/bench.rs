@@ -0,0 +1,114 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Settings {
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            runs: 10,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Summarises the durations of each run, which must not be empty.
+    pub fn from_durations(mut durations: Vec<Duration>) -> Self {
+        assert!(!durations.is_empty(), "Need at least one run to summarise");
+        durations.sort();
+        let runs = durations.len();
+        let median = if runs % 2 == 1 {
+            durations[runs / 2]
+        } else {
+            (durations[runs / 2 - 1] + durations[runs / 2]) / 2
+        };
+        // nearest rank, so the p95 is always one of the observed runs
+        let p95_rank = (runs * 95).div_ceil(100);
+        Self {
+            runs,
+            min: durations[0],
+            median,
+            p95: durations[p95_rank - 1],
+        }
+    }
+}
+
+/// A timed phase of solving a puzzle, e.g. `parse` or `part_1`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timing {
+    pub phase: String,
+    pub stats: Stats,
+}
+
+/// Times `f` over the configured number of runs after warming up.
+pub fn measure<T, F: FnMut() -> T>(settings: &Settings, mut f: F) -> Stats {
+    for _ in 0..settings.warmup {
+        black_box(f());
+    }
+    let durations = (0..settings.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_durations(durations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|ms| Duration::from_millis(*ms)).collect()
+    }
+
+    #[rstest]
+    #[case(&[5], 5, 5, 5)]
+    #[case(&[4, 1, 3, 2, 5], 1, 3, 5)]
+    #[case(&[9, 1, 5], 1, 5, 9)]
+    #[case(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21], 1, 11, 20)]
+    fn test_stats(
+        #[case] durations: &[u64],
+        #[case] min: u64,
+        #[case] median: u64,
+        #[case] p95: u64,
+    ) {
+        let stats = Stats::from_durations(millis(durations));
+        assert_eq!(stats.runs, durations.len());
+        assert_eq!(stats.min, Duration::from_millis(min));
+        assert_eq!(stats.median, Duration::from_millis(median));
+        assert_eq!(stats.p95, Duration::from_millis(p95));
+    }
+
+    #[test]
+    fn test_even_median_is_midpoint() {
+        let stats = Stats::from_durations(millis(&[1, 2, 3, 4]));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+    }
+
+    #[test]
+    fn test_measure_counts_runs_and_warmup() {
+        let mut calls = 0;
+        let settings = Settings { warmup: 2, runs: 5 };
+        let stats = measure(&settings, || calls += 1);
+        assert_eq!(calls, 7);
+        assert_eq!(stats.runs, 5);
+        assert!(stats.min <= stats.median && stats.median <= stats.p95);
+    }
+}
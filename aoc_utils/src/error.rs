@@ -0,0 +1,229 @@
+use crate::answer::AnswerError;
+use crate::params::ParamError;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// Where in the input a problem was found.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Position {
+    /// Index of the line, from 0 as given by `lines().enumerate()`.
+    pub line: usize,
+    /// Index of the character within the line, if known.
+    pub column: Option<usize>,
+}
+
+/// Why a puzzle couldn't be solved, so that the runner can report it and
+/// move on to the next puzzle instead of panicking.
+#[derive(Debug, PartialEq)]
+pub enum PuzzleError {
+    /// The input isn't in the format given by the puzzle description.
+    Malformed {
+        message: String,
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+    /// A valid input which the solution doesn't handle, e.g. because it
+    /// relies on a property the real inputs happen to have.
+    Unsupported {
+        message: String,
+        line: Option<usize>,
+    },
+    Params(ParamError),
+}
+
+impl PuzzleError {
+    pub fn malformed(message: impl Into<String>) -> Self {
+        PuzzleError::Malformed {
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn unsupported(message: impl Into<String>) -> Self {
+        PuzzleError::Unsupported {
+            message: message.into(),
+            line: None,
+        }
+    }
+
+    /// Adds the index of the line the error was found on, unless a more
+    /// specific line was already given.
+    pub fn on_line(mut self, index: usize) -> Self {
+        match &mut self {
+            PuzzleError::Malformed { line, .. } | PuzzleError::Unsupported { line, .. } => {
+                line.get_or_insert(index);
+            }
+            PuzzleError::Params(_) => {}
+        }
+        self
+    }
+
+    /// Adds the index of the character within its line the error was found at.
+    pub fn at_column(mut self, index: usize) -> Self {
+        if let PuzzleError::Malformed { column, .. } = &mut self {
+            column.get_or_insert(index);
+        }
+        self
+    }
+
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            PuzzleError::Malformed { line, column, .. } => line.map(|line| Position {
+                line,
+                column: *column,
+            }),
+            PuzzleError::Unsupported { line, .. } => {
+                line.map(|line| Position { line, column: None })
+            }
+            PuzzleError::Params(_) => None,
+        }
+    }
+}
+
+/// Positions are shown counting from 1, as in an editor.
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}", self.line + 1)?;
+        if let Some(column) = self.column {
+            write!(f, ", column {}", column + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (kind, message) = match self {
+            PuzzleError::Malformed { message, .. } => ("Malformed input", message),
+            PuzzleError::Unsupported { message, .. } => ("Unsupported input", message),
+            PuzzleError::Params(e) => return write!(f, "{e}"),
+        };
+        match self.position() {
+            Some(position) => write!(f, "{kind} at {position}: {message}"),
+            None => write!(f, "{kind}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+impl From<ParamError> for PuzzleError {
+    fn from(e: ParamError) -> Self {
+        PuzzleError::Params(e)
+    }
+}
+
+/// A solution which can't come up with a valid answer doesn't handle the input.
+impl From<AnswerError> for PuzzleError {
+    fn from(e: AnswerError) -> Self {
+        PuzzleError::unsupported(e.to_string())
+    }
+}
+
+/// Parses each line of the input, adding the line to any error.
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, PuzzleError>,
+) -> Result<Vec<T>, PuzzleError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|e| e.on_line(index)))
+        .collect()
+}
+
+/// Parses each field of a line split on `separator`, such as an Intcode
+/// program, adding the column the field starts at to any error.
+pub fn parse_fields<T>(
+    line: &str,
+    separator: char,
+    mut parse: impl FnMut(&str) -> Result<T, PuzzleError>,
+) -> Result<Vec<T>, PuzzleError> {
+    let mut column = 0;
+    line.split(separator)
+        .map(|field| {
+            let start = column;
+            column += field.chars().count() + 1;
+            parse(field).map_err(|e| e.at_column(start))
+        })
+        .collect()
+}
+
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, PuzzleError> {
+    text.trim()
+        .parse()
+        .map_err(|_| PuzzleError::malformed(format!("Expected a number, not {text:?}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            PuzzleError::malformed("Unexpected direction 'q'").to_string(),
+            "Malformed input: Unexpected direction 'q'"
+        );
+        assert_eq!(
+            PuzzleError::malformed("Unexpected direction 'q'")
+                .at_column(4)
+                .on_line(2)
+                .to_string(),
+            "Malformed input at line 3, column 5: Unexpected direction 'q'"
+        );
+        assert_eq!(
+            PuzzleError::unsupported("Too hard").on_line(0).to_string(),
+            "Unsupported input at line 1: Too hard"
+        );
+    }
+
+    #[test]
+    fn test_innermost_line_is_kept() {
+        let error = PuzzleError::malformed("Bad").on_line(3).on_line(7);
+        assert_eq!(
+            error.position(),
+            Some(Position {
+                line: 3,
+                column: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            parse_lines("1\n2\n3", parse_number::<u8>),
+            Ok(vec![1, 2, 3])
+        );
+        let error = parse_lines("1\n2\nthree", parse_number::<u8>).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Malformed input at line 3: Expected a number, not \"three\""
+        );
+    }
+
+    #[test]
+    fn test_parse_fields() {
+        assert_eq!(
+            parse_fields("1,-2,3", ',', parse_number::<i8>),
+            Ok(vec![1, -2, 3])
+        );
+        let error = parse_lines("1,2\n1,-2,x3", |line| {
+            parse_fields(line, ',', parse_number::<i8>)
+        })
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Malformed input at line 2, column 6: Expected a number, not \"x3\""
+        );
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number::<i32>(" -12"), Ok(-12));
+        assert!(parse_number::<u32>("-12").is_err());
+        assert!(parse_number::<u32>("").is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use super::{Grid, GridBounds, Point2D};
+
+type Point = Point2D<usize>;
+
+const BITS: usize = u64::BITS as usize;
+
+/// A grid of cells that are either set or not, packed 64 to a word with a
+/// row starting on a fresh word. Whole words are worked on at once, so
+/// counting the neighbours of every cell takes a handful of shifts and
+/// logical operations per 64 cells instead of eight lookups per cell.
+///
+/// Bits past the end of each row are always clear.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BitGrid {
+    bounds: GridBounds,
+    /// The number of words in each row.
+    stride: usize,
+    words: Vec<u64>,
+}
+
+/// The number of set neighbours of every cell of a [`BitGrid`], kept as the
+/// four bits of each count in separate grids so that the cells with the
+/// counts wanted can be picked out a word at a time.
+#[derive(Clone, Debug)]
+pub struct NeighbourCounts {
+    bounds: GridBounds,
+    stride: usize,
+    /// Bit `i` of every count, in the same layout as the grid.
+    planes: [Vec<u64>; 4],
+}
+
+impl BitGrid {
+    /// A grid with no cells set.
+    pub fn new(bounds: GridBounds) -> Self {
+        let stride = bounds.width.div_ceil(BITS);
+        Self {
+            bounds,
+            stride,
+            words: vec![0; stride * bounds.height],
+        }
+    }
+
+    /// The cells of a grid for which the function holds.
+    pub fn from_grid<T>(grid: &Grid<T>, mut set: impl FnMut(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.bounds());
+        for (point, cell) in grid.iter() {
+            if set(cell) {
+                bits.set(&point, true);
+            }
+        }
+        bits
+    }
+
+    pub fn bounds(&self) -> GridBounds {
+        self.bounds
+    }
+
+    fn word(&self, point: &Point) -> (usize, u64) {
+        (
+            point.y * self.stride + point.x / BITS,
+            1 << (point.x % BITS),
+        )
+    }
+
+    /// Whether the cell is set, which it never is outside the grid.
+    pub fn get(&self, point: &Point) -> bool {
+        if !self.bounds.contains(point) {
+            return false;
+        }
+        let (index, mask) = self.word(point);
+        self.words[index] & mask != 0
+    }
+
+    pub fn set(&mut self, point: &Point, value: bool) {
+        assert!(self.bounds.contains(point), "{point} is outside the grid");
+        let (index, mask) = self.word(point);
+        if value {
+            self.words[index] |= mask;
+        } else {
+            self.words[index] &= !mask;
+        }
+    }
+
+    /// The number of cells set.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Every cell set, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(move |(index, &word)| {
+                let (y, x) = (index / self.stride, index % self.stride * BITS);
+                let mut rest = word;
+                std::iter::from_fn(move || {
+                    (rest != 0).then(|| {
+                        let bit = rest.trailing_zeros() as usize;
+                        rest &= rest - 1;
+                        Point::new(x + bit, y)
+                    })
+                })
+            })
+    }
+
+    /// The cells set in both grids.
+    pub fn and(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// The cells set in either grid.
+    pub fn or(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// The cells set in this grid but not the other.
+    pub fn and_not(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    fn combine(&self, other: &Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        assert_eq!(self.bounds, other.bounds, "Expected grids of the same size");
+        let words = self
+            .words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(&a, &b)| f(a, b))
+            .collect();
+        Self { words, ..*self }
+    }
+
+    /// The cells of a row, shifted so that each lines up with the one to its
+    /// west and the one to its east, along with the cells themselves.
+    fn row_shifts(&self, y: usize) -> Vec<[u64; 3]> {
+        let row = &self.words[y * self.stride..(y + 1) * self.stride];
+        (0..self.stride)
+            .map(|i| {
+                let previous = if i > 0 { row[i - 1] >> (BITS - 1) } else { 0 };
+                let next = row.get(i + 1).map_or(0, |word| word << (BITS - 1));
+                [(row[i] << 1) | previous, row[i], (row[i] >> 1) | next]
+            })
+            .collect()
+    }
+
+    fn counts(&self, diagonals: bool) -> NeighbourCounts {
+        let mut planes = [0; 4].map(|_| vec![0; self.words.len()]);
+        let empty = vec![[0; 3]; self.stride];
+        let rows: Vec<_> = (0..self.bounds.height)
+            .map(|y| self.row_shifts(y))
+            .collect();
+        for y in 0..self.bounds.height {
+            let above = if y > 0 { &rows[y - 1] } else { &empty };
+            let below = rows.get(y + 1).unwrap_or(&empty);
+            for i in 0..self.stride {
+                let ([north_west, north, north_east], [south_west, south, south_east]) =
+                    (above[i], below[i]);
+                let [west, _, east] = rows[y][i];
+                let neighbours = [
+                    north, west, east, south, north_west, north_east, south_west, south_east,
+                ];
+                // add each neighbour to the counts of every cell at once, a
+                // bit at a time with the carry rippling up
+                let index = y * self.stride + i;
+                for mut carry in neighbours.into_iter().take(if diagonals { 8 } else { 4 }) {
+                    for plane in planes.iter_mut() {
+                        let sum = plane[index] ^ carry;
+                        carry &= plane[index];
+                        plane[index] = sum;
+                    }
+                }
+            }
+        }
+        NeighbourCounts {
+            bounds: self.bounds,
+            stride: self.stride,
+            planes,
+        }
+    }
+
+    /// The number of set cells sharing an edge with each cell.
+    pub fn adjacent_counts(&self) -> NeighbourCounts {
+        self.counts(false)
+    }
+
+    /// The number of set cells sharing an edge or a corner with each cell.
+    pub fn neighbour_counts(&self) -> NeighbourCounts {
+        self.counts(true)
+    }
+
+    /// Whether each cell is set.
+    pub fn to_grid(&self) -> Grid<bool> {
+        let cells = self.bounds.points().map(|point| self.get(&point)).collect();
+        Grid::new(self.bounds, cells)
+    }
+}
+
+impl NeighbourCounts {
+    pub fn get(&self, point: &Point) -> u8 {
+        let index = point.y * self.stride + point.x / BITS;
+        let bit = point.x % BITS;
+        (0..4)
+            .map(|i| (((self.planes[i][index] >> bit) & 1) as u8) << i)
+            .sum()
+    }
+
+    /// The cells with a count the function holds for.
+    pub fn matching(&self, f: impl Fn(u8) -> bool) -> BitGrid {
+        let counts: Vec<u8> = (0..=8).filter(|&count| f(count)).collect();
+        let mut bits = BitGrid::new(self.bounds);
+        for (index, word) in bits.words.iter_mut().enumerate() {
+            for &count in counts.iter() {
+                *word |= (0..4).fold(!0, |equal, i| {
+                    let plane = self.planes[i][index];
+                    equal & if count >> i & 1 == 1 { plane } else { !plane }
+                });
+            }
+        }
+        // keep the bits past the end of each row clear
+        let spare = self.stride * BITS - self.bounds.width;
+        if spare > 0 {
+            for row in bits.words.chunks_mut(self.stride) {
+                *row.last_mut().unwrap() &= u64::MAX >> spare;
+            }
+        }
+        bits
+    }
+
+    /// The cells with exactly this many neighbours set.
+    pub fn exactly(&self, count: u8) -> BitGrid {
+        self.matching(|other| other == count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    /// A scattering of cells across several words of each row.
+    fn scattered(width: usize, height: usize) -> Grid<bool> {
+        let mut state = 12345u64;
+        let cells = (0..width * height)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                state >> 62 == 0
+            })
+            .collect();
+        Grid::new(GridBounds::new(width, height), cells)
+    }
+
+    #[test]
+    fn test_bits() {
+        let mut bits = BitGrid::new(GridBounds::new(70, 3));
+        for point in [
+            Point::new(0, 0),
+            Point::new(63, 1),
+            Point::new(64, 1),
+            Point::new(69, 2),
+        ] {
+            bits.set(&point, true);
+        }
+        assert_eq!(bits.count(), 4);
+        assert!(bits.get(&Point::new(64, 1)));
+        assert!(!bits.get(&Point::new(70, 1)));
+        bits.set(&Point::new(0, 0), false);
+        let points: Vec<_> = bits.points().collect();
+        assert_eq!(
+            points,
+            vec![Point::new(63, 1), Point::new(64, 1), Point::new(69, 2)]
+        );
+        assert_eq!(BitGrid::from_grid(&bits.to_grid(), |&set| set), bits);
+    }
+
+    #[rstest]
+    #[case(5, 4)]
+    #[case(64, 3)]
+    #[case(130, 7)]
+    fn test_neighbour_counts(#[case] width: usize, #[case] height: usize) {
+        let grid = scattered(width, height);
+        let bits = BitGrid::from_grid(&grid, |&set| set);
+        let (adjacent, all) = (bits.adjacent_counts(), bits.neighbour_counts());
+        for point in grid.bounds().points() {
+            let count = |neighbours: Vec<Point>| neighbours.iter().filter(|&&p| grid[p]).count();
+            let expected = count(grid.adjacent_neighbours(&point).collect());
+            assert_eq!(adjacent.get(&point) as usize, expected, "{point}");
+            let expected = count(grid.neighbours(&point).collect());
+            assert_eq!(all.get(&point) as usize, expected, "{point}");
+        }
+        // cells past the end of the rows never count
+        let lonely = all.exactly(0);
+        assert_eq!(lonely.count(), lonely.points().count());
+        assert!(lonely.points().all(|point| grid.bounds().contains(&point)));
+    }
+
+    #[test]
+    fn test_life() {
+        let blinker = BitGrid::from_grid(&Grid::parse(".....\n.###.\n.....", Ok).unwrap(), |&c| {
+            c == '#'
+        });
+        let counts = blinker.neighbour_counts();
+        let next = counts.exactly(3).or(&blinker.and(&counts.exactly(2)));
+        let points: Vec<_> = next.points().collect();
+        assert_eq!(
+            points,
+            vec![Point::new(2, 0), Point::new(2, 1), Point::new(2, 2)]
+        );
+        assert_eq!(blinker.and_not(&next).count(), 2);
+    }
+}
@@ -0,0 +1,254 @@
+use super::Point;
+use num::PrimInt;
+use std::iter;
+
+/// The smallest box, edges included, containing a set of points. Boxes are
+/// axis-aligned, so they double as rectangles and cuboids.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BoundingBox<T, const N: usize> {
+    pub min: Point<T, N>,
+    pub max: Point<T, N>,
+}
+
+pub type Rect<T> = BoundingBox<T, 2>;
+pub type Cuboid<T> = BoundingBox<T, 3>;
+
+impl<T: PrimInt, const N: usize> BoundingBox<T, N> {
+    /// The box containing just the one point.
+    pub fn new(point: Point<T, N>) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    /// The box with these opposite corners, in either order.
+    pub fn from_corners(a: Point<T, N>, b: Point<T, N>) -> Self {
+        Self {
+            min: a.zip_with(b, T::min),
+            max: a.zip_with(b, T::max),
+        }
+    }
+
+    /// `None` if there are no points to bound.
+    pub fn from_points(points: impl IntoIterator<Item = Point<T, N>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let mut bounds = Self::new(points.next()?);
+        for point in points {
+            bounds.include(&point);
+        }
+        Some(bounds)
+    }
+
+    /// Grows the box just enough to contain the point.
+    pub fn include(&mut self, point: &Point<T, N>) {
+        self.min = self.min.zip_with(*point, T::min);
+        self.max = self.max.zip_with(*point, T::max);
+    }
+
+    pub fn contains(&self, point: &Point<T, N>) -> bool {
+        (0..N).all(|axis| self.min[axis] <= point[axis] && point[axis] <= self.max[axis])
+    }
+
+    /// Whether every point of the other box is also in this one.
+    pub fn contains_box(&self, other: &Self) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    /// Whether the boxes share at least one point.
+    pub fn intersects(&self, other: &Self) -> bool {
+        (0..N).all(|axis| self.min[axis] <= other.max[axis] && other.min[axis] <= self.max[axis])
+    }
+
+    /// The points in both boxes, `None` if they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
+        }
+        Some(Self {
+            min: self.min.zip_with(other.min, T::max),
+            max: self.max.zip_with(other.max, T::min),
+        })
+    }
+
+    /// The smallest box containing both, which may include points in neither.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: self.min.zip_with(other.min, T::min),
+            max: self.max.zip_with(other.max, T::max),
+        }
+    }
+
+    /// The points in this box but not the other, as disjoint boxes: at most
+    /// two per axis, slicing off what's either side of the overlap before
+    /// moving on to the next axis. Summing their volumes counts points
+    /// without visiting them, e.g. for cuboids being switched on and off.
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        let Some(overlap) = self.intersection(other) else {
+            return vec![*self];
+        };
+        let mut pieces = Vec::with_capacity(2 * N);
+        let mut rest = *self;
+        for axis in 0..N {
+            if rest.min[axis] < overlap.min[axis] {
+                let mut below = rest;
+                below.max[axis] = overlap.min[axis] - T::one();
+                pieces.push(below);
+                rest.min[axis] = overlap.min[axis];
+            }
+            if overlap.max[axis] < rest.max[axis] {
+                let mut above = rest;
+                above.min[axis] = overlap.max[axis] + T::one();
+                pieces.push(above);
+                rest.max[axis] = overlap.max[axis];
+            }
+        }
+        pieces
+    }
+
+    /// The box with `margin` more on every side, stopping at the edge of the
+    /// integer type. A margin of one leaves room to walk around the outside.
+    pub fn expanded(&self, margin: T) -> Self {
+        Self {
+            min: Point(self.min.0.map(|coord| coord.saturating_sub(margin))),
+            max: Point(self.max.0.map(|coord| coord.saturating_add(margin))),
+        }
+    }
+
+    /// The number of points along each axis.
+    pub fn lengths(&self) -> [T; N] {
+        (self.max - self.min + Point([T::one(); N])).0
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> u64 {
+        self.lengths()
+            .iter()
+            .map(|length| length.to_u64().unwrap_or(u64::MAX))
+            .fold(1, u64::saturating_mul)
+    }
+
+    /// Every point in the box, in order.
+    pub fn points(&self) -> impl Iterator<Item = Point<T, N>> {
+        let Self { min, max } = *self;
+        iter::successors(Some(min), move |point| {
+            // count up like an odometer, with the last axis changing fastest
+            let mut next = *point;
+            for axis in (0..N).rev() {
+                if next[axis] < max[axis] {
+                    next[axis] = next[axis] + T::one();
+                    return Some(next);
+                }
+                next[axis] = min[axis];
+            }
+            None
+        })
+    }
+}
+
+impl<T: PrimInt> Rect<T> {
+    /// The number of points in the rectangle, edges included.
+    pub fn area(&self) -> u64 {
+        self.volume()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounding_box() {
+        let points = [
+            Point::new([1, 5, -2]),
+            Point::new([3, 0, 0]),
+            Point::new([2, 2, 2]),
+        ];
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(bounds.min, Point::new([1, 0, -2]));
+        assert_eq!(bounds.max, Point::new([3, 5, 2]));
+        assert_eq!(bounds.lengths(), [3, 6, 5]);
+        assert_eq!(bounds.volume(), 90);
+        assert!(points.iter().all(|point| bounds.contains(point)));
+        assert!(!bounds.contains(&Point::new([0, 0, 0])));
+        assert!(bounds.expanded(1).contains(&Point::new([0, 0, 0])));
+        assert_eq!(BoundingBox::<i32, 3>::from_points([]), None);
+    }
+
+    #[test]
+    fn test_bounding_box_points() {
+        let bounds = BoundingBox::from_points([Point::new([0u8, 1]), Point::new([1, 3])]).unwrap();
+        let points: Vec<_> = bounds.points().collect();
+        assert_eq!(points.len() as u64, bounds.volume());
+        assert_eq!(points[0], Point::new([0, 1]));
+        assert_eq!(points[1], Point::new([0, 2]));
+        assert_eq!(points[5], Point::new([1, 3]));
+        assert_eq!(bounds.expanded(1).min, Point::new([0, 0]));
+    }
+
+    #[test]
+    fn test_from_corners() {
+        let rect = Rect::from_corners(Point::new([2, 8]), Point::new([6, 4]));
+        assert_eq!(rect.min, Point::new([2, 4]));
+        assert_eq!(rect.max, Point::new([6, 8]));
+        assert_eq!(rect.area(), 25);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = Rect::from_corners(Point::new([0, 0]), Point::new([3, 3]));
+        let b = Rect::from_corners(Point::new([2, 1]), Point::new([5, 2]));
+        let c = Rect::from_corners(Point::new([4, 4]), Point::new([5, 5]));
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+        let overlap = Rect::from_corners(Point::new([2, 1]), Point::new([3, 2]));
+        assert_eq!(a.intersection(&b), Some(overlap));
+        assert_eq!(a.intersection(&c), None);
+        assert_eq!(
+            a.union(&c),
+            Rect::from_corners(Point::new([0, 0]), Point::new([5, 5]))
+        );
+        assert!(a.union(&b).contains_box(&b));
+        assert!(!a.contains_box(&b));
+    }
+
+    #[test]
+    fn test_subtract() {
+        let outer = Cuboid::from_corners(Point::new([0, 0, 0]), Point::new([4, 4, 4]));
+        let inner = Cuboid::from_corners(Point::new([1, 1, 1]), Point::new([2, 2, 2]));
+        let pieces = outer.subtract(&inner);
+        assert_eq!(pieces.len(), 6);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<u64>(), 125 - 8);
+        for (i, piece) in pieces.iter().enumerate() {
+            assert!(outer.contains_box(piece));
+            assert!(!piece.intersects(&inner));
+            assert!(pieces[i + 1..].iter().all(|other| !piece.intersects(other)));
+        }
+        assert_eq!(inner.subtract(&outer), vec![]);
+        let apart = Cuboid::new(Point::new([9, 9, 9]));
+        assert_eq!(outer.subtract(&apart), vec![outer]);
+    }
+
+    #[test]
+    fn test_reactor() {
+        // switch cuboids on and off in turn, keeping the lit ones disjoint
+        let steps = [
+            (true, [10, 10, 10], [12, 12, 12]),
+            (true, [11, 11, 11], [13, 13, 13]),
+            (false, [9, 9, 9], [11, 11, 11]),
+            (true, [10, 10, 10], [10, 10, 10]),
+        ];
+        let mut lit: Vec<Cuboid<i32>> = Vec::new();
+        for (on, a, b) in steps {
+            let cuboid = Cuboid::from_corners(Point::new(a), Point::new(b));
+            lit = lit
+                .iter()
+                .flat_map(|other| other.subtract(&cuboid))
+                .collect();
+            if on {
+                lit.push(cuboid);
+            }
+        }
+        assert_eq!(lit.iter().map(Cuboid::volume).sum::<u64>(), 39);
+    }
+}
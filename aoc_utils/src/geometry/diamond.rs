@@ -0,0 +1,233 @@
+use super::{abs_diff, Point, Point2D, Rect};
+use num::{PrimInt, Signed};
+
+/// The points within a Manhattan distance of a centre, which make a diamond
+/// on the grid. Rotating by 45 degrees with [`to_rotated`] turns diamonds
+/// into squares, so questions about them become ones about rectangles.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Diamond<T> {
+    pub centre: Point2D<T>,
+    pub radius: T,
+}
+
+/// The point in coordinates `(x + y, x - y)` running along the diagonals.
+pub fn to_rotated<T: PrimInt>(point: &Point2D<T>) -> Point<T, 2> {
+    Point::new([point.x + point.y, point.x - point.y])
+}
+
+/// The inverse of [`to_rotated`], `None` for the half of the rotated points
+/// that fall between grid points, where the coordinates have different parity.
+pub fn from_rotated<T: PrimInt>(point: &Point<T, 2>) -> Option<Point2D<T>> {
+    let two = T::one() + T::one();
+    let (sum, difference) = (point[0] + point[1], point[0] - point[1]);
+    (sum % two == T::zero()).then(|| Point2D::new(sum / two, difference / two))
+}
+
+impl<T: PrimInt + Signed> Diamond<T> {
+    pub fn new(centre: Point2D<T>, radius: T) -> Self {
+        Self { centre, radius }
+    }
+
+    /// The smallest diamond around the centre reaching the point, e.g. a
+    /// sensor and the closest beacon it can see.
+    pub fn through(centre: Point2D<T>, point: &Point2D<T>) -> Self {
+        Self::new(centre, centre.manhattan_distance(point))
+    }
+
+    pub fn contains(&self, point: &Point2D<T>) -> bool {
+        self.centre.manhattan_distance(point) <= self.radius
+    }
+
+    /// The number of points in the diamond.
+    pub fn area(&self) -> u64 {
+        let radius = self.radius.to_u64().unwrap();
+        2 * radius * (radius + 1) + 1
+    }
+
+    /// The first and last `x` in row `y`, if the diamond reaches it.
+    pub fn row(&self, y: T) -> Option<(T, T)> {
+        let reach = self.radius.checked_sub(&abs_diff(self.centre.y, y))?;
+        (reach >= T::zero()).then(|| (self.centre.x - reach, self.centre.x + reach))
+    }
+
+    /// The first and last `y` in column `x`, if the diamond reaches it.
+    pub fn column(&self, x: T) -> Option<(T, T)> {
+        let reach = self.radius.checked_sub(&abs_diff(self.centre.x, x))?;
+        (reach >= T::zero()).then(|| (self.centre.y - reach, self.centre.y + reach))
+    }
+
+    /// The square covering the diamond after rotating with [`to_rotated`].
+    /// Only the points in it with coordinates of the same parity are in the
+    /// diamond.
+    pub fn rotated(&self) -> Rect<T> {
+        let centre = to_rotated(&self.centre);
+        let radius = Point::new([self.radius; 2]);
+        Rect::from_corners(centre - radius, centre + radius)
+    }
+}
+
+/// Joins inclusive intervals that overlap or touch, in order.
+fn merge<T: PrimInt>(mut intervals: Vec<(T, T)>) -> Vec<(T, T)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + T::one() => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// The `x` covered by any of the diamonds along row `y`, as separate
+/// inclusive intervals in order.
+pub fn row_coverage<T: PrimInt + Signed>(diamonds: &[Diamond<T>], y: T) -> Vec<(T, T)> {
+    merge(
+        diamonds
+            .iter()
+            .filter_map(|diamond| diamond.row(y))
+            .collect(),
+    )
+}
+
+/// The points within the bounds that none of the diamonds cover, but which
+/// are right next to one that does. This finds a lone gap between diamonds
+/// without scanning every row, as all the candidates lie on lines just
+/// outside the edges of the rotated squares.
+pub fn uncovered<T: PrimInt + Signed>(
+    diamonds: &[Diamond<T>],
+    bounds: &Rect<T>,
+) -> Vec<Point2D<T>> {
+    let one = T::one();
+    let squares: Vec<_> = diamonds.iter().map(Diamond::rotated).collect();
+    let mut points = Vec::new();
+    for axis in [0, 1] {
+        let other = 1 - axis;
+        for square in squares.iter() {
+            for line in [square.min[axis] - one, square.max[axis] + one] {
+                let covered = merge(
+                    squares
+                        .iter()
+                        .filter(|s| s.min[axis] <= line && line <= s.max[axis])
+                        .map(|s| (s.min[other], s.max[other]))
+                        .collect(),
+                );
+                // the stretch of the line bordering the square and in bounds
+                let (low, high) = rotated_span(bounds, axis, line);
+                let mut start = low.max(square.min[other] - one);
+                let end = high.min(square.max[other] + one);
+                let after_end = (end + one, end);
+                for (covered_start, covered_end) in covered.into_iter().chain([after_end]) {
+                    for along in num::range_inclusive(start, end.min(covered_start - one)) {
+                        let mut rotated = Point::new([line; 2]);
+                        rotated[other] = along;
+                        points.extend(from_rotated(&rotated));
+                    }
+                    start = start.max(covered_end + one);
+                }
+            }
+        }
+    }
+    points.sort_unstable();
+    points.dedup();
+    points
+}
+
+/// The first and last coordinate along a rotated line at `line` on `axis`
+/// that can land within the bounds once rotated back.
+fn rotated_span<T: PrimInt>(bounds: &Rect<T>, axis: usize, line: T) -> (T, T) {
+    let two = T::one() + T::one();
+    let ([min_x, min_y], [max_x, max_y]) = (bounds.min.0, bounds.max.0);
+    if axis == 0 {
+        // x = (line + v) / 2 and y = (line - v) / 2
+        let low = (two * min_x - line).max(line - two * max_y);
+        (low, (two * max_x - line).min(line - two * min_y))
+    } else {
+        // x = (u + line) / 2 and y = (u - line) / 2
+        let low = (two * min_x - line).max(two * min_y + line);
+        (low, (two * max_x - line).min(two * max_y + line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    /// The sensors and their closest beacons from the example for 2022 day 15.
+    fn sensors() -> Vec<Diamond<i64>> {
+        [
+            (2, 18, -2, 15),
+            (9, 16, 10, 16),
+            (13, 2, 15, 3),
+            (12, 14, 10, 16),
+            (10, 20, 10, 16),
+            (14, 17, 10, 16),
+            (8, 7, 2, 10),
+            (2, 0, 2, 10),
+            (0, 11, 2, 10),
+            (20, 14, 25, 17),
+            (17, 20, 21, 22),
+            (16, 7, 15, 3),
+            (14, 3, 15, 3),
+            (20, 1, 15, 3),
+        ]
+        .into_iter()
+        .map(|(x, y, bx, by)| Diamond::through(Point2D::new(x, y), &Point2D::new(bx, by)))
+        .collect()
+    }
+
+    #[test]
+    fn test_diamond() {
+        let diamond = Diamond::new(Point2D::new(1, 2), 2);
+        assert_eq!(diamond.area(), 13);
+        assert_eq!(diamond.row(2), Some((-1, 3)));
+        assert_eq!(diamond.row(3), Some((0, 2)));
+        assert_eq!(diamond.row(5), None);
+        assert_eq!(diamond.column(3), Some((2, 2)));
+        assert!(diamond.contains(&Point2D::new(2, 3)));
+        assert!(!diamond.contains(&Point2D::new(3, 3)));
+    }
+
+    #[rstest]
+    #[case(Point2D::new(0, 0))]
+    #[case(Point2D::new(3, -5))]
+    #[case(Point2D::new(-2, -7))]
+    fn test_rotation(#[case] point: Point2D<i32>) {
+        let rotated = to_rotated(&point);
+        assert_eq!(from_rotated(&rotated), Some(point));
+        assert_eq!(from_rotated(&(rotated + Point::new([1, 0]))), None);
+        let diamond = Diamond::new(Point2D::new(1, -1), 3);
+        assert_eq!(
+            diamond.contains(&point),
+            diamond.rotated().contains(&rotated)
+        );
+    }
+
+    #[test]
+    fn test_row_coverage() {
+        let coverage = row_coverage(&sensors(), 10);
+        assert_eq!(coverage, vec![(-2, 24)]);
+        let split = [
+            Diamond::new(Point2D::new(0, 0), 1),
+            Diamond::new(Point2D::new(4, 0), 2),
+        ];
+        // touching intervals are joined
+        assert_eq!(row_coverage(&split, 0), vec![(-1, 6)]);
+        assert_eq!(row_coverage(&split, 1), vec![(0, 0), (3, 5)]);
+    }
+
+    #[test]
+    fn test_uncovered() {
+        let bounds = Rect::from_corners(Point::new([0, 0]), Point::new([20, 20]));
+        assert_eq!(uncovered(&sensors(), &bounds), vec![Point2D::new(14, 11)]);
+        // everything around a single diamond
+        let single = [Diamond::new(Point2D::new(0, 0), 1)];
+        let bounds = Rect::from_corners(Point::new([-5, -5]), Point::new([5, 5]));
+        let ring = uncovered(&single, &bounds);
+        assert_eq!(ring.len(), 8);
+        assert!(ring
+            .iter()
+            .all(|point| point.manhattan_distance(&Point2D::new(0, 0)) == 2));
+    }
+}
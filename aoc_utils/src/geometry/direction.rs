@@ -0,0 +1,378 @@
+use super::Point2D;
+use crate::error::PuzzleError;
+use num::{PrimInt, Signed};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A heading along one of the axes. North is up, which increases `y` for a
+/// [`Point2D`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+/// A heading along an axis or a diagonal. North is up, which increases `y`
+/// for a [`Point2D`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// Turns by a whole number of `step` degree turns, clockwise for positive
+/// `degrees`, given the index clockwise from north out of `count` headings.
+fn rotate_index(index: usize, count: usize, degrees: i32) -> Result<usize, PuzzleError> {
+    let step = 360 / count as i32;
+    if degrees % step != 0 {
+        return Err(PuzzleError::malformed(format!(
+            "Can only turn by multiples of {step} degrees, not {degrees}"
+        )));
+    }
+    let turns = (degrees / step).rem_euclid(count as i32) as usize;
+    Ok((index + turns) % count)
+}
+
+impl Direction4 {
+    /// Every heading, clockwise from north.
+    pub const ALL: [Self; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Turns clockwise, or anticlockwise for negative `degrees`, which must
+    /// be a multiple of 90.
+    pub fn rotate(self, degrees: i32) -> Result<Self, PuzzleError> {
+        Ok(Self::ALL[rotate_index(self as usize, 4, degrees)?])
+    }
+
+    /// The compass bearing, clockwise from north.
+    pub fn degrees(self) -> i32 {
+        self as i32 * 90
+    }
+
+    /// How many right turns it takes to face this way from `start`, e.g. for
+    /// scores which number headings clockwise from east.
+    pub fn clockwise_from(self, start: Self) -> u8 {
+        ((self as usize + 4 - start as usize) % 4) as u8
+    }
+
+    pub fn unit_vector<T: PrimInt + Signed>(self) -> Point2D<T> {
+        Direction8::from(self).unit_vector()
+    }
+
+    /// The neighbouring point in this direction, unless it's off the edge of
+    /// the integer type.
+    pub fn step<T: PrimInt>(self, point: &Point2D<T>) -> Option<Point2D<T>> {
+        Direction8::from(self).step(point)
+    }
+
+    /// The arrow used for this heading in puzzle inputs, as in `^>v<`.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction4::North => '^',
+            Direction4::East => '>',
+            Direction4::South => 'v',
+            Direction4::West => '<',
+        }
+    }
+}
+
+impl Direction8 {
+    /// Every heading, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 2) % 8]
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 6) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Turns clockwise, or anticlockwise for negative `degrees`, which must
+    /// be a multiple of 45.
+    pub fn rotate(self, degrees: i32) -> Result<Self, PuzzleError> {
+        Ok(Self::ALL[rotate_index(self as usize, 8, degrees)?])
+    }
+
+    /// The compass bearing, clockwise from north.
+    pub fn degrees(self) -> i32 {
+        self as i32 * 45
+    }
+
+    /// How many eighth turns clockwise it takes to face this way from `start`.
+    pub fn clockwise_from(self, start: Self) -> u8 {
+        ((self as usize + 8 - start as usize) % 8) as u8
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The step to take in this direction, with diagonals moving one along
+    /// each axis.
+    pub fn unit_vector<T: PrimInt + Signed>(self) -> Point2D<T> {
+        let (zero, one) = (T::zero(), T::one());
+        let (x, y) = match self {
+            Direction8::North => (zero, one),
+            Direction8::NorthEast => (one, one),
+            Direction8::East => (one, zero),
+            Direction8::SouthEast => (one, -one),
+            Direction8::South => (zero, -one),
+            Direction8::SouthWest => (-one, -one),
+            Direction8::West => (-one, zero),
+            Direction8::NorthWest => (-one, one),
+        };
+        Point2D::new(x, y)
+    }
+
+    /// The neighbouring point in this direction, unless it's off the edge of
+    /// the integer type.
+    pub fn step<T: PrimInt>(self, point: &Point2D<T>) -> Option<Point2D<T>> {
+        match self {
+            Direction8::North => point.up(),
+            Direction8::NorthEast => point.up_right(),
+            Direction8::East => point.right(),
+            Direction8::SouthEast => point.down_right(),
+            Direction8::South => point.down(),
+            Direction8::SouthWest => point.down_left(),
+            Direction8::West => point.left(),
+            Direction8::NorthWest => point.up_left(),
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+/// Only headings along an axis convert.
+impl TryFrom<Direction8> for Direction4 {
+    type Error = PuzzleError;
+
+    fn try_from(direction: Direction8) -> Result<Self, Self::Error> {
+        if direction.is_diagonal() {
+            Err(PuzzleError::unsupported(format!(
+                "{direction} isn't along an axis"
+            )))
+        } else {
+            Ok(Direction4::ALL[direction as usize / 2])
+        }
+    }
+}
+
+/// Parses any of the alphabets used for headings in puzzle inputs: compass
+/// points `NESW`, `UDLR` and arrows `^>v<`.
+impl TryFrom<char> for Direction4 {
+    type Error = PuzzleError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_uppercase() {
+            'N' | 'U' | '^' => Ok(Direction4::North),
+            'E' | 'R' | '>' => Ok(Direction4::East),
+            'S' | 'D' | 'V' => Ok(Direction4::South),
+            'W' | 'L' | '<' => Ok(Direction4::West),
+            _ => Err(PuzzleError::malformed(format!(
+                "Unexpected direction {c:?}"
+            ))),
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction4::try_from(c),
+            _ => Err(PuzzleError::malformed(format!(
+                "Unexpected direction {s:?}"
+            ))),
+        }
+    }
+}
+
+/// Parses compass points such as `N` or `SW`, or any heading along an axis
+/// which [`Direction4`] parses.
+impl FromStr for Direction8 {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "NE" => Ok(Direction8::NorthEast),
+            "SE" => Ok(Direction8::SouthEast),
+            "SW" => Ok(Direction8::SouthWest),
+            "NW" => Ok(Direction8::NorthWest),
+            _ => s.parse::<Direction4>().map(Direction8::from),
+        }
+    }
+}
+
+/// Shown as compass points, which parse back to the same heading.
+impl Display for Direction4 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Direction8::from(*self).fmt(f)
+    }
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Direction8::North => "N",
+            Direction8::NorthEast => "NE",
+            Direction8::East => "E",
+            Direction8::SouthEast => "SE",
+            Direction8::South => "S",
+            Direction8::SouthWest => "SW",
+            Direction8::West => "W",
+            Direction8::NorthWest => "NW",
+        };
+        write!(f, "{name}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction4::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().reverse(), direction);
+        }
+        assert_eq!(Direction4::North.turn_left(), Direction4::West);
+        assert_eq!(Direction8::NorthEast.turn_right(), Direction8::SouthEast);
+        assert_eq!(Direction8::NorthEast.reverse(), Direction8::SouthWest);
+        assert_eq!(Direction8::North.turn_left(), Direction8::West);
+    }
+
+    #[rstest]
+    #[case(Direction4::East, 90, Direction4::South)]
+    #[case(Direction4::East, 270, Direction4::North)]
+    #[case(Direction4::East, -90, Direction4::North)]
+    #[case(Direction4::West, 720, Direction4::West)]
+    #[case(Direction4::North, -450, Direction4::West)]
+    fn test_rotate(#[case] start: Direction4, #[case] degrees: i32, #[case] expected: Direction4) {
+        assert_eq!(start.rotate(degrees), Ok(expected));
+        assert_eq!(expected.rotate(-degrees), Ok(start));
+        let diagonal = Direction8::from(start).rotate(degrees + 45).unwrap();
+        assert_eq!(diagonal.rotate(-45).unwrap(), Direction8::from(expected));
+    }
+
+    #[test]
+    fn test_rotate_errors() {
+        assert!(Direction4::North.rotate(45).is_err());
+        assert!(Direction8::North.rotate(30).is_err());
+        assert_eq!(
+            Direction4::North.rotate(35).unwrap_err().to_string(),
+            "Malformed input: Can only turn by multiples of 90 degrees, not 35"
+        );
+    }
+
+    #[test]
+    fn test_scoring() {
+        // 2022 day 22 scores right as 0, down as 1, left as 2 and up as 3
+        let scores = Direction4::ALL.map(|d| d.clockwise_from(Direction4::East));
+        assert_eq!(scores, [3, 0, 1, 2]);
+        assert_eq!(Direction4::West.degrees(), 270);
+        assert_eq!(Direction8::SouthWest.degrees(), 225);
+        assert_eq!(Direction8::West.clockwise_from(Direction8::NorthEast), 5);
+    }
+
+    #[test]
+    fn test_unit_vectors() {
+        for direction in Direction8::ALL {
+            let vector = direction.unit_vector::<i32>();
+            let origin = Point2D::new(0, 0);
+            assert_eq!(direction.step(&origin), Some(vector));
+            assert_eq!(direction.reverse().unit_vector(), -vector);
+            assert_eq!(origin.chebyshev_distance(&vector), 1);
+            assert_eq!(
+                origin.manhattan_distance(&vector) == 2,
+                direction.is_diagonal()
+            );
+        }
+        assert_eq!(Direction4::North.unit_vector::<i8>(), Point2D::new(0, 1));
+        assert_eq!(Direction4::West.step(&Point2D::<u8>::new(0, 3)), None);
+    }
+
+    #[rstest]
+    #[case('N', Direction4::North)]
+    #[case('U', Direction4::North)]
+    #[case('^', Direction4::North)]
+    #[case('R', Direction4::East)]
+    #[case('>', Direction4::East)]
+    #[case('d', Direction4::South)]
+    #[case('v', Direction4::South)]
+    #[case('W', Direction4::West)]
+    #[case('<', Direction4::West)]
+    fn test_parse(#[case] c: char, #[case] expected: Direction4) {
+        assert_eq!(Direction4::try_from(c), Ok(expected));
+        assert_eq!(c.to_string().parse(), Ok(expected));
+        assert_eq!(c.to_string().parse(), Ok(Direction8::from(expected)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Direction4::try_from('x').is_err());
+        assert!("NE".parse::<Direction4>().is_err());
+        assert!("".parse::<Direction4>().is_err());
+        assert!("NNE".parse::<Direction8>().is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        for direction in Direction8::ALL {
+            assert_eq!(direction.to_string().parse(), Ok(direction));
+        }
+        assert_eq!(Direction4::South.to_string(), "S");
+        let arrows: String = Direction4::ALL.map(Direction4::arrow).iter().collect();
+        assert_eq!(arrows, "^>v<");
+        assert_eq!(Direction4::try_from(Direction8::East), Ok(Direction4::East));
+        assert!(Direction4::try_from(Direction8::SouthEast).is_err());
+    }
+}
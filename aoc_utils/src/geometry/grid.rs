@@ -0,0 +1,285 @@
+use super::{Direction8, GridBounds, Orientation, Point2D};
+use crate::error::PuzzleError;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+type Point = Point2D<usize>;
+
+/// A rectangular grid of cells stored a row at a time, indexed by points
+/// with `y` as the row, as for a text grid.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    bounds: GridBounds,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid from its cells, a row at a time.
+    pub fn new(bounds: GridBounds, cells: Vec<T>) -> Self {
+        assert_eq!(
+            bounds.len(),
+            cells.len(),
+            "Expected a cell for every point in the grid"
+        );
+        Self { bounds, cells }
+    }
+
+    /// A grid with every cell the same.
+    pub fn filled(bounds: GridBounds, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(bounds, vec![value; bounds.len()])
+    }
+
+    /// Parses a text grid, turning each character into a cell. Every line
+    /// has to be as long as the first.
+    pub fn parse(
+        text: &str,
+        mut cell: impl FnMut(char) -> Result<T, PuzzleError>,
+    ) -> Result<Self, PuzzleError> {
+        let bounds = GridBounds::of_text(text);
+        let mut cells = Vec::with_capacity(bounds.len());
+        for (row, line) in text.lines().enumerate() {
+            let start = cells.len();
+            for (column, c) in line.chars().enumerate() {
+                cells.push(cell(c).map_err(|e| e.on_line(row).at_column(column))?);
+            }
+            if cells.len() - start != bounds.width {
+                return Err(PuzzleError::malformed(format!(
+                    "Expected a row of {} cells, not {}",
+                    bounds.width,
+                    cells.len() - start
+                ))
+                .on_line(row));
+            }
+        }
+        Ok(Self { bounds, cells })
+    }
+
+    pub fn bounds(&self) -> GridBounds {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.height
+    }
+
+    /// The cell at the point, unless it's outside the grid.
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.bounds
+            .contains(point)
+            .then(|| &self.cells[self.bounds.index(point)])
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        if self.bounds.contains(point) {
+            Some(&mut self.cells[self.bounds.index(point)])
+        } else {
+            None
+        }
+    }
+
+    /// Every cell, a row at a time, e.g. to count the cells of a kind.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    /// Every cell with its point, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.bounds.points().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.bounds.width..(y + 1) * self.bounds.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // a grid with no columns still has its rows
+        (0..self.bounds.height).map(|y| self.row(y))
+    }
+
+    /// The cells in column `x`, from the top.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.bounds.width.max(1))
+            .take(self.bounds.height)
+    }
+
+    /// The points in a straight line from a point, not including it, up to
+    /// the edge of the grid. North is up the page, so heading south east
+    /// from the top left corner runs along the diagonal.
+    pub fn line(
+        &self,
+        start: &Point,
+        direction: impl Into<Direction8>,
+    ) -> impl Iterator<Item = Point> + '_ {
+        let direction = direction.into();
+        std::iter::successors(Some(*start), move |point| {
+            Orientation::Screen.step(point, direction)
+        })
+        .skip(1)
+        .take_while(|point| self.bounds.contains(point))
+    }
+
+    /// The points in the grid sharing an edge with this one.
+    pub fn adjacent_neighbours(&self, point: &Point) -> impl Iterator<Item = Point> + '_ {
+        self.bounds.adjacent_neighbours(point)
+    }
+
+    /// The points in the grid sharing an edge or a corner with this one.
+    pub fn neighbours(&self, point: &Point) -> impl Iterator<Item = Point> + '_ {
+        self.bounds.neighbours(point)
+    }
+
+    /// The first point with the value, a row at a time.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|index| self.bounds.point(index))
+    }
+
+    /// Every point with the value, a row at a time.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(point, _)| point)
+    }
+
+    /// A grid of the same size with each cell converted.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            bounds: self.bounds,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        assert!(self.bounds.contains(&point), "{point} is outside the grid");
+        &self.cells[self.bounds.index(&point)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        assert!(self.bounds.contains(&point), "{point} is outside the grid");
+        let index = self.bounds.index(&point);
+        &mut self.cells[index]
+    }
+}
+
+/// Shows each cell in turn with a line per row, the inverse of parsing a
+/// grid of characters.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction4;
+    use rstest::*;
+
+    const TREES: &str = "30373\n25512\n65332\n33549\n35390";
+
+    fn trees() -> Grid<u32> {
+        Grid::parse(TREES, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| PuzzleError::malformed("Expected a digit"))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = trees();
+        assert_eq!((grid.width(), grid.height()), (5, 5));
+        assert_eq!(grid[Point::new(3, 0)], 7);
+        assert_eq!(grid[Point::new(0, 2)], 6);
+        assert_eq!(grid.get(&Point::new(5, 0)), None);
+        assert_eq!(grid.to_string(), TREES);
+        let chars: Grid<char> = Grid::parse("ab\ncd\n", Ok).unwrap();
+        assert_eq!(chars.find(&'c'), Some(Point::new(0, 1)));
+    }
+
+    #[rstest]
+    #[case("12\n3", 1, None)]
+    #[case("12\n3x", 1, Some(1))]
+    fn test_parse_errors(#[case] text: &str, #[case] line: usize, #[case] column: Option<usize>) {
+        let error = Grid::parse(text, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| PuzzleError::malformed("Expected a digit"))
+        })
+        .unwrap_err();
+        let position = error.position().unwrap();
+        assert_eq!((position.line, position.column), (line, column));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = trees();
+        assert_eq!(grid.row(1), &[2, 5, 5, 1, 2]);
+        assert_eq!(grid.rows().count(), 5);
+        assert_eq!(
+            grid.column(2).copied().collect::<Vec<_>>(),
+            vec![3, 5, 3, 5, 3]
+        );
+        assert_eq!(grid.cells().len(), 25);
+    }
+
+    #[rstest]
+    #[case(Point::new(0, 0), Direction8::SouthEast, vec![5, 3, 4, 0])]
+    #[case(Point::new(2, 2), Direction8::North, vec![5, 3])]
+    #[case(Point::new(2, 2), Direction8::NorthEast, vec![1, 3])]
+    #[case(Point::new(4, 4), Direction8::East, vec![])]
+    fn test_line(#[case] start: Point, #[case] direction: Direction8, #[case] expected: Vec<u32>) {
+        let grid = trees();
+        let cells: Vec<_> = grid
+            .line(&start, direction)
+            .map(|point| grid[point])
+            .collect();
+        assert_eq!(cells, expected);
+        assert_eq!(grid.line(&start, Direction4::West).count(), start.x);
+    }
+
+    #[test]
+    fn test_neighbours_and_find() {
+        let mut grid = trees();
+        assert_eq!(grid.neighbours(&Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.adjacent_neighbours(&Point::new(2, 2)).count(), 4);
+        assert_eq!(grid.find(&9), Some(Point::new(4, 3)));
+        assert_eq!(grid.find_all(&5).count(), 5);
+        grid[Point::new(4, 3)] = 0;
+        *grid.get_mut(&Point::new(0, 0)).unwrap() = 9;
+        assert_eq!(grid.find(&9), Some(Point::new(0, 0)));
+        let tall = grid.map(|&height| height >= 5);
+        assert_eq!(tall.cells().iter().filter(|&&tall| tall).count(), 9);
+    }
+}
@@ -0,0 +1,126 @@
+use super::Point2D;
+use num::PrimInt;
+
+/// The extent of a grid with `width` columns and `height` rows, with points
+/// from the origin up to but not including those extents. Neighbours are
+/// only ever inside the grid, so there's no need to check either edge or
+/// cast to a signed type to step off the top or left.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GridBounds<T = usize> {
+    pub width: T,
+    pub height: T,
+}
+
+impl GridBounds<usize> {
+    /// The bounds of a text grid, as wide as its first line.
+    pub fn of_text(text: &str) -> Self {
+        Self {
+            width: text.lines().next().map_or(0, |line| line.chars().count()),
+            height: text.lines().count(),
+        }
+    }
+
+    /// The index of the point in a row-major `Vec` of the grid's cells.
+    pub fn index(&self, point: &Point2D<usize>) -> usize {
+        point.y * self.width + point.x
+    }
+
+    /// The point for an index in a row-major `Vec` of the grid's cells.
+    pub fn point(&self, index: usize) -> Point2D<usize> {
+        Point2D::new(index % self.width, index / self.width)
+    }
+
+    /// The number of cells in the grid.
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: PrimInt> GridBounds<T> {
+    pub fn new(width: T, height: T) -> Self {
+        Self { width, height }
+    }
+
+    pub fn contains(&self, point: &Point2D<T>) -> bool {
+        let zero = T::zero();
+        (zero..self.width).contains(&point.x) && (zero..self.height).contains(&point.y)
+    }
+
+    /// The points in the grid sharing an edge with this one, in the order
+    /// given by [`Point2D::adjacent_neighbours`].
+    pub fn adjacent_neighbours(&self, point: &Point2D<T>) -> impl Iterator<Item = Point2D<T>> + '_ {
+        point
+            .adjacent_neighbours()
+            .into_iter()
+            .filter(|neighbour| self.contains(neighbour))
+    }
+
+    /// The points in the grid sharing an edge or a corner with this one, in
+    /// the order given by [`Point2D::neighbours`].
+    pub fn neighbours(&self, point: &Point2D<T>) -> impl Iterator<Item = Point2D<T>> + '_ {
+        point
+            .neighbours()
+            .into_iter()
+            .filter(|neighbour| self.contains(neighbour))
+    }
+
+    /// Every point in the grid, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point2D<T>> + '_ {
+        num::range(T::zero(), self.height)
+            .flat_map(move |y| num::range(T::zero(), self.width).map(move |x| Point2D::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(Point2D::new(0, 0), 2, 3)]
+    #[case(Point2D::new(1, 0), 3, 5)]
+    #[case(Point2D::new(3, 2), 2, 3)]
+    #[case(Point2D::new(1, 1), 4, 8)]
+    #[case(Point2D::new(3, 1), 3, 5)]
+    fn test_neighbours(#[case] point: Point2D<usize>, #[case] adjacent: usize, #[case] all: usize) {
+        let bounds = GridBounds::new(4, 3);
+        assert_eq!(bounds.adjacent_neighbours(&point).count(), adjacent);
+        assert_eq!(bounds.neighbours(&point).count(), all);
+        assert!(bounds
+            .neighbours(&point)
+            .all(|neighbour| bounds.contains(&neighbour)));
+    }
+
+    #[test]
+    fn test_signed() {
+        let bounds = GridBounds::new(2i32, 2);
+        assert!(!bounds.contains(&Point2D::new(-1, 0)));
+        assert_eq!(bounds.neighbours(&Point2D::new(0, 0)).count(), 3);
+        let neighbours: Vec<_> = bounds.neighbours(&Point2D::new(-1, -1)).collect();
+        assert_eq!(neighbours, vec![Point2D::new(0, 0)]);
+    }
+
+    #[test]
+    fn test_of_text() {
+        let bounds = GridBounds::of_text("..#.\n#...\n....\n");
+        assert_eq!(bounds, GridBounds::new(4, 3));
+        assert_eq!(bounds.len(), 12);
+        assert!(GridBounds::of_text("").is_empty());
+    }
+
+    #[test]
+    fn test_indexes() {
+        let bounds = GridBounds::new(4, 3);
+        let points: Vec<_> = bounds.points().collect();
+        assert_eq!(points.len(), bounds.len());
+        for (index, point) in points.iter().enumerate() {
+            assert_eq!(bounds.index(point), index);
+            assert_eq!(bounds.point(index), *point);
+        }
+        assert_eq!(points[5], Point2D::new(1, 1));
+    }
+}
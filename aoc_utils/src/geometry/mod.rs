@@ -0,0 +1,37 @@
+mod bit_grid;
+mod bounding_box;
+mod diamond;
+mod direction;
+mod grid;
+mod grid_bounds;
+mod orientation;
+mod point;
+mod point_2d;
+mod polygon;
+mod segment;
+mod sight;
+mod sparse_grid;
+
+pub use bit_grid::{BitGrid, NeighbourCounts};
+pub use bounding_box::{BoundingBox, Cuboid, Rect};
+pub use diamond::{from_rotated, row_coverage, to_rotated, uncovered, Diamond};
+pub use direction::{Direction4, Direction8};
+pub use grid::Grid;
+pub use grid_bounds::GridBounds;
+pub use orientation::Orientation;
+pub use point::{Point, Point3D, Point4D};
+pub use point_2d::Point2D;
+pub use polygon::{CompressedPolygon, Polygon};
+pub use segment::{Crossing, Intersection, Polyline, Segment};
+pub use sight::{first_visible, viewing_distances, visible_from_edge, SightLines};
+pub use sparse_grid::SparseGrid;
+
+use num::PrimInt;
+
+fn abs_diff<T: PrimInt>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
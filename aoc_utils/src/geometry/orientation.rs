@@ -0,0 +1,156 @@
+use super::{Direction8, Point2D};
+use crate::error::PuzzleError;
+use num::{PrimInt, Signed};
+
+/// Which way `y` grows, so that north means up the page either way.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    /// `y` grows upwards as on a graph, which is what [`Point2D::up`] assumes.
+    Cartesian,
+    /// `y` grows downwards with each line of a text grid, as for the row
+    /// index in row-major puzzles.
+    Screen,
+}
+
+impl Orientation {
+    /// The heading to step in for a point using Cartesian coordinates.
+    fn cartesian(self, direction: impl Into<Direction8>) -> Direction8 {
+        let direction = direction.into();
+        match self {
+            Orientation::Cartesian => direction,
+            // mirror north and south
+            Orientation::Screen => Direction8::ALL[(12 - direction as usize) % 8],
+        }
+    }
+
+    pub fn unit_vector<T: PrimInt + Signed>(self, direction: impl Into<Direction8>) -> Point2D<T> {
+        self.cartesian(direction).unit_vector()
+    }
+
+    /// The neighbouring point in this direction, unless it's off the edge of
+    /// the integer type, e.g. north of the first row of a text grid.
+    pub fn step<T: PrimInt>(
+        self,
+        point: &Point2D<T>,
+        direction: impl Into<Direction8>,
+    ) -> Option<Point2D<T>> {
+        self.cartesian(direction).step(point)
+    }
+
+    /// Each character of a text grid with its point, with the first character
+    /// of the bottom line at the origin for Cartesian coordinates, or the top
+    /// line for screen ones.
+    pub fn parse_grid<T: PrimInt>(
+        self,
+        text: &str,
+    ) -> Result<Vec<(Point2D<T>, char)>, PuzzleError> {
+        let height = text.lines().count();
+        let mut cells = Vec::new();
+        for (row, line) in text.lines().enumerate() {
+            let y = match self {
+                Orientation::Cartesian => height - 1 - row,
+                Orientation::Screen => row,
+            };
+            for (x, c) in line.chars().enumerate() {
+                let (Some(x), Some(y)) = (T::from(x), T::from(y)) else {
+                    return Err(PuzzleError::unsupported(
+                        "The grid is too big for the type of coordinate",
+                    ));
+                };
+                cells.push((Point2D::new(x, y), c));
+            }
+        }
+        Ok(cells)
+    }
+
+    /// Draws a `width` by `height` grid from the origin, the inverse of
+    /// [`Orientation::parse_grid`] for rectangular grids.
+    pub fn render<T: PrimInt>(
+        self,
+        width: usize,
+        height: usize,
+        mut cell: impl FnMut(Point2D<T>) -> char,
+    ) -> String {
+        let mut lines = Vec::with_capacity(height);
+        for row in 0..height {
+            let y = match self {
+                Orientation::Cartesian => height - 1 - row,
+                Orientation::Screen => row,
+            };
+            let line: String = (0..width)
+                .map(|x| cell(Point2D::new(T::from(x).unwrap(), T::from(y).unwrap())))
+                .collect();
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction4;
+    use rstest::*;
+    use std::collections::HashMap;
+
+    const GRID: &str = "#..\n.#.\n..v";
+
+    #[rstest]
+    #[case(Orientation::Cartesian, Point2D::new(0, 2), Point2D::new(2, 0))]
+    #[case(Orientation::Screen, Point2D::new(0, 0), Point2D::new(2, 2))]
+    fn test_parse_grid(
+        #[case] orientation: Orientation,
+        #[case] top_left: Point2D<u8>,
+        #[case] bottom_right: Point2D<u8>,
+    ) {
+        let cells: HashMap<_, _> = orientation.parse_grid(GRID).unwrap().into_iter().collect();
+        assert_eq!(cells.len(), 9);
+        assert_eq!(cells[&top_left], '#');
+        assert_eq!(cells[&bottom_right], 'v');
+        // the arrow points south, down the page
+        let below = orientation.step(&top_left, Direction4::South).unwrap();
+        assert_eq!(cells[&below], '.');
+        let diagonal = orientation.step(&top_left, Direction8::SouthEast).unwrap();
+        assert_eq!(cells[&diagonal], '#');
+        // off the top of the grid, or off the edge of the type
+        let above = orientation.step(&top_left, Direction4::North);
+        assert!(!above.is_some_and(|point| cells.contains_key(&point)));
+    }
+
+    #[rstest]
+    #[case(Orientation::Cartesian)]
+    #[case(Orientation::Screen)]
+    fn test_round_trip(#[case] orientation: Orientation) {
+        let cells: HashMap<Point2D<i32>, char> =
+            orientation.parse_grid(GRID).unwrap().into_iter().collect();
+        assert_eq!(orientation.render(3, 3, |point| cells[&point]), GRID);
+    }
+
+    #[test]
+    fn test_unit_vectors() {
+        assert_eq!(
+            Orientation::Cartesian.unit_vector::<i8>(Direction4::North),
+            Point2D::new(0, 1)
+        );
+        assert_eq!(
+            Orientation::Screen.unit_vector::<i8>(Direction4::North),
+            Point2D::new(0, -1)
+        );
+        assert_eq!(
+            Orientation::Screen.unit_vector::<i8>(Direction8::SouthWest),
+            Point2D::new(-1, 1)
+        );
+        for direction in Direction8::ALL {
+            let cartesian = Orientation::Cartesian.unit_vector::<i8>(direction);
+            let screen = Orientation::Screen.unit_vector::<i8>(direction);
+            assert_eq!((cartesian.x, -cartesian.y), (screen.x, screen.y));
+        }
+    }
+
+    #[test]
+    fn test_too_big() {
+        let wide = ".".repeat(300);
+        assert!(Orientation::Screen.parse_grid::<u8>(&wide).is_err());
+        assert!(Orientation::Screen.parse_grid::<u16>(&wide).is_ok());
+    }
+}
@@ -0,0 +1,296 @@
+use super::abs_diff;
+use crate::error::PuzzleError;
+use num::{PrimInt, Signed};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A point on an integer grid with any number of dimensions, indexed by axis.
+/// Points are ordered by each coordinate in turn.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T, const N: usize>(pub [T; N]);
+
+pub type Point3D<T> = Point<T, 3>;
+pub type Point4D<T> = Point<T, 4>;
+
+impl<T: PrimInt, const N: usize> Default for Point<T, N> {
+    fn default() -> Self {
+        Self([T::zero(); N])
+    }
+}
+
+impl<T: Display, const N: usize> Display for Point<T, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (axis, coord) in self.0.iter().enumerate() {
+            if axis > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{coord}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Moving off the edge of the integer type, e.g. below zero for unsigned
+/// coordinates, leaves those points out of the neighbours.
+impl<T: PrimInt, const N: usize> Point<T, N> {
+    pub fn new(coords: [T; N]) -> Self {
+        Self(coords)
+    }
+
+    /// The points sharing a face with this one, i.e. a step back and then
+    /// forward along each axis in turn: 6 of them in 3 dimensions.
+    pub fn face_neighbours(&self) -> Vec<Self> {
+        let mut neighbours = Vec::with_capacity(2 * N);
+        for axis in 0..N {
+            for step in [T::checked_sub, T::checked_add] {
+                if let Some(coord) = step(&self.0[axis], &T::one()) {
+                    let mut neighbour = *self;
+                    neighbour.0[axis] = coord;
+                    neighbours.push(neighbour);
+                }
+            }
+        }
+        neighbours
+    }
+
+    /// The points sharing a face, edge or corner with this one, in order:
+    /// 26 of them in 3 dimensions and 80 in 4.
+    pub fn neighbours(&self) -> Vec<Self> {
+        let mut points = vec![*self];
+        for axis in 0..N {
+            points = points
+                .into_iter()
+                .flat_map(|point| {
+                    let coord = point.0[axis];
+                    [
+                        coord.checked_sub(&T::one()),
+                        Some(coord),
+                        coord.checked_add(&T::one()),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .map(move |coord| {
+                        let mut neighbour = point;
+                        neighbour.0[axis] = coord;
+                        neighbour
+                    })
+                })
+                .collect();
+        }
+        points.retain(|point| point != self);
+        points
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        self.axis_distances(other)
+            .fold(T::zero(), |total, distance| total + distance)
+    }
+
+    /// Distance when diagonal moves cost the same as straight ones, like a king in chess.
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        self.axis_distances(other).fold(T::zero(), T::max)
+    }
+
+    /// The square of the straight line distance, which is still an integer.
+    pub fn euclidean_distance_squared(&self, other: &Self) -> T {
+        self.axis_distances(other)
+            .fold(T::zero(), |total, distance| total + distance * distance)
+    }
+
+    fn axis_distances<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = T> + 'a {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .map(|(&a, &b)| abs_diff(a, b))
+    }
+
+    pub(super) fn zip_with(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        let mut coords = self.0;
+        for (coord, &other) in coords.iter_mut().zip(other.0.iter()) {
+            *coord = f(*coord, other);
+        }
+        Self(coords)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Point<T, N> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        &self.0[axis]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for Point<T, N> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        &mut self.0[axis]
+    }
+}
+
+impl<T: PrimInt, const N: usize> Add for Point<T, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+impl<T: PrimInt, const N: usize> AddAssign for Point<T, N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: PrimInt, const N: usize> Sub for Point<T, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+impl<T: PrimInt, const N: usize> SubAssign for Point<T, N> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: PrimInt + Signed, const N: usize> Neg for Point<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|coord| -coord))
+    }
+}
+
+/// Scales every coordinate, e.g. to take several steps in a direction at once.
+impl<T: PrimInt, const N: usize> Mul<T> for Point<T, N> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Self(self.0.map(|coord| coord * scale))
+    }
+}
+
+/// Parses comma separated coordinates such as `"x,y,z"`, as used in puzzle inputs.
+impl<T: PrimInt + FromStr, const N: usize> FromStr for Point<T, N> {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || PuzzleError::malformed(format!("Expected a point with {N} coordinates, not {s:?}"));
+        let mut coords = [T::zero(); N];
+        let mut fields = s.split(',');
+        for coord in coords.iter_mut() {
+            *coord = fields
+                .next()
+                .and_then(|field| field.trim().parse().ok())
+                .ok_or_else(invalid)?;
+        }
+        match fields.next() {
+            Some(_) => Err(invalid()),
+            None => Ok(Self(coords)),
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Point<T, N> {
+    fn from(coords: [T; N]) -> Self {
+        Self(coords)
+    }
+}
+
+impl<T, const N: usize> From<Point<T, N>> for [T; N] {
+    fn from(point: Point<T, N>) -> Self {
+        point.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Point3D::<i32>::default(), Point::new([0, 0, 0]));
+        assert_eq!(Point4D::<u8>::default(), Point::new([0, 0, 0, 0]));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Point::new([1, -2, 3]).to_string(), "(1, -2, 3)");
+        assert_eq!(Point::new([1, 2, 3, 4]).to_string(), "(1, 2, 3, 4)");
+    }
+
+    #[test]
+    fn test_face_neighbours() {
+        let point = Point3D::<i8>::new([1, 2, 3]);
+        assert_eq!(
+            point.face_neighbours(),
+            vec![
+                Point::new([0, 2, 3]),
+                Point::new([2, 2, 3]),
+                Point::new([1, 1, 3]),
+                Point::new([1, 3, 3]),
+                Point::new([1, 2, 2]),
+                Point::new([1, 2, 4]),
+            ]
+        );
+        assert_eq!(Point4D::<i8>::default().face_neighbours().len(), 8);
+    }
+
+    #[rstest]
+    #[case(Point::new([5, 5]), 8)]
+    #[case(Point::new([0, 5]), 5)]
+    #[case(Point::new([0, 0]), 3)]
+    fn test_neighbours_at_edge_of_type(#[case] point: Point<u8, 2>, #[case] expected: usize) {
+        assert_eq!(point.neighbours().len(), expected);
+        assert!(!point.neighbours().contains(&point));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point3D::<i32>::new([0, 0, 0]);
+        let neighbours = point.neighbours();
+        assert_eq!(neighbours.len(), 26);
+        assert_eq!(neighbours[0], Point::new([-1, -1, -1]));
+        assert_eq!(neighbours[25], Point::new([1, 1, 1]));
+        assert!(neighbours.iter().all(|n| point.chebyshev_distance(n) == 1));
+        assert_eq!(Point4D::<i32>::default().neighbours().len(), 80);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point3D::<u32>::new([1, 5, 2]);
+        let b = Point3D::<u32>::new([4, 1, 2]);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.euclidean_distance_squared(&b), 25);
+        assert_eq!(b.manhattan_distance(&a), 7);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point3D::<i64>::new([1, 2, 3]);
+        point += Point::new([1, 1, 1]);
+        assert_eq!(point, Point::new([2, 3, 4]));
+        point -= Point::new([2, 0, 0]);
+        assert_eq!(point * 2, Point::new([0, 6, 8]));
+        assert_eq!(-point, Point::new([0, -3, -4]));
+        point[1] = 7;
+        assert_eq!(point[1], 7);
+    }
+
+    #[rstest]
+    #[case("1,2,3", Some([1, 2, 3]))]
+    #[case(" -1, 0,3", Some([-1, 0, 3]))]
+    #[case("1,2", None)]
+    #[case("1,2,3,4", None)]
+    #[case("1,x,3", None)]
+    fn test_parse(#[case] input: &str, #[case] expected: Option<[i16; 3]>) {
+        assert_eq!(input.parse::<Point3D<i16>>().ok(), expected.map(Point::new));
+    }
+}
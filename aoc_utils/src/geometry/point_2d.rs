@@ -0,0 +1,394 @@
+use super::abs_diff;
+#[cfg(doc)]
+use super::Orientation;
+use super::Point;
+use crate::error::PuzzleError;
+use num::{Complex, PrimInt, Signed};
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A point on an integer grid, with `up` increasing `y` as for
+/// [`Orientation::Cartesian`]. Use [`Orientation::Screen`] to step around a
+/// text grid where `y` is the row. Points are ordered by `x` and then `y`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point2D<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Display> Display for Point2D<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Moving off the edge of the integer type, e.g. below zero for unsigned
+/// coordinates, gives `None`.
+impl<T: PrimInt> Point2D<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn up(&self) -> Option<Self> {
+        self.y.checked_add(&T::one()).map(|y| Self { x: self.x, y })
+    }
+
+    pub fn down(&self) -> Option<Self> {
+        self.y.checked_sub(&T::one()).map(|y| Self { x: self.x, y })
+    }
+
+    pub fn right(&self) -> Option<Self> {
+        self.x.checked_add(&T::one()).map(|x| Self { x, y: self.y })
+    }
+
+    pub fn left(&self) -> Option<Self> {
+        self.x.checked_sub(&T::one()).map(|x| Self { x, y: self.y })
+    }
+
+    pub fn up_right(&self) -> Option<Self> {
+        self.up()?.right()
+    }
+
+    pub fn up_left(&self) -> Option<Self> {
+        self.up()?.left()
+    }
+
+    pub fn down_right(&self) -> Option<Self> {
+        self.down()?.right()
+    }
+
+    pub fn down_left(&self) -> Option<Self> {
+        self.down()?.left()
+    }
+
+    /// The points sharing an edge with this one, clockwise from up.
+    pub fn adjacent_neighbours(&self) -> Vec<Self> {
+        vec![self.up(), self.right(), self.down(), self.left()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+    }
+
+    /// The points sharing an edge or a corner with this one, clockwise from up.
+    pub fn neighbours(&self) -> Vec<Self> {
+        vec![
+            self.up(),
+            self.up_right(),
+            self.right(),
+            self.down_right(),
+            self.down(),
+            self.down_left(),
+            self.left(),
+            self.up_left(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Distance when diagonal moves cost the same as straight ones, like a king in chess.
+    pub fn chebyshev_distance(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: PrimInt> Add for Point2D<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: PrimInt> AddAssign for Point2D<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: PrimInt> Sub for Point2D<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: PrimInt> SubAssign for Point2D<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: PrimInt + Signed> Neg for Point2D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+/// Scales both coordinates, e.g. to take several steps in a direction at once.
+impl<T: PrimInt> Mul<T> for Point2D<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Self::new(self.x * scale, self.y * scale)
+    }
+}
+
+/// Parses `"x,y"`, as used for coordinates in puzzle inputs.
+impl<T: FromStr> FromStr for Point2D<T> {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || PuzzleError::malformed(format!("Expected a point x,y, not {s:?}"));
+        let (x, y) = s.split_once(',').ok_or_else(invalid)?;
+        match (x.trim().parse(), y.trim().parse()) {
+            (Ok(x), Ok(y)) => Ok(Self { x, y }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Point2D<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2D<T>> for (T, T) {
+    fn from(point: Point2D<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> From<Point<T, 2>> for Point2D<T> {
+    fn from(Point([x, y]): Point<T, 2>) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point2D<T>> for Point<T, 2> {
+    fn from(point: Point2D<T>) -> Self {
+        Point([point.x, point.y])
+    }
+}
+
+/// The real part is `x` and the imaginary part is `y`.
+impl<T> From<Complex<T>> for Point2D<T> {
+    fn from(complex: Complex<T>) -> Self {
+        Self {
+            x: complex.re,
+            y: complex.im,
+        }
+    }
+}
+
+impl<T> From<Point2D<T>> for Complex<T> {
+    fn from(point: Point2D<T>) -> Self {
+        Complex::new(point.x, point.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creation() {
+        let point_usize = Point2D::<usize>::new(1, 2);
+        assert_eq!(point_usize.x, 1usize);
+        assert_eq!(point_usize.y, 2usize);
+
+        let point_u8 = Point2D::<u8>::new(3, 4);
+        assert_eq!(point_u8.x, 3u8);
+        assert_eq!(point_u8.y, 4u8);
+
+        let point_i8 = Point2D::<i8>::new(-1, -5);
+        assert_eq!(point_i8.x, -1i8);
+        assert_eq!(point_i8.y, -5i8);
+    }
+
+    #[test]
+    fn test_defaults() {
+        assert_eq!(Point2D::<usize>::default(), Point2D::<usize>::new(0, 0));
+        assert_eq!(Point2D::<i128>::default(), Point2D::<i128>::new(0, 0));
+        assert_eq!(Point2D::<u32>::default(), Point2D::<u32>::new(0, 0));
+    }
+
+    #[test]
+    fn test_display() {
+        let point = Point2D::<usize>::new(1, 2);
+        assert_eq!(format!("{point}"), "(1, 2)");
+
+        let other_point = Point2D::<i8>::new(-4, -3);
+        assert_eq!(format!("{other_point}"), "(-4, -3)");
+    }
+
+    #[test]
+    fn test_up() {
+        let point = Point2D::<usize>::default();
+        assert_eq!(point.up().unwrap(), Point2D::<usize>::new(0, 1));
+        assert_eq!(
+            point.up().unwrap().up().unwrap(),
+            Point2D::<usize>::new(0, 2)
+        );
+    }
+
+    #[test]
+    fn test_down() {
+        let point = Point2D::<usize>::new(1, 1);
+        assert_eq!(point.down().unwrap(), Point2D::<usize>::new(1, 0));
+    }
+
+    #[test]
+    fn test_down_on_non_negative_int_type() {
+        let point = Point2D::<usize>::default();
+        assert_eq!(point.down(), None);
+    }
+
+    #[test]
+    fn test_left() {
+        let point = Point2D::<usize>::new(1, 1);
+        assert_eq!(point.left().unwrap(), Point2D::<usize>::new(0, 1));
+    }
+
+    #[test]
+    fn test_left_on_non_negative_int_type() {
+        let point = Point2D::<u16>::new(0, 5);
+        assert_eq!(point.left(), None);
+    }
+
+    #[test]
+    fn test_right() {
+        let point = Point2D::<usize>::new(1, 1);
+        assert_eq!(point.right().unwrap(), Point2D::<usize>::new(2, 1));
+    }
+
+    #[test]
+    fn test_adjacent_neighbours() {
+        let point = Point2D::<i8>::default();
+        let expected = vec![
+            Point2D::<i8>::new(0, 1),
+            Point2D::<i8>::new(1, 0),
+            Point2D::<i8>::new(0, -1),
+            Point2D::<i8>::new(-1, 0),
+        ];
+        assert_eq!(point.adjacent_neighbours(), expected);
+    }
+
+    #[test]
+    fn test_adjacent_neighbours_non_negative_type_origin() {
+        let point = Point2D::<u8>::default();
+        let expected = vec![Point2D::<u8>::new(0, 1), Point2D::<u8>::new(1, 0)];
+        assert_eq!(point.adjacent_neighbours(), expected);
+    }
+
+    #[test]
+    fn test_adjacent_neighbours_non_negative_type_x_axis() {
+        let point = Point2D::<u8>::new(7, 0);
+        let expected = vec![
+            Point2D::<u8>::new(7, 1),
+            Point2D::<u8>::new(8, 0),
+            Point2D::<u8>::new(6, 0),
+        ];
+        assert_eq!(point.adjacent_neighbours(), expected);
+    }
+
+    #[test]
+    fn test_adjacent_neighbours_non_negative_type_y_axis() {
+        let point = Point2D::<u32>::new(0, 3);
+        let expected = vec![
+            Point2D::<u32>::new(0, 4),
+            Point2D::<u32>::new(1, 3),
+            Point2D::<u32>::new(0, 2),
+        ];
+        assert_eq!(point.adjacent_neighbours(), expected);
+    }
+
+    #[test]
+    fn test_up_and_right_at_the_edge_of_the_type() {
+        let point = Point2D::<u8>::new(255, 255);
+        assert_eq!(point.up(), None);
+        assert_eq!(point.right(), None);
+        assert_eq!(
+            point.neighbours(),
+            vec![
+                Point2D::new(255, 254),
+                Point2D::new(254, 254),
+                Point2D::new(254, 255),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let point = Point2D::<i32>::new(1, 1);
+        assert_eq!(point.neighbours().len(), 8);
+        assert!(point
+            .neighbours()
+            .iter()
+            .all(|n| point.chebyshev_distance(n) == 1));
+        assert_eq!(Point2D::<u32>::default().neighbours().len(), 3);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let mut point = Point2D::new(1, -2);
+        assert_eq!(point + Point2D::new(3, 4), Point2D::new(4, 2));
+        assert_eq!(point - Point2D::new(3, 4), Point2D::new(-2, -6));
+        assert_eq!(-point, Point2D::new(-1, 2));
+        assert_eq!(point * 3, Point2D::new(3, -6));
+        point += Point2D::new(1, 1);
+        assert_eq!(point, Point2D::new(2, -1));
+        point -= Point2D::new(2, 2);
+        assert_eq!(point, Point2D::new(0, -3));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2D::<i64>::new(1, -2);
+        let b = Point2D::<i64>::new(-3, 5);
+        assert_eq!(a.manhattan_distance(&b), 11);
+        assert_eq!(a.chebyshev_distance(&b), 7);
+        let c = Point2D::<usize>::new(3, 0);
+        let d = Point2D::<usize>::new(1, 4);
+        assert_eq!(c.manhattan_distance(&d), 6);
+        assert_eq!(d.manhattan_distance(&c), 6);
+        assert_eq!(c.chebyshev_distance(&d), 4);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut points = vec![Point2D::new(1, 0), Point2D::new(0, 5), Point2D::new(0, -1)];
+        points.sort();
+        assert_eq!(
+            points,
+            vec![Point2D::new(0, -1), Point2D::new(0, 5), Point2D::new(1, 0)]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!("498,4".parse(), Ok(Point2D::<u32>::new(498, 4)));
+        assert_eq!(" -1, 2".parse(), Ok(Point2D::<i8>::new(-1, 2)));
+        assert!("498".parse::<Point2D<u32>>().is_err());
+        assert!("-1,2".parse::<Point2D<u32>>().is_err());
+        assert!("1,2,3".parse::<Point2D<u32>>().is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Point2D::from((1, 2)), Point2D::new(1, 2));
+        assert_eq!(<(i32, i32)>::from(Point2D::new(1, 2)), (1, 2));
+        let complex = Complex::new(3_i64, -4);
+        assert_eq!(Point2D::from(complex), Point2D::new(3, -4));
+        assert_eq!(Complex::from(Point2D::new(3_i64, -4)), complex);
+    }
+}
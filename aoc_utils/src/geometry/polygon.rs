@@ -0,0 +1,262 @@
+use super::{Point, Rect};
+use crate::error::PuzzleError;
+use num::Integer;
+
+/// A closed polygon on the integer grid, with an edge from each vertex to the
+/// next and from the last back to the first. Vertices can go either way round.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Point<i64, 2>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point<i64, 2>>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Point<i64, 2>] {
+        &self.vertices
+    }
+
+    /// Each edge as its start and end vertex.
+    pub fn edges(&self) -> impl Iterator<Item = (Point<i64, 2>, Point<i64, 2>)> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Whether every edge is horizontal or vertical.
+    pub fn is_rectilinear(&self) -> bool {
+        self.edges().all(|(a, b)| a[0] == b[0] || a[1] == b[1])
+    }
+
+    /// Twice the area enclosed by the edges, which is always an integer, from
+    /// the shoelace formula.
+    pub fn double_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a[0] * b[1] - b[0] * a[1])
+            .sum::<i64>()
+            .abs()
+    }
+
+    /// The number of grid points on the edges.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| (b[0] - a[0]).gcd(&(b[1] - a[1])))
+            .sum()
+    }
+
+    /// The number of grid points strictly inside, from Pick's theorem.
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// Whether the point is inside the polygon or on one of its edges.
+    pub fn contains(&self, point: &Point<i64, 2>) -> bool {
+        let (x, y) = (point[0], point[1]);
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            let cross = (b[0] - a[0]) * (y - a[1]) - (b[1] - a[1]) * (x - a[0]);
+            let between = |axis: usize| {
+                a[axis].min(b[axis]) <= point[axis] && point[axis] <= a[axis].max(b[axis])
+            };
+            if cross == 0 && between(0) && between(1) {
+                return true;
+            }
+            // count the edges crossed by a ray to the right of the point
+            if (a[1] > y) != (b[1] > y) && (cross > 0) == (b[1] > a[1]) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    /// Compresses the coordinates of a rectilinear polygon so that whether a
+    /// rectangle lies inside it can be answered without walking the edges.
+    pub fn compress(&self) -> Result<CompressedPolygon, PuzzleError> {
+        if !self.is_rectilinear() {
+            return Err(PuzzleError::unsupported(
+                "Can only compress a polygon with horizontal and vertical edges",
+            ));
+        }
+        Ok(CompressedPolygon::new(self))
+    }
+}
+
+/// A rectilinear polygon cut into blocks of grid points along the lines of
+/// its vertices, along with the gaps in between, so that every point in a
+/// block is either in the polygon or not. A 2D prefix sum over the blocks
+/// outside the polygon answers rectangle queries in logarithmic time.
+#[derive(Clone, Debug)]
+pub struct CompressedPolygon {
+    bounds: Rect<i64>,
+    /// The first coordinate in each block, for each axis.
+    starts: [Vec<i64>; 2],
+    /// The number of blocks outside the polygon up to each column and row.
+    outside: Vec<u32>,
+}
+
+impl CompressedPolygon {
+    fn new(polygon: &Polygon) -> Self {
+        let bounds = Rect::from_points(polygon.vertices().iter().copied())
+            .unwrap_or_else(|| Rect::new(Point::default()));
+        let starts = [0, 1].map(|axis| {
+            let mut coords: Vec<_> = polygon
+                .vertices()
+                .iter()
+                .map(|vertex| vertex[axis])
+                .collect();
+            coords.sort_unstable();
+            coords.dedup();
+            let mut starts = Vec::with_capacity(2 * coords.len());
+            for (i, &coord) in coords.iter().enumerate() {
+                starts.push(coord);
+                if coords.get(i + 1).is_some_and(|&next| next > coord + 1) {
+                    starts.push(coord + 1);
+                }
+            }
+            starts
+        });
+        let mut compressed = Self {
+            bounds,
+            starts,
+            outside: Vec::new(),
+        };
+        let (columns, rows) = (compressed.starts[0].len(), compressed.starts[1].len());
+        let mut outside = vec![0; (columns + 1) * (rows + 1)];
+        for (row, &y) in compressed.starts[1].iter().enumerate() {
+            for (column, inside) in compressed.row_inside(polygon, y).into_iter().enumerate() {
+                let index = (row + 1) * (columns + 1) + column + 1;
+                let above = outside[index - columns - 1] - outside[index - columns - 2];
+                outside[index] = u32::from(!inside) + outside[index - 1] + above;
+            }
+        }
+        compressed.outside = outside;
+        compressed
+    }
+
+    /// The block of an axis that a coordinate inside the bounds falls in.
+    fn block(&self, axis: usize, coord: i64) -> usize {
+        self.starts[axis].partition_point(|&start| start <= coord) - 1
+    }
+
+    /// Whether each column of blocks is inside the polygon along row `y`.
+    fn row_inside(&self, polygon: &Polygon, y: i64) -> Vec<bool> {
+        let columns = self.starts[0].len();
+        // flips for the columns right of a crossing, and counts of the edges
+        // covering each column as a difference array
+        let mut crossings = vec![false; columns + 1];
+        let mut on_edges = vec![0i32; columns + 1];
+        for (a, b) in polygon.edges() {
+            let (low, high) = (a[1].min(b[1]), a[1].max(b[1]));
+            if low <= y && y <= high {
+                let (left, right) = (a[0].min(b[0]), a[0].max(b[0]));
+                on_edges[self.block(0, left)] += 1;
+                on_edges[self.block(0, right) + 1] -= 1;
+            }
+            if a[0] == b[0] && low <= y && y < high {
+                let column = self.block(0, a[0]);
+                crossings[column + 1] = !crossings[column + 1];
+            }
+        }
+        let (mut inside, mut covered) = (false, 0);
+        (0..columns)
+            .map(|column| {
+                inside ^= crossings[column];
+                covered += on_edges[column];
+                inside || covered > 0
+            })
+            .collect()
+    }
+
+    /// Whether every point of the rectangle is inside the polygon or on an edge.
+    pub fn contains_rect(&self, rect: &Rect<i64>) -> bool {
+        if !self.bounds.contains_box(rect) {
+            return false;
+        }
+        let columns = self.starts[0].len() + 1;
+        let (left, right) = (self.block(0, rect.min[0]), self.block(0, rect.max[0]) + 1);
+        let (top, bottom) = (self.block(1, rect.min[1]), self.block(1, rect.max[1]) + 1);
+        let total = |row: usize, column: usize| self.outside[row * columns + column];
+        total(bottom, right) + total(top, left) == total(top, right) + total(bottom, left)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn polygon(vertices: &[[i64; 2]]) -> Polygon {
+        Polygon::new(vertices.iter().copied().map(Point::new).collect())
+    }
+
+    /// The red tiles from the example for 2025 day 9.
+    fn tiles() -> Polygon {
+        polygon(&[
+            [7, 1],
+            [11, 1],
+            [11, 7],
+            [9, 7],
+            [9, 5],
+            [2, 5],
+            [2, 3],
+            [7, 3],
+        ])
+    }
+
+    #[test]
+    fn test_counts() {
+        let triangle = polygon(&[[0, 0], [4, 0], [0, 4]]);
+        assert_eq!(triangle.double_area(), 16);
+        assert_eq!(triangle.boundary_points(), 12);
+        assert_eq!(triangle.interior_points(), 3);
+        assert!(!triangle.is_rectilinear());
+        let square = polygon(&[[0, 0], [0, 2], [2, 2], [2, 0]]);
+        assert_eq!(square.double_area(), 8);
+        assert_eq!(square.interior_points() + square.boundary_points(), 9);
+        assert_eq!(tiles().interior_points() + tiles().boundary_points(), 46);
+    }
+
+    #[rstest]
+    #[case([7, 1], true)]
+    #[case([9, 3], true)]
+    #[case([2, 4], true)]
+    #[case([4, 2], false)]
+    #[case([10, 6], true)]
+    #[case([8, 6], false)]
+    #[case([12, 4], false)]
+    fn test_contains(#[case] point: [i64; 2], #[case] expected: bool) {
+        assert_eq!(tiles().contains(&Point::new(point)), expected);
+    }
+
+    #[test]
+    fn test_contains_diagonal_edges() {
+        let triangle = polygon(&[[0, 0], [4, 0], [0, 4]]);
+        assert!(triangle.contains(&Point::new([2, 2])));
+        assert!(triangle.contains(&Point::new([1, 1])));
+        assert!(!triangle.contains(&Point::new([3, 2])));
+        assert!(!triangle.contains(&Point::new([-1, 1])));
+    }
+
+    #[rstest]
+    #[case([9, 5], [2, 3], true)]
+    #[case([7, 3], [11, 1], true)]
+    #[case([9, 7], [9, 5], true)]
+    #[case([11, 1], [2, 5], false)]
+    #[case([7, 1], [2, 5], false)]
+    #[case([0, 0], [3, 3], false)]
+    fn test_contains_rect(#[case] a: [i64; 2], #[case] b: [i64; 2], #[case] expected: bool) {
+        let compressed = tiles().compress().unwrap();
+        let rect = Rect::from_corners(Point::new(a), Point::new(b));
+        assert_eq!(compressed.contains_rect(&rect), expected);
+        // the same as checking every point
+        let tiles = tiles();
+        let inside = rect.points().all(|point| tiles.contains(&point));
+        assert_eq!(inside, expected);
+    }
+
+    #[test]
+    fn test_compress_needs_rectilinear() {
+        assert!(polygon(&[[0, 0], [4, 0], [0, 4]]).compress().is_err());
+    }
+}
@@ -0,0 +1,359 @@
+use super::{Direction4, Point2D};
+use crate::error::PuzzleError;
+use num::Integer;
+
+type Point = Point2D<i64>;
+
+fn cross(a: Point, b: Point) -> i64 {
+    a.x * b.y - a.y * b.x
+}
+
+fn dot(a: Point, b: Point) -> i64 {
+    a.x * b.x + a.y * b.y
+}
+
+/// A straight line between two points on the grid, both ends included. Any
+/// angle is allowed, though only horizontal, vertical and diagonal segments
+/// pass through a grid point at every step.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Segment {
+    pub start: Point,
+    pub end: Point,
+}
+
+/// Where two segments meet.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Intersection {
+    Point(Point),
+    /// The segments lie along the same line and share more than one point,
+    /// given in the direction of the first segment.
+    Overlap(Segment),
+}
+
+impl Segment {
+    pub fn new(start: Point, end: Point) -> Self {
+        Self { start, end }
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    /// The number of steps between the grid points on the segment, which for
+    /// horizontal and vertical segments is just the length.
+    pub fn steps(&self) -> u64 {
+        let offset = self.end - self.start;
+        offset.x.gcd(&offset.y).unsigned_abs()
+    }
+
+    /// The smallest step between grid points on the segment.
+    fn unit_step(&self) -> Point {
+        let offset = self.end - self.start;
+        match offset.x.gcd(&offset.y) {
+            0 => offset,
+            steps => Point::new(offset.x / steps, offset.y / steps),
+        }
+    }
+
+    /// Every grid point on the segment, from start to end.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (start, step) = (self.start, self.unit_step());
+        (0..=self.steps() as i64).map(move |steps| start + step * steps)
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        let (offset, along) = (*point - self.start, self.end - self.start);
+        // every offset is parallel to a segment which doesn't go anywhere
+        if along == Point::default() {
+            return *point == self.start;
+        }
+        cross(along, offset) == 0 && (0..=dot(along, along)).contains(&dot(offset, along))
+    }
+
+    /// The number of steps from the start to a point on the segment.
+    pub fn steps_to(&self, point: &Point) -> u64 {
+        let offset = *point - self.start;
+        offset.x.gcd(&offset.y).unsigned_abs()
+    }
+
+    /// Where the segments meet, worked out exactly with integers. Segments
+    /// crossing between grid points don't count, as when they're paths
+    /// along the grid they never actually meet.
+    pub fn intersection(&self, other: &Segment) -> Option<Intersection> {
+        let (p, q) = (self.start, other.start);
+        let (r, s) = (self.end - p, other.end - q);
+        if r == Point::default() || s == Point::default() {
+            let (point, segment) = if r == Point::default() {
+                (p, other)
+            } else {
+                (q, self)
+            };
+            return segment
+                .contains(&point)
+                .then_some(Intersection::Point(point));
+        }
+        let denominator = cross(r, s);
+        if denominator == 0 {
+            return self.overlap(other);
+        }
+        // solve p + t r = q + u s for the fractions t and u along each segment
+        let (t, u) = (cross(q - p, s), cross(q - p, r));
+        let within = |n: i64| {
+            if denominator > 0 {
+                0 <= n && n <= denominator
+            } else {
+                denominator <= n && n <= 0
+            }
+        };
+        let on_grid = (t * r.x) % denominator == 0 && (t * r.y) % denominator == 0;
+        if !within(t) || !within(u) || !on_grid {
+            return None;
+        }
+        Some(Intersection::Point(
+            p + Point::new(t * r.x / denominator, t * r.y / denominator),
+        ))
+    }
+
+    /// The shared part of parallel segments, if they're on the same line.
+    fn overlap(&self, other: &Segment) -> Option<Intersection> {
+        let along = self.end - self.start;
+        if cross(along, other.start - self.start) != 0 {
+            return None;
+        }
+        // order the ends by how far they are along this segment
+        let key = |point: &Point| dot(*point - self.start, along);
+        let (first, last) = if key(&other.start) <= key(&other.end) {
+            (other.start, other.end)
+        } else {
+            (other.end, other.start)
+        };
+        let start = if key(&first) > 0 { first } else { self.start };
+        let end = if key(&last) < key(&self.end) {
+            last
+        } else {
+            self.end
+        };
+        match key(&start).cmp(&key(&end)) {
+            std::cmp::Ordering::Less => Some(Intersection::Overlap(Segment::new(start, end))),
+            std::cmp::Ordering::Equal => Some(Intersection::Point(start)),
+            std::cmp::Ordering::Greater => None,
+        }
+    }
+}
+
+/// A path made of segments joined end to end, such as a wire.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polyline {
+    vertices: Vec<Point>,
+}
+
+/// A point where two paths meet, with how far along each path it is.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Crossing {
+    pub point: Point,
+    pub steps: (u64, u64),
+}
+
+impl Polyline {
+    /// A path that hasn't gone anywhere from its start yet.
+    pub fn new(start: Point) -> Self {
+        Self {
+            vertices: vec![start],
+        }
+    }
+
+    /// Parses moves such as `"R8,U5,L5"` from the start, with up increasing `y`.
+    pub fn parse_moves(start: Point, moves: &str) -> Result<Self, PuzzleError> {
+        let mut path = Self::new(start);
+        for step in moves.trim().split(',') {
+            let mut chars = step.chars();
+            let direction = chars
+                .next()
+                .ok_or_else(|| PuzzleError::malformed("Found an empty move"))
+                .and_then(Direction4::try_from)?;
+            let distance = chars.as_str().parse().map_err(|_| {
+                PuzzleError::malformed(format!("Expected a distance to move, not {step:?}"))
+            })?;
+            path.go(direction, distance);
+        }
+        Ok(path)
+    }
+
+    /// Extends the path in a straight line.
+    pub fn go(&mut self, direction: Direction4, distance: i64) {
+        let end = self.end() + direction.unit_vector() * distance;
+        self.vertices.push(end);
+    }
+
+    /// Extends the path in a straight line to a point.
+    pub fn go_to(&mut self, point: Point) {
+        self.vertices.push(point);
+    }
+
+    pub fn end(&self) -> Point {
+        *self.vertices.last().unwrap()
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    /// Each segment of the path, with the steps taken before reaching it.
+    pub fn segments(&self) -> impl Iterator<Item = (u64, Segment)> + '_ {
+        self.vertices
+            .windows(2)
+            .map(|pair| Segment::new(pair[0], pair[1]))
+            .scan(0, |steps, segment| {
+                let before = *steps;
+                *steps += segment.steps();
+                Some((before, segment))
+            })
+    }
+
+    /// Every grid point where the paths meet, with the steps taken along each
+    /// path to get there. A point is given once for each time either path
+    /// passes through it, so the smallest steps come from taking the minimum.
+    pub fn crossings(&self, other: &Polyline) -> Vec<Crossing> {
+        let mut crossings = Vec::new();
+        for (i, (steps, segment)) in self.segments().enumerate() {
+            for (j, (other_steps, other_segment)) in other.segments().enumerate() {
+                let points: Vec<_> = match segment.intersection(&other_segment) {
+                    None => continue,
+                    Some(Intersection::Point(point)) => vec![point],
+                    Some(Intersection::Overlap(overlap)) => overlap.points().collect(),
+                };
+                // each corner was already found at the end of the segment before
+                let corner = |point: &Point| {
+                    (i > 0 && *point == segment.start) || (j > 0 && *point == other_segment.start)
+                };
+                for point in points.into_iter().filter(|point| !corner(point)) {
+                    crossings.push(Crossing {
+                        point,
+                        steps: (
+                            steps + segment.steps_to(&point),
+                            other_steps + other_segment.steps_to(&point),
+                        ),
+                    });
+                }
+            }
+        }
+        crossings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    fn segment(start: (i64, i64), end: (i64, i64)) -> Segment {
+        Segment::new(start.into(), end.into())
+    }
+
+    fn at(x: i64, y: i64) -> Option<Intersection> {
+        Some(Intersection::Point(Point::new(x, y)))
+    }
+
+    fn overlap(start: (i64, i64), end: (i64, i64)) -> Option<Intersection> {
+        Some(Intersection::Overlap(segment(start, end)))
+    }
+
+    #[rstest]
+    #[case(segment((0, 0), (4, 4)), segment((0, 4), (4, 0)), at(2, 2))]
+    #[case(segment((0, 0), (0, 5)), segment((-2, 3), (2, 3)), at(0, 3))]
+    #[case(segment((0, 0), (0, 5)), segment((1, 3), (4, 3)), None)]
+    // crossing between grid points
+    #[case(segment((0, 0), (1, 1)), segment((0, 1), (1, 0)), None)]
+    // touching at the ends
+    #[case(segment((0, 0), (3, 0)), segment((3, 0), (3, 2)), at(3, 0))]
+    #[case(segment((0, 0), (3, 0)), segment((3, 0), (6, 0)), at(3, 0))]
+    #[case(segment((0, 0), (6, 0)), segment((8, 0), (2, 0)), overlap((2, 0), (6, 0)))]
+    #[case(segment((6, 6), (0, 0)), segment((1, 1), (2, 2)), overlap((2, 2), (1, 1)))]
+    #[case(segment((0, 0), (2, 0)), segment((0, 1), (2, 1)), None)]
+    #[case(segment((0, 0), (2, 0)), segment((4, 0), (5, 0)), None)]
+    #[case(segment((1, 1), (1, 1)), segment((0, 0), (2, 2)), at(1, 1))]
+    #[case(segment((1, 1), (1, 1)), segment((0, 0), (0, 2)), None)]
+    #[case(segment((0, 0), (0, 2)), segment((1, 1), (1, 1)), None)]
+    #[case(segment((1, 1), (1, 1)), segment((1, 1), (1, 1)), at(1, 1))]
+    #[case(segment((1, 1), (1, 1)), segment((2, 2), (2, 2)), None)]
+    fn test_intersection(
+        #[case] a: Segment,
+        #[case] b: Segment,
+        #[case] expected: Option<Intersection>,
+    ) {
+        assert_eq!(a.intersection(&b), expected);
+    }
+
+    #[test]
+    fn test_points() {
+        let diagonal = segment((3, 3), (0, 0));
+        assert_eq!(diagonal.steps(), 3);
+        let points: Vec<_> = diagonal.points().collect();
+        let expected: Vec<Point> = vec![(3, 3).into(), (2, 2).into(), (1, 1).into(), (0, 0).into()];
+        assert_eq!(points, expected);
+        assert!(diagonal.contains(&Point::new(2, 2)));
+        assert!(!diagonal.contains(&Point::new(4, 4)));
+        assert_eq!(diagonal.steps_to(&Point::new(1, 1)), 2);
+        assert_eq!(segment((0, 0), (4, 2)).points().count(), 3);
+    }
+
+    #[test]
+    fn test_zero_length_segment() {
+        let point = segment((2, 1), (2, 1));
+        assert_eq!(point.steps(), 0);
+        assert!(point.contains(&Point::new(2, 1)));
+        assert!(!point.contains(&Point::new(0, 0)));
+        assert!(!point.contains(&Point::new(4, 2)));
+        // wires which both start with a move of no distance don't meet there
+        let wire_1 = Polyline::parse_moves(Point::default(), "U0,R2").unwrap();
+        let wire_2 = Polyline::parse_moves(Point::new(5, 5), "D0,D1").unwrap();
+        assert!(wire_1.crossings(&wire_2).is_empty());
+    }
+
+    #[test]
+    fn test_crossings() {
+        let wire_1 = Polyline::parse_moves(Point::default(), "R8,U5,L5,D3").unwrap();
+        let wire_2 = Polyline::parse_moves(Point::default(), "U7,R6,D4,L4").unwrap();
+        assert_eq!(wire_1.end(), Point::new(3, 2));
+        let mut crossings = wire_1.crossings(&wire_2);
+        crossings.sort_by_key(|crossing| crossing.point);
+        assert_eq!(
+            crossings,
+            vec![
+                Crossing {
+                    point: Point::new(0, 0),
+                    steps: (0, 0)
+                },
+                Crossing {
+                    point: Point::new(3, 3),
+                    steps: (20, 20)
+                },
+                Crossing {
+                    point: Point::new(6, 5),
+                    steps: (15, 15)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overlapping_paths() {
+        let wire_1 = Polyline::parse_moves(Point::default(), "R4").unwrap();
+        let wire_2 = Polyline::parse_moves(Point::new(6, 0), "L3,U1").unwrap();
+        let crossings = wire_1.crossings(&wire_2);
+        let steps: Vec<_> = crossings.iter().map(|crossing| crossing.steps).collect();
+        assert_eq!(steps, vec![(3, 3), (4, 2)]);
+    }
+
+    #[rstest]
+    #[case("R8,X5")]
+    #[case("R8,U")]
+    #[case("R8,,U5")]
+    fn test_bad_moves(#[case] moves: &str) {
+        assert!(Polyline::parse_moves(Point::default(), moves).is_err());
+    }
+}
@@ -0,0 +1,213 @@
+use super::{Direction8, Grid, GridBounds, Orientation, Point2D};
+
+type Point = Point2D<usize>;
+
+/// The first point along a ray from a point, not including it, that can't be
+/// seen past, unless the ray reaches the edge of the grid first.
+pub fn first_visible<T>(
+    grid: &Grid<T>,
+    start: &Point,
+    direction: impl Into<Direction8>,
+    transparent: impl Fn(&T) -> bool,
+) -> Option<Point> {
+    grid.line(start, direction)
+        .find(|point| !transparent(&grid[*point]))
+}
+
+/// Every line of points across the grid in a direction, each starting on the
+/// edge it heads away from, e.g. the rows from left to right for east.
+fn lines(bounds: GridBounds, direction: Direction8) -> impl Iterator<Item = Vec<Point>> {
+    let step = move |point: &Point| {
+        Orientation::Screen
+            .step(point, direction)
+            .filter(|next| bounds.contains(next))
+    };
+    let back = direction.reverse();
+    let starts: Vec<_> = bounds
+        .points()
+        .filter(|point| {
+            Orientation::Screen
+                .step(point, back)
+                .is_none_or(|previous| !bounds.contains(&previous))
+        })
+        .collect();
+    starts
+        .into_iter()
+        .map(move |start| std::iter::successors(Some(start), step).collect())
+}
+
+/// Whether each cell is higher than everything beyond it in the direction, so
+/// that it can be seen from that edge of the grid.
+pub fn visible_from_edge<T: Ord>(grid: &Grid<T>, direction: impl Into<Direction8>) -> Grid<bool> {
+    let mut visible = Grid::filled(grid.bounds(), false);
+    for line in lines(grid.bounds(), direction.into()) {
+        let mut highest: Option<&T> = None;
+        for point in line.into_iter().rev() {
+            let height = &grid[point];
+            if highest.is_none_or(|highest| height > highest) {
+                visible[point] = true;
+                highest = Some(height);
+            }
+        }
+    }
+    visible
+}
+
+/// How many cells can be seen from each cell looking in the direction, up to
+/// and including the first that's at least as high or else to the edge.
+pub fn viewing_distances<T: Ord>(grid: &Grid<T>, direction: impl Into<Direction8>) -> Grid<usize> {
+    let mut distances = Grid::filled(grid.bounds(), 0);
+    for line in lines(grid.bounds(), direction.into()) {
+        // the positions in view from the cell being looked at, with the
+        // heights decreasing towards it
+        let mut blockers: Vec<usize> = Vec::new();
+        for (position, &point) in line.iter().enumerate().rev() {
+            let height = &grid[point];
+            while blockers
+                .last()
+                .is_some_and(|&blocker| grid[line[blocker]] < *height)
+            {
+                blockers.pop();
+            }
+            let end = blockers.last().copied().unwrap_or(line.len() - 1);
+            distances[point] = end - position;
+            blockers.push(position);
+        }
+    }
+    distances
+}
+
+/// The first cell that can be seen in each of the eight directions from
+/// every cell of a grid, looking past the transparent cells, such as the
+/// floor between seats. Working these out up front saves walking the same
+/// rays again each time the other cells change.
+#[derive(Clone, Debug)]
+pub struct SightLines {
+    bounds: GridBounds,
+    seen: Vec<[Option<Point>; 8]>,
+}
+
+impl SightLines {
+    pub fn new<T>(grid: &Grid<T>, transparent: impl Fn(&T) -> bool) -> Self {
+        let bounds = grid.bounds();
+        let mut seen = vec![[None; 8]; bounds.len()];
+        for direction in Direction8::ALL {
+            for line in lines(bounds, direction) {
+                // walking back from the edge, the last opaque cell passed is
+                // the first one seen from the next
+                let mut nearest = None;
+                for point in line.into_iter().rev() {
+                    seen[bounds.index(&point)][direction as usize] = nearest;
+                    if !transparent(&grid[point]) {
+                        nearest = Some(point);
+                    }
+                }
+            }
+        }
+        Self { bounds, seen }
+    }
+
+    /// The first cell seen from a point in a direction.
+    pub fn first(&self, point: &Point, direction: impl Into<Direction8>) -> Option<Point> {
+        self.seen[self.bounds.index(point)][direction.into() as usize]
+    }
+
+    /// The cells seen from a point, in whichever directions see one.
+    pub fn visible(&self, point: &Point) -> impl Iterator<Item = Point> + '_ {
+        self.seen[self.bounds.index(point)]
+            .iter()
+            .flatten()
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::PuzzleError;
+    use crate::geometry::Direction4;
+    use rstest::*;
+
+    fn trees() -> Grid<u32> {
+        Grid::parse("30373\n25512\n65332\n33549\n35390", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| PuzzleError::malformed("Expected a digit"))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_visible_from_edge() {
+        let grid = trees();
+        let edges = Direction4::ALL.map(|d| visible_from_edge(&grid, d));
+        let visible = grid
+            .iter()
+            .filter(|&(point, _)| edges.iter().any(|e| e[point]));
+        assert_eq!(visible.count(), 21);
+        let west = visible_from_edge(&grid, Direction4::West);
+        assert_eq!(west.row(1), &[true, true, false, false, false]);
+        // the same as walking out from every tree
+        for direction in Direction8::ALL {
+            let visible = visible_from_edge(&grid, direction);
+            for (point, &height) in grid.iter() {
+                let expected = grid
+                    .line(&point, direction)
+                    .all(|other| grid[other] < height);
+                assert_eq!(visible[point], expected, "{point} looking {direction}");
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(Point::new(2, 1), [1, 2, 2, 1])]
+    #[case(Point::new(2, 3), [2, 2, 1, 2])]
+    #[case(Point::new(0, 0), [0, 2, 2, 0])]
+    fn test_viewing_distances(#[case] tree: Point, #[case] expected: [usize; 4]) {
+        let grid = trees();
+        let distances = Direction4::ALL.map(|d| viewing_distances(&grid, d)[tree]);
+        assert_eq!(distances, expected);
+    }
+
+    #[test]
+    fn test_viewing_distances_match_walking() {
+        let grid = trees();
+        for direction in Direction8::ALL {
+            let distances = viewing_distances(&grid, direction);
+            for (point, &height) in grid.iter() {
+                let line: Vec<_> = grid.line(&point, direction).collect();
+                let blocked = line.iter().position(|&other| grid[other] >= height);
+                assert_eq!(distances[point], blocked.map_or(line.len(), |i| i + 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sight_lines() {
+        let seats = ".......#.\n...#.....\n.#.......\n.........\n..#L#....\n....#....\n\
+                     .........\n#........\n...#.....";
+        let grid: Grid<char> = Grid::parse(seats, Ok).unwrap();
+        let sight_lines = SightLines::new(&grid, |&c| c == '.');
+        let seat = Point::new(3, 4);
+        assert_eq!(
+            sight_lines
+                .visible(&seat)
+                .filter(|&p| grid[p] == '#')
+                .count(),
+            8
+        );
+        assert_eq!(
+            sight_lines.first(&seat, Direction8::NorthEast),
+            Some(Point::new(7, 0))
+        );
+        for direction in Direction8::ALL {
+            for (point, _) in grid.iter() {
+                let walked = first_visible(&grid, &point, direction, |&c| c == '.');
+                assert_eq!(sight_lines.first(&point, direction), walked);
+            }
+        }
+        let row: Grid<char> = Grid::parse(".L.L.#.#.#.#.", Ok).unwrap();
+        let sight_lines = SightLines::new(&row, |&c| c == '.');
+        let seen: Vec<_> = sight_lines.visible(&Point::new(1, 0)).collect();
+        assert_eq!(seen, vec![Point::new(3, 0)]);
+    }
+}
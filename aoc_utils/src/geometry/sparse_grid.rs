@@ -0,0 +1,275 @@
+use super::{Grid, GridBounds, Orientation, Point2D, Rect};
+use num::PrimInt;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::Hash;
+
+/// Cells scattered over an unbounded plane, such as elves spreading out or
+/// sand piling up, which keeps track of the rectangle around them as cells
+/// are added instead of scanning them all each time it's needed. With no
+/// values it's a set of points.
+pub struct SparseGrid<T, V = ()> {
+    cells: HashMap<Point2D<T>, V>,
+    /// The rectangle around the cells, or `None` after removing a cell from
+    /// its edge until it's next needed.
+    bounds: Cell<Option<Rect<T>>>,
+}
+
+impl<T: PrimInt + Hash, V> SparseGrid<T, V> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: Cell::new(None),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: &Point2D<T>) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    pub fn get(&self, point: &Point2D<T>) -> Option<&V> {
+        self.cells.get(point)
+    }
+
+    pub fn get_mut(&mut self, point: &Point2D<T>) -> Option<&mut V> {
+        self.cells.get_mut(point)
+    }
+
+    /// Sets a cell, giving back what was there before.
+    pub fn insert(&mut self, point: Point2D<T>, value: V) -> Option<V> {
+        if let Some(mut bounds) = self.bounds.get() {
+            bounds.include(&point.into());
+            self.bounds.set(Some(bounds));
+        } else if self.cells.is_empty() {
+            self.bounds.set(Some(Rect::new(point.into())));
+        }
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: &Point2D<T>) -> Option<V> {
+        let value = self.cells.remove(point)?;
+        let on_edge = self.bounds.get().is_some_and(|bounds| {
+            [bounds.min, bounds.max]
+                .iter()
+                .any(|corner| corner[0] == point.x || corner[1] == point.y)
+        });
+        if on_edge {
+            self.bounds.set(None);
+        }
+        Some(value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point2D<T>, &V)> + '_ {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = &Point2D<T>> + '_ {
+        self.cells.keys()
+    }
+
+    /// The smallest rectangle containing every cell, unless there are none.
+    pub fn bounds(&self) -> Option<Rect<T>> {
+        if self.bounds.get().is_none() {
+            self.bounds.set(Rect::from_points(
+                self.cells.keys().map(|&point| point.into()),
+            ));
+        }
+        self.bounds.get()
+    }
+
+    /// The number of points in the bounding rectangle without a cell.
+    pub fn count_empty(&self) -> u64 {
+        self.bounds().map_or(0, |bounds| bounds.area()) - self.len() as u64
+    }
+
+    /// The width and height of the bounding rectangle, and the `y` of each
+    /// row in it from the top of the page down.
+    fn rows(&self, orientation: Orientation) -> Option<(Rect<T>, usize, Vec<T>)> {
+        let bounds = self.bounds()?;
+        let [width, height] = bounds.lengths().map(|length| length.to_usize().unwrap());
+        let rows = (0..height)
+            .map(|row| {
+                let row = T::from(row).unwrap();
+                match orientation {
+                    Orientation::Cartesian => bounds.max[1] - row,
+                    Orientation::Screen => bounds.min[1] + row,
+                }
+            })
+            .collect();
+        Some((bounds, width, rows))
+    }
+
+    /// Draws the bounding rectangle a line at a time, choosing a character
+    /// for each point from what's there.
+    pub fn render(&self, orientation: Orientation, cell: impl Fn(Option<&V>) -> char) -> String {
+        let Some((bounds, width, rows)) = self.rows(orientation) else {
+            return String::new();
+        };
+        let lines: Vec<String> = rows
+            .into_iter()
+            .map(|y| {
+                (0..width)
+                    .map(|column| {
+                        let x = bounds.min[0] + T::from(column).unwrap();
+                        cell(self.cells.get(&Point2D::new(x, y)))
+                    })
+                    .collect()
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    /// The bounding rectangle as a dense grid, with the top left corner at
+    /// the origin and the rows running down the page as `y` grows.
+    pub fn to_grid(&self) -> Grid<Option<V>>
+    where
+        V: Clone,
+    {
+        let Some((bounds, width, rows)) = self.rows(Orientation::Screen) else {
+            return Grid::new(GridBounds::new(0, 0), Vec::new());
+        };
+        let mut grid = Grid::filled(GridBounds::new(width, rows.len()), None);
+        for (point, value) in self.cells.iter() {
+            let x = (point.x - bounds.min[0]).to_usize().unwrap();
+            let y = (point.y - bounds.min[1]).to_usize().unwrap();
+            grid[Point2D::new(x, y)] = Some(value.clone());
+        }
+        grid
+    }
+
+    /// The cells of a dense grid, keeping those the function gives a value for.
+    pub fn from_grid<U>(grid: &Grid<U>, mut cell: impl FnMut(&U) -> Option<V>) -> Self {
+        grid.iter()
+            .filter_map(|(point, value)| {
+                let coordinate =
+                    |c: usize| T::from(c).expect("The grid is too big for the type of coordinate");
+                let (x, y) = (coordinate(point.x), coordinate(point.y));
+                cell(value).map(|value| (Point2D::new(x, y), value))
+            })
+            .collect()
+    }
+}
+
+impl<T: PrimInt + Hash> SparseGrid<T> {
+    /// Adds a point, returning whether it wasn't already there.
+    pub fn add(&mut self, point: Point2D<T>) -> bool {
+        self.insert(point, ()).is_none()
+    }
+}
+
+impl<T: PrimInt + Hash, V> Default for SparseGrid<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PrimInt + Hash, V: Clone> Clone for SparseGrid<T, V> {
+    fn clone(&self) -> Self {
+        Self {
+            cells: self.cells.clone(),
+            bounds: self.bounds.clone(),
+        }
+    }
+}
+
+impl<T: PrimInt + Hash + Debug, V: Debug> Debug for SparseGrid<T, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SparseGrid")
+            .field("cells", &self.cells)
+            .finish()
+    }
+}
+
+/// Grids are the same if they have the same cells.
+impl<T: PrimInt + Hash, V: PartialEq> PartialEq for SparseGrid<T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cells == other.cells
+    }
+}
+
+impl<T: PrimInt + Hash, V: Eq> Eq for SparseGrid<T, V> {}
+
+impl<T: PrimInt + Hash, V> FromIterator<(Point2D<T>, V)> for SparseGrid<T, V> {
+    fn from_iter<I: IntoIterator<Item = (Point2D<T>, V)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (point, value) in iter {
+            grid.insert(point, value);
+        }
+        grid
+    }
+}
+
+impl<T: PrimInt + Hash> FromIterator<Point2D<T>> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = Point2D<T>>>(iter: I) -> Self {
+        iter.into_iter().map(|point| (point, ())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+
+    /// The small example of elves from 2022 day 23.
+    const ELVES: &str = ".....\n..##.\n..#..\n.....\n..##.\n.....";
+
+    fn elves() -> SparseGrid<i64> {
+        let grid = Grid::parse(ELVES, Ok).unwrap();
+        SparseGrid::from_grid(&grid, |&c| (c == '#').then_some(()))
+    }
+
+    fn draw(grid: &SparseGrid<i64>, orientation: Orientation) -> String {
+        grid.render(orientation, |cell| if cell.is_some() { '#' } else { '.' })
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = elves();
+        assert_eq!(grid.len(), 5);
+        let bounds = Rect::from_corners(Point::new([2, 1]), Point::new([3, 4]));
+        assert_eq!(grid.bounds(), Some(bounds));
+        assert_eq!(grid.count_empty(), 3);
+        assert!(grid.add(Point2D::new(-1, 2)));
+        assert!(!grid.add(Point2D::new(-1, 2)));
+        assert_eq!(grid.bounds().unwrap().min, Point::new([-1, 1]));
+        assert_eq!(grid.count_empty(), 14);
+        // shrinks again when the cell on the edge goes
+        assert_eq!(grid.remove(&Point2D::new(-1, 2)), Some(()));
+        assert_eq!(grid.bounds(), Some(bounds));
+        assert_eq!(grid.remove(&Point2D::new(-1, 2)), None);
+        assert_eq!(SparseGrid::<i64>::new().bounds(), None);
+        assert_eq!(SparseGrid::<i64>::new().count_empty(), 0);
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = elves();
+        assert_eq!(draw(&grid, Orientation::Screen), "##\n#.\n..\n##");
+        assert_eq!(draw(&grid, Orientation::Cartesian), "##\n..\n#.\n##");
+        assert_eq!(draw(&SparseGrid::new(), Orientation::Screen), "");
+    }
+
+    #[test]
+    fn test_dense_grids() {
+        let mut grid: SparseGrid<i32, u8> = SparseGrid::new();
+        grid.insert(Point2D::new(-2, 5), 1);
+        grid.insert(Point2D::new(0, 4), 2);
+        let dense = grid.to_grid();
+        assert_eq!((dense.width(), dense.height()), (3, 2));
+        assert_eq!(dense.row(0), &[None, None, Some(2)]);
+        assert_eq!(dense.row(1), &[Some(1), None, None]);
+        let back: SparseGrid<i32, u8> = SparseGrid::from_grid(&dense, |&cell| cell);
+        assert_eq!(back.len(), 2);
+        assert_eq!(back.get(&Point2D::new(2, 0)), Some(&2));
+        assert_eq!(SparseGrid::<i32, u8>::new().to_grid().cells().len(), 0);
+    }
+}
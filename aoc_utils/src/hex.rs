@@ -0,0 +1,458 @@
+use crate::error::PuzzleError;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// A hexagon on a hex grid in axial coordinates, see
+/// <https://www.redblobgames.com/grids/hexagons/>. The third cube coordinate
+/// `s` is implied by `q + r + s = 0`.
+///
+/// Which way `q` and `r` run depends on the [`Layout`], but the six
+/// neighbours, distances, rings and spirals are the same for both, so only
+/// naming directions and placing hexes on the page need to know it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+/// The steps to each neighbour, anticlockwise as drawn by [`Layout::centre`]
+/// with `y` increasing down the page: east then north-east for pointy-top
+/// hexes, south-east then north-east for flat-top ones.
+const OFFSETS: [Hex; 6] = [
+    Hex { q: 1, r: 0 },
+    Hex { q: 1, r: -1 },
+    Hex { q: 0, r: -1 },
+    Hex { q: -1, r: 0 },
+    Hex { q: -1, r: 1 },
+    Hex { q: 0, r: 1 },
+];
+
+impl Hex {
+    pub const ORIGIN: Hex = Hex { q: 0, r: 0 };
+
+    pub fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// `None` unless the coordinates add up to zero.
+    pub fn from_cube(q: i64, r: i64, s: i64) -> Option<Self> {
+        (q + r + s == 0).then_some(Self { q, r })
+    }
+
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    pub fn cube(&self) -> (i64, i64, i64) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn step<D: HexDirection>(&self, direction: D) -> Self {
+        *self + direction.offset()
+    }
+
+    /// The hex reached by taking each step in turn.
+    pub fn walk<D: HexDirection>(&self, directions: impl IntoIterator<Item = D>) -> Self {
+        directions
+            .into_iter()
+            .fold(*self, |hex, direction| hex.step(direction))
+    }
+
+    /// The six hexes sharing an edge with this one, in the same order for
+    /// both layouts.
+    pub fn neighbours(&self) -> [Self; 6] {
+        OFFSETS.map(|offset| *self + offset)
+    }
+
+    /// The number of steps to the other hex.
+    pub fn distance(&self, other: &Self) -> i64 {
+        let difference = *self - *other;
+        (difference.q.abs() + difference.r.abs() + difference.s().abs()) / 2
+    }
+
+    /// The `6 * radius` hexes at exactly `radius` steps away, going round from
+    /// the one reached by heading in the direction of the fifth neighbour.
+    /// A radius of zero is just this hex.
+    pub fn ring(&self, radius: u32) -> Vec<Self> {
+        if radius == 0 {
+            return vec![*self];
+        }
+        let radius = i64::from(radius);
+        let mut hex = *self + OFFSETS[4] * radius;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        for offset in OFFSETS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex += offset;
+            }
+        }
+        ring
+    }
+
+    /// Every hex up to `radius` steps away, ring by ring outwards from this one.
+    pub fn spiral(&self, radius: u32) -> Vec<Self> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl AddAssign for Hex {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl SubAssign for Hex {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Hex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.q, -self.r)
+    }
+}
+
+impl Mul<i64> for Hex {
+    type Output = Self;
+
+    fn mul(self, scale: i64) -> Self {
+        Self::new(self.q * scale, self.r * scale)
+    }
+}
+
+/// Whether hexes have a corner or an edge at the top, which decides which
+/// compass directions their neighbours lie in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Layout {
+    PointyTop,
+    FlatTop,
+}
+
+impl Layout {
+    /// Where the centre of the hex is drawn, for hexes with sides of length
+    /// one and `y` increasing down the page.
+    pub fn centre(self, hex: &Hex) -> (f64, f64) {
+        let (q, r) = (hex.q as f64, hex.r as f64);
+        let root_3 = 3f64.sqrt();
+        match self {
+            Layout::PointyTop => (root_3 * (q + r / 2.0), 1.5 * r),
+            Layout::FlatTop => (1.5 * q, root_3 * (r + q / 2.0)),
+        }
+    }
+}
+
+/// The compass directions to the neighbours of a hex in one of the layouts.
+pub trait HexDirection: Copy + FromStr<Err = PuzzleError> {
+    const LAYOUT: Layout;
+    /// Every direction, clockwise.
+    const ALL: [Self; 6];
+
+    fn offset(self) -> Hex;
+
+    fn reverse(self) -> Self {
+        let offset = -self.offset();
+        *Self::ALL
+            .iter()
+            .find(|direction| direction.offset() == offset)
+            .unwrap()
+    }
+}
+
+/// Directions between pointy-top hexes, which are side by side in rows.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PointyDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl HexDirection for PointyDirection {
+    const LAYOUT: Layout = Layout::PointyTop;
+    const ALL: [Self; 6] = [
+        PointyDirection::East,
+        PointyDirection::SouthEast,
+        PointyDirection::SouthWest,
+        PointyDirection::West,
+        PointyDirection::NorthWest,
+        PointyDirection::NorthEast,
+    ];
+
+    fn offset(self) -> Hex {
+        match self {
+            PointyDirection::East => OFFSETS[0],
+            PointyDirection::NorthEast => OFFSETS[1],
+            PointyDirection::NorthWest => OFFSETS[2],
+            PointyDirection::West => OFFSETS[3],
+            PointyDirection::SouthWest => OFFSETS[4],
+            PointyDirection::SouthEast => OFFSETS[5],
+        }
+    }
+}
+
+/// Parses `e`, `se`, `sw`, `w`, `nw` or `ne`.
+impl FromStr for PointyDirection {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(PointyDirection::East),
+            "se" => Ok(PointyDirection::SouthEast),
+            "sw" => Ok(PointyDirection::SouthWest),
+            "w" => Ok(PointyDirection::West),
+            "nw" => Ok(PointyDirection::NorthWest),
+            "ne" => Ok(PointyDirection::NorthEast),
+            _ => Err(PuzzleError::malformed(format!(
+                "Unexpected direction {s:?}"
+            ))),
+        }
+    }
+}
+
+/// Directions between flat-top hexes, which are stacked in columns.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FlatDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+impl HexDirection for FlatDirection {
+    const LAYOUT: Layout = Layout::FlatTop;
+    const ALL: [Self; 6] = [
+        FlatDirection::North,
+        FlatDirection::NorthEast,
+        FlatDirection::SouthEast,
+        FlatDirection::South,
+        FlatDirection::SouthWest,
+        FlatDirection::NorthWest,
+    ];
+
+    fn offset(self) -> Hex {
+        match self {
+            FlatDirection::SouthEast => OFFSETS[0],
+            FlatDirection::NorthEast => OFFSETS[1],
+            FlatDirection::North => OFFSETS[2],
+            FlatDirection::NorthWest => OFFSETS[3],
+            FlatDirection::SouthWest => OFFSETS[4],
+            FlatDirection::South => OFFSETS[5],
+        }
+    }
+}
+
+/// Parses `n`, `ne`, `se`, `s`, `sw` or `nw`.
+impl FromStr for FlatDirection {
+    type Err = PuzzleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(FlatDirection::North),
+            "ne" => Ok(FlatDirection::NorthEast),
+            "se" => Ok(FlatDirection::SouthEast),
+            "s" => Ok(FlatDirection::South),
+            "sw" => Ok(FlatDirection::SouthWest),
+            "nw" => Ok(FlatDirection::NorthWest),
+            _ => Err(PuzzleError::malformed(format!(
+                "Unexpected direction {s:?}"
+            ))),
+        }
+    }
+}
+
+/// Parses a line of directions, either run together like `nwwswee` or
+/// separated by commas or whitespace like `ne,ne,s`, adding the column of
+/// any direction which isn't recognised.
+pub fn parse_directions<D: HexDirection>(line: &str) -> Result<Vec<D>, PuzzleError> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut directions = Vec::new();
+    let mut column = 0;
+    while column < chars.len() {
+        if chars[column] == ',' || chars[column].is_whitespace() {
+            column += 1;
+            continue;
+        }
+        // no direction is a prefix of a longer one in the same layout, except
+        // n or s for flat-top hexes, so try the longer one first
+        let two: String = chars[column..chars.len().min(column + 2)].iter().collect();
+        let one: String = chars[column..column + 1].iter().collect();
+        if let Ok(direction) = two.parse() {
+            directions.push(direction);
+            column += 2;
+        } else if let Ok(direction) = one.parse() {
+            directions.push(direction);
+            column += 1;
+        } else {
+            return Err(
+                PuzzleError::malformed(format!("Unexpected direction {two:?}")).at_column(column),
+            );
+        }
+    }
+    Ok(directions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::*;
+
+    #[test]
+    fn test_cube() {
+        let hex = Hex::new(1, -3);
+        assert_eq!(hex.cube(), (1, -3, 2));
+        assert_eq!(Hex::from_cube(1, -3, 2), Some(hex));
+        assert_eq!(Hex::from_cube(1, -3, 1), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let hex = Hex::new(2, -1);
+        let neighbours = hex.neighbours();
+        assert!(neighbours.iter().all(|n| hex.distance(n) == 1));
+        let mut ring = hex.ring(1);
+        // the ring starts at the fifth neighbour
+        ring.rotate_left(2);
+        assert_eq!(neighbours.to_vec(), ring);
+    }
+
+    #[rstest]
+    #[case(Hex::new(0, 0), Hex::new(0, 0), 0)]
+    #[case(Hex::new(0, 0), Hex::new(3, -1), 3)]
+    #[case(Hex::new(-2, 3), Hex::new(1, -1), 4)]
+    #[case(Hex::new(-2, 0), Hex::new(1, 1), 4)]
+    fn test_distance(#[case] a: Hex, #[case] b: Hex, #[case] expected: i64) {
+        assert_eq!(a.distance(&b), expected);
+        assert_eq!(b.distance(&a), expected);
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(1, 6)]
+    #[case(2, 12)]
+    #[case(5, 30)]
+    fn test_ring(#[case] radius: u32, #[case] expected: usize) {
+        let centre = Hex::new(4, -7);
+        let ring = centre.ring(radius);
+        assert_eq!(ring.len(), expected);
+        assert!(ring
+            .iter()
+            .all(|hex| centre.distance(hex) == i64::from(radius)));
+        for (hex, next) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+            assert!(radius == 0 || hex.distance(next) == 1);
+        }
+    }
+
+    #[test]
+    fn test_spiral() {
+        let spiral = Hex::ORIGIN.spiral(3);
+        assert_eq!(spiral.len(), 1 + 3 * 3 * 4);
+        assert_eq!(spiral[0], Hex::ORIGIN);
+        let mut unique = spiral.clone();
+        unique.sort();
+        unique.dedup();
+        assert_eq!(unique.len(), spiral.len());
+    }
+
+    #[test]
+    fn test_directions() {
+        for direction in PointyDirection::ALL {
+            assert_eq!(
+                Hex::ORIGIN.step(direction).step(direction.reverse()),
+                Hex::ORIGIN
+            );
+        }
+        assert_eq!(FlatDirection::North.reverse(), FlatDirection::South);
+        assert_eq!(
+            PointyDirection::NorthEast.reverse(),
+            PointyDirection::SouthWest
+        );
+        // going around the compass goes around the neighbours
+        let pointy = PointyDirection::ALL.map(|d| d.offset());
+        let flat = FlatDirection::ALL.map(|d| d.offset());
+        for offsets in [pointy, flat] {
+            for (offset, next) in offsets.iter().zip(offsets.iter().cycle().skip(1)) {
+                assert_eq!(offset.distance(next), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        for layout in [Layout::PointyTop, Layout::FlatTop] {
+            let (x, y) = layout.centre(&Hex::new(1, 0));
+            assert!((x.hypot(y) - 3f64.sqrt()).abs() < 1e-9);
+        }
+        let (x, y) = Layout::PointyTop.centre(&Hex::ORIGIN.step(PointyDirection::East));
+        assert!(x > 0.0 && y == 0.0);
+        let (x, y) = Layout::FlatTop.centre(&Hex::ORIGIN.step(FlatDirection::North));
+        assert!(x == 0.0 && y < 0.0);
+        let (x, y) = Layout::FlatTop.centre(&Hex::ORIGIN.step(FlatDirection::NorthEast));
+        assert!(x > 0.0 && y < 0.0);
+    }
+
+    #[rstest]
+    #[case("nwwswee", 0)]
+    #[case("esew", 1)]
+    fn test_parse_pointy(#[case] line: &str, #[case] expected: i64) {
+        let directions = parse_directions::<PointyDirection>(line).unwrap();
+        assert_eq!(
+            Hex::ORIGIN.walk(directions).distance(&Hex::ORIGIN),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("ne,ne,ne", 3)]
+    #[case("ne,ne,sw,sw", 0)]
+    #[case("ne,ne,s,s", 2)]
+    #[case("se,sw,se,sw,sw", 3)]
+    #[case("nnes", 1)]
+    fn test_parse_flat(#[case] line: &str, #[case] expected: i64) {
+        let directions = parse_directions::<FlatDirection>(line).unwrap();
+        assert_eq!(
+            Hex::ORIGIN.walk(directions).distance(&Hex::ORIGIN),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_directions::<PointyDirection>("nwwsxee").unwrap_err();
+        assert_eq!(
+            error.on_line(0).to_string(),
+            "Malformed input at line 1, column 4: Unexpected direction \"sx\""
+        );
+        assert!(parse_directions::<PointyDirection>("en").is_err());
+        assert!(parse_directions::<FlatDirection>("ne,e").is_err());
+    }
+}
@@ -0,0 +1,176 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Directory containing `<year>/puzzle_<day>/` folders of inputs, used
+/// instead of the puzzle folders inside the repository when set.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Passed instead of a path to read the input from stdin.
+pub const STDIN: &str = "-";
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing { path: PathBuf },
+    Unreadable { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(
+                f,
+                "No input found at {}, download it there, set {INPUT_DIR_VAR} or pass the input on stdin",
+                path.display()
+            ),
+            InputError::Unreadable { path, source } => {
+                write!(f, "Could not read {}: {source}", path.display())
+            }
+            InputError::Stdin(source) => write!(f, "Could not read input from stdin: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Unreadable { source, .. } | InputError::Stdin(source) => Some(source),
+        }
+    }
+}
+
+/// Root of the repository, which contains a folder per year.
+fn repository_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap()
+}
+
+/// The puzzle's folder inside the repository, next to its solution.
+pub fn puzzle_dir(year: u16, day: u8) -> PathBuf {
+    repository_root()
+        .join(year.to_string())
+        .join("examples")
+        .join(format!("puzzle_{day}"))
+}
+
+fn path_under(input_dir: Option<&Path>, year: u16, day: u8, file: &str) -> PathBuf {
+    match input_dir {
+        Some(dir) => dir.join(year.to_string()).join(format!("puzzle_{day}")),
+        None => puzzle_dir(year, day),
+    }
+    .join(file)
+}
+
+/// Where `file` for a puzzle lives, honouring `AOC_INPUT_DIR` when it is set.
+pub fn path(year: u16, day: u8, file: &str) -> PathBuf {
+    let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    path_under(input_dir.as_deref(), year, day, file)
+}
+
+/// Reads a file, distinguishing a missing file from one that can't be read.
+pub fn read(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing {
+            path: path.to_path_buf(),
+        },
+        _ => InputError::Unreadable {
+            path: path.to_path_buf(),
+            source,
+        },
+    })
+}
+
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut contents = String::new();
+    io::stdin()
+        .read_to_string(&mut contents)
+        .map_err(InputError::Stdin)?;
+    Ok(contents)
+}
+
+/// Loads from an explicit location, `-` meaning stdin, falling back to the
+/// puzzle's own `input.txt` when none is given.
+pub fn load_from(source: Option<&str>, year: u16, day: u8) -> Result<String, InputError> {
+    match source {
+        Some(STDIN) => read_stdin(),
+        Some(file) => read(Path::new(file)),
+        None => load(year, day),
+    }
+}
+
+/// The personal puzzle input for a day.
+pub fn load(year: u16, day: u8) -> Result<String, InputError> {
+    read(&path(year, day, "input.txt"))
+}
+
+/// Loads a file for use in tests, which should be skipped when it returns
+/// `None` as inputs and examples are not checked in.
+pub fn load_example(year: u16, day: u8, file: &str) -> Option<String> {
+    match read(&path(year, day, file)) {
+        Ok(contents) => Some(contents),
+        Err(e) => {
+            eprintln!("Skipping test: {e}");
+            None
+        }
+    }
+}
+
+/// Names of the example files present for a puzzle, in name order.
+pub fn example_files(year: u16, day: u8) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(path(year, day, "")) else {
+        return Vec::new();
+    };
+    let mut files: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| name.contains("example") && name.ends_with(".txt"))
+        .collect();
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_path_is_inside_puzzle_folder() {
+        assert_eq!(
+            path_under(None, 2022, 17, "input.txt"),
+            repository_root().join("2022/examples/puzzle_17/input.txt")
+        );
+    }
+
+    #[test]
+    fn test_input_dir_override() {
+        assert_eq!(
+            path_under(Some(Path::new("/inputs")), 2020, 9, "example.txt"),
+            PathBuf::from("/inputs/2020/puzzle_9/example.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_input_is_an_error() {
+        let error = load_from(Some("/no/such/input.txt"), 2019, 1).unwrap_err();
+        assert!(matches!(error, InputError::Missing { .. }));
+        assert!(error.to_string().contains("/no/such/input.txt"));
+        assert!(error.to_string().contains(INPUT_DIR_VAR));
+    }
+
+    #[test]
+    fn test_example_files() {
+        // the only examples which are checked in
+        let files = example_files(2022, 18);
+        assert!(files.contains(&String::from("minimal_example.txt")));
+        assert!(files.contains(&String::from("non_diagonal_example.txt")));
+        assert!(example_files(1999, 1).is_empty());
+    }
+
+    #[test]
+    fn test_read_existing_file() {
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+        let contents = load_from(manifest.to_str(), 2019, 1).unwrap();
+        assert!(contents.contains("aoc_utils"));
+    }
+}
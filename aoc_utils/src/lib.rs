@@ -1,2 +1,11 @@
+pub mod answer;
+pub mod automaton;
+pub mod bench;
+pub mod error;
 pub mod geometry;
+pub mod hex;
+pub mod input;
+pub mod manifest;
 pub mod number_theory;
+pub mod params;
+pub mod puzzle;
@@ -0,0 +1,136 @@
+use crate::answer::Answer;
+use crate::input;
+use crate::puzzle::Part;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::{fs, io};
+use toml::Table;
+
+/// Checked in alongside each solution, with a table per input file giving
+/// the parameters which differ from those for the real input and, for the
+/// examples, the answers from the puzzle description:
+///
+/// ```toml
+/// ["example.txt"]
+/// params = { row_index = 10 }
+/// part_1 = 26
+/// part_2 = 56000011
+/// ```
+pub const FILE_NAME: &str = "inputs.toml";
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+struct InputEntry {
+    #[serde(default)]
+    params: Table,
+    part_1: Option<Answer>,
+    part_2: Option<Answer>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub struct Manifest(BTreeMap<String, InputEntry>);
+
+impl Manifest {
+    /// A puzzle without a manifest uses the default parameters for every input.
+    pub fn load(year: u16, day: u8) -> Result<Self, String> {
+        let path = input::puzzle_dir(year, day).join(FILE_NAME);
+        match fs::read_to_string(&path) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    /// Input files which have an entry, in name order.
+    pub fn files(&self) -> impl Iterator<Item = &String> {
+        self.0.keys()
+    }
+
+    /// Parameters for an input file, which is looked up by name only.
+    pub fn params(&self, file: &str) -> Table {
+        self.0
+            .get(file)
+            .map(|entry| entry.params.clone())
+            .unwrap_or_default()
+    }
+
+    /// The known answer to a part for an input file.
+    pub fn expected(&self, file: &str, part: Part) -> Option<&Answer> {
+        let entry = self.0.get(file)?;
+        match part {
+            Part::One => entry.part_1.as_ref(),
+            Part::Two => entry.part_2.as_ref(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+["example_1.txt"]
+params = { row_index = 10 }
+part_1 = 26
+
+["example_2.txt"]
+part_2 = "CMZ"
+
+["example_3.txt".params]
+row_index = 20
+"#;
+
+    #[test]
+    fn test_params() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+        assert_eq!(
+            manifest.params("example_1.txt")["row_index"].as_integer(),
+            Some(10)
+        );
+        assert_eq!(
+            manifest.params("example_3.txt")["row_index"].as_integer(),
+            Some(20)
+        );
+        assert!(manifest.params("example_2.txt").is_empty());
+        assert!(manifest.params("input.txt").is_empty());
+    }
+
+    #[test]
+    fn test_expected() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+        assert_eq!(
+            manifest.expected("example_1.txt", Part::One),
+            Some(&Answer::Number(26))
+        );
+        assert_eq!(manifest.expected("example_1.txt", Part::Two), None);
+        assert_eq!(
+            manifest.expected("example_2.txt", Part::Two),
+            Some(&Answer::from("CMZ"))
+        );
+        assert_eq!(manifest.expected("input.txt", Part::One), None);
+    }
+
+    #[test]
+    fn test_files() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+        assert_eq!(
+            manifest.files().collect::<Vec<_>>(),
+            vec!["example_1.txt", "example_2.txt", "example_3.txt"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_manifest() {
+        assert!(Manifest::parse("[\"example.txt\"]\npart_3 = 1").is_err());
+        assert!(Manifest::parse("[\"example.txt\"]\npart_1 = 1.5").is_err());
+    }
+
+    #[test]
+    fn test_missing_manifest_is_empty() {
+        assert_eq!(Manifest::load(1999, 1), Ok(Manifest::default()));
+    }
+}
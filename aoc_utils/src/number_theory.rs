@@ -12,7 +12,7 @@ pub fn greatest_common_divisor<T: Integer + Copy>(x: T, y: T) -> T {
         a = b;
         b = rem;
     }
-    return b;
+    b
 }
 
 pub fn extended_euclidean_algorithm<T: Integer + Copy>(x: T, y: T) -> (T, T) {
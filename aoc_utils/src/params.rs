@@ -0,0 +1,119 @@
+use crate::input::STDIN;
+use crate::manifest::Manifest;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::fmt::{self, Display, Formatter};
+use std::path::Path;
+
+pub use toml::Table;
+
+/// Parameters of a puzzle which solves every input the same way.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+#[derive(Debug, PartialEq)]
+pub struct ParamError(String);
+
+impl Display for ParamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid puzzle parameters: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// Converts raw parameters into a puzzle's own type, whose defaults should
+/// be the values for the real input.
+pub fn from_table<T: DeserializeOwned>(table: &Table) -> Result<T, ParamError> {
+    toml::Value::Table(table.clone())
+        .try_into()
+        .map_err(|e: toml::de::Error| ParamError(e.message().to_owned()))
+}
+
+/// Parses a `name=value` assignment from the command line, where the value
+/// is written as in TOML, e.g. `row_index=10` or `cycles_to_sum=[20, 60]`.
+pub fn parse_assignment(assignment: &str) -> Result<(String, toml::Value), String> {
+    let table: Table = toml::from_str(assignment)
+        .map_err(|_| format!("Expected name=value with a TOML value, not {assignment:?}"))?;
+    match table.into_iter().collect::<Vec<_>>().as_slice() {
+        [(name, value)] => Ok((name.clone(), value.clone())),
+        _ => Err(format!("Expected a single name=value, not {assignment:?}")),
+    }
+}
+
+/// Parameters for the input read from `source` as in `input::load_from`,
+/// where stdin and the default location are taken to be the real input.
+pub fn for_input(year: u16, day: u8, source: Option<&str>) -> Result<Table, String> {
+    let file = match source {
+        None | Some(STDIN) => "input.txt",
+        Some(path) => Path::new(path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(path),
+    };
+    Ok(Manifest::load(year, day)?.params(file))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct Params {
+        row_index: i64,
+        cycles: Vec<u32>,
+    }
+
+    impl Default for Params {
+        fn default() -> Self {
+            Self {
+                row_index: 2_000_000,
+                cycles: vec![20, 60],
+            }
+        }
+    }
+
+    #[test]
+    fn test_defaults_are_for_the_real_input() {
+        let params: Params = from_table(&Table::new()).unwrap();
+        assert_eq!(params, Params::default());
+        assert_eq!(from_table::<NoParams>(&Table::new()), Ok(NoParams {}));
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let (name, value) = parse_assignment("rows=10").unwrap();
+        let table = Table::from_iter([(name, value)]);
+        assert!(from_table::<Params>(&table)
+            .unwrap_err()
+            .0
+            .contains("unknown field `rows`"));
+        assert!(from_table::<NoParams>(&table).is_err());
+
+        let table = Table::from_iter([parse_assignment("row_index=\"ten\"").unwrap()]);
+        assert!(from_table::<Params>(&table).is_err());
+    }
+
+    #[test]
+    fn test_parse_assignment() {
+        assert_eq!(
+            parse_assignment("row_index=10"),
+            Ok((String::from("row_index"), toml::Value::Integer(10)))
+        );
+        let (_, cycles) = parse_assignment("cycles = [1, 2]").unwrap();
+        assert_eq!(cycles.as_array().unwrap().len(), 2);
+        assert!(parse_assignment("row_index").is_err());
+        assert!(parse_assignment("a=1\nb=2").is_err());
+    }
+
+    #[test]
+    fn test_missing_manifest_uses_defaults() {
+        assert_eq!(for_input(1999, 1, None), Ok(Table::new()));
+        assert_eq!(
+            for_input(1999, 1, Some("/tmp/example.txt")),
+            Ok(Table::new())
+        );
+    }
+}
@@ -0,0 +1,642 @@
+use crate::answer::Answer;
+use crate::bench::{self, Settings, Timing};
+use crate::error::PuzzleError;
+use crate::input;
+use crate::params::{self, ParamError, Table};
+use serde::de::DeserializeOwned;
+use std::fmt::{self, Display, Formatter};
+use std::process;
+use std::time::Instant;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("Part should be 1 or 2, not {value}")),
+        }
+    }
+}
+
+/// An alternative implementation of a part, kept alongside the default one
+/// to compare approaches.
+pub struct Variant<P> {
+    pub name: &'static str,
+    pub solve: fn(&P) -> Result<Answer, PuzzleError>,
+    /// Whether it should always agree with the default implementation.
+    pub cross_check: bool,
+}
+
+impl<P> Variant<P> {
+    pub fn new(name: &'static str, solve: fn(&P) -> Result<Answer, PuzzleError>) -> Self {
+        Self {
+            name,
+            solve,
+            cross_check: true,
+        }
+    }
+
+    /// A variant which is only correct for some inputs, so it can be run and
+    /// timed but isn't expected to agree with the default implementation.
+    pub fn unchecked(name: &'static str, solve: fn(&P) -> Result<Answer, PuzzleError>) -> Self {
+        Self {
+            name,
+            solve,
+            cross_check: false,
+        }
+    }
+}
+
+/// A variant whose answer differs from the default implementation's.
+#[derive(Debug, PartialEq)]
+pub struct Disagreement {
+    pub part: Part,
+    pub default: &'static str,
+    pub expected: Answer,
+    pub variant: &'static str,
+    pub answer: Answer,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "part {}: {} gave {} but {} gave {}",
+            self.part, self.variant, self.answer, self.default, self.expected
+        )
+    }
+}
+
+/// A single day's solution. The input is parsed once and shared by both parts,
+/// solutions which parse differently per part can simply use the raw input.
+pub trait Puzzle: Sync {
+    const YEAR: u16;
+    const DAY: u8;
+    /// The final puzzle of each calendar only has a single part.
+    const HAS_PART_2: bool = true;
+    /// Whether `parse` does the parsing, rather than handing the raw input
+    /// on to the parts, so that it's worth timing on its own.
+    const PARSES_INPUT: bool = true;
+
+    /// Knobs which differ between the examples and the real input, such as
+    /// the row to inspect, read from the puzzle's `inputs.toml` manifest.
+    /// Missing values should default to the ones for the real input.
+    type Params: DeserializeOwned;
+    type Parsed;
+
+    /// Errors for inputs which can't be solved should be returned rather
+    /// than panicking, so that a run over every puzzle can carry on.
+    fn parse(&self, input: &str, params: &Self::Params) -> Result<Self::Parsed, PuzzleError>;
+
+    fn part_1(&self, parsed: &Self::Parsed) -> Result<Answer, PuzzleError>;
+
+    fn part_2(&self, _parsed: &Self::Parsed) -> Result<Answer, PuzzleError> {
+        Err(PuzzleError::unsupported(format!(
+            "{}/{} only has a single part",
+            Self::YEAR,
+            Self::DAY
+        )))
+    }
+
+    /// Name of the function behind the default implementation of a part,
+    /// to tell it apart from the variants.
+    fn name(&self, part: Part) -> &'static str {
+        match part {
+            Part::One => "part_1",
+            Part::Two => "part_2",
+        }
+    }
+
+    /// Alternative implementations of a part.
+    fn variants(&self, _part: Part) -> Vec<Variant<Self::Parsed>> {
+        Vec::new()
+    }
+}
+
+fn solve_part<P: Puzzle>(
+    puzzle: &P,
+    parsed: &P::Parsed,
+    part: Part,
+) -> Result<Answer, PuzzleError> {
+    match part {
+        Part::One => puzzle.part_1(parsed),
+        Part::Two => puzzle.part_2(parsed),
+    }
+}
+
+/// Object safe view of a `Puzzle`, so that solutions with different parsed
+/// input types can live in the same registry.
+pub trait Solver: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn parts(&self) -> &'static [Part];
+    fn solve(
+        &self,
+        input: &str,
+        params: &Table,
+        parts: &[Part],
+    ) -> Result<Vec<(Part, Answer)>, PuzzleError>;
+    /// Times parsing and each requested part separately, including variants.
+    /// Puzzles which leave the parsing to the parts only time the parts.
+    fn bench(
+        &self,
+        input: &str,
+        params: &Table,
+        parts: &[Part],
+        settings: &Settings,
+    ) -> Result<Vec<Timing>, PuzzleError>;
+    fn variants(&self, part: Part) -> Vec<&'static str>;
+    /// Whether the raw parameters are valid for this puzzle.
+    fn check_params(&self, params: &Table) -> Result<(), ParamError>;
+    /// Solves the requested parts with every variant which should agree
+    /// with the default implementation, returning those which don't.
+    fn cross_check(
+        &self,
+        input: &str,
+        params: &Table,
+        parts: &[Part],
+    ) -> Result<Vec<Disagreement>, PuzzleError>;
+}
+
+impl<P: Puzzle> Solver for P {
+    fn year(&self) -> u16 {
+        P::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        P::DAY
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        if P::HAS_PART_2 {
+            &Part::ALL
+        } else {
+            &Part::ALL[..1]
+        }
+    }
+
+    fn solve(
+        &self,
+        input: &str,
+        params: &Table,
+        parts: &[Part],
+    ) -> Result<Vec<(Part, Answer)>, PuzzleError> {
+        let parsed = self.parse(input, &params::from_table(params)?)?;
+        parts
+            .iter()
+            .filter(|part| self.parts().contains(part))
+            .map(|part| Ok((*part, solve_part(self, &parsed, *part)?)))
+            .collect()
+    }
+
+    fn bench(
+        &self,
+        input: &str,
+        params: &Table,
+        parts: &[Part],
+        settings: &Settings,
+    ) -> Result<Vec<Timing>, PuzzleError> {
+        let params = params::from_table(params)?;
+        // errors would only be timed, so each phase is run once up front
+        let parsed = self.parse(input, &params)?;
+        let mut timings = Vec::new();
+        if P::PARSES_INPUT {
+            timings.push(Timing {
+                phase: String::from("parse"),
+                stats: bench::measure(settings, || self.parse(input, &params)),
+            });
+        }
+        for part in parts.iter().filter(|part| self.parts().contains(part)) {
+            solve_part(self, &parsed, *part)?;
+            timings.push(Timing {
+                phase: String::from(Puzzle::name(self, *part)),
+                stats: bench::measure(settings, || solve_part(self, &parsed, *part)),
+            });
+            for variant in Puzzle::variants(self, *part) {
+                (variant.solve)(&parsed)?;
+                timings.push(Timing {
+                    phase: String::from(variant.name),
+                    stats: bench::measure(settings, || (variant.solve)(&parsed)),
+                });
+            }
+        }
+        Ok(timings)
+    }
+
+    fn variants(&self, part: Part) -> Vec<&'static str> {
+        Puzzle::variants(self, part)
+            .iter()
+            .map(|variant| variant.name)
+            .collect()
+    }
+
+    fn check_params(&self, params: &Table) -> Result<(), ParamError> {
+        params::from_table::<P::Params>(params).map(|_| ())
+    }
+
+    fn cross_check(
+        &self,
+        input: &str,
+        params: &Table,
+        parts: &[Part],
+    ) -> Result<Vec<Disagreement>, PuzzleError> {
+        let params = params::from_table(params)?;
+        let to_check: Vec<(Part, Vec<Variant<P::Parsed>>)> = parts
+            .iter()
+            .filter(|part| self.parts().contains(part))
+            .map(|part| {
+                let variants = Puzzle::variants(self, *part)
+                    .into_iter()
+                    .filter(|variant| variant.cross_check)
+                    .collect::<Vec<_>>();
+                (*part, variants)
+            })
+            .filter(|(_, variants)| !variants.is_empty())
+            .collect();
+        if to_check.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let parsed = self.parse(input, &params)?;
+        let mut disagreements = Vec::new();
+        for (part, variants) in to_check {
+            let expected = solve_part(self, &parsed, part)?;
+            for variant in variants {
+                let answer = (variant.solve)(&parsed)?;
+                if answer != expected {
+                    disagreements.push(Disagreement {
+                        part,
+                        default: Puzzle::name(self, part),
+                        expected: expected.clone(),
+                        variant: variant.name,
+                        answer,
+                    });
+                }
+            }
+        }
+        Ok(disagreements)
+    }
+}
+
+/// Solves the requested parts, printing each answer and the total time taken.
+pub fn report(
+    solver: &dyn Solver,
+    input: &str,
+    params: &Table,
+    parts: &[Part],
+) -> Result<(), PuzzleError> {
+    let start = Instant::now();
+    for (part, answer) in solver.solve(input, params, parts)? {
+        if answer.is_multiline() {
+            println!("Answer for part {part} is:\n{answer}");
+        } else {
+            println!("Answer for part {part} is: {answer}");
+        }
+    }
+    let duration = start.elapsed();
+    println!("Took {:?} to solve this puzzle", duration);
+    Ok(())
+}
+
+/// Entry point for the cargo example of each puzzle. The input is read from
+/// the path given as the first argument, `-` for stdin, or the puzzle folder,
+/// with the parameters the manifest gives for that file.
+pub fn run(solver: &dyn Solver) {
+    let source = std::env::args().nth(1);
+    let (year, day) = (solver.year(), solver.day());
+    let result = input::load_from(source.as_deref(), year, day)
+        .map_err(|e| e.to_string())
+        .and_then(|input| Ok((input, params::for_input(year, day, source.as_deref())?)))
+        .and_then(|(input, params)| {
+            report(solver, &input, &params, solver.parts()).map_err(|e| e.to_string())
+        });
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::parse_number;
+    use crate::params::{parse_assignment, NoParams};
+    use serde::Deserialize;
+
+    struct Lengths;
+
+    impl Puzzle for Lengths {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Params = NoParams;
+        type Parsed = Vec<usize>;
+
+        fn parse(&self, input: &str, _params: &NoParams) -> Result<Vec<usize>, PuzzleError> {
+            Ok(input.lines().map(|line| line.len()).collect())
+        }
+
+        fn part_1(&self, parsed: &Vec<usize>) -> Result<Answer, PuzzleError> {
+            Ok(parsed.iter().sum::<usize>().into())
+        }
+
+        fn part_2(&self, parsed: &Vec<usize>) -> Result<Answer, PuzzleError> {
+            Ok((*parsed.iter().max().unwrap()).into())
+        }
+    }
+
+    struct SinglePart;
+
+    impl Puzzle for SinglePart {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 25;
+        const HAS_PART_2: bool = false;
+        const PARSES_INPUT: bool = false;
+
+        type Params = NoParams;
+        type Parsed = String;
+
+        fn parse(&self, input: &str, _params: &NoParams) -> Result<String, PuzzleError> {
+            Ok(input.to_owned())
+        }
+
+        fn part_1(&self, parsed: &String) -> Result<Answer, PuzzleError> {
+            Ok(parsed.to_uppercase().into())
+        }
+    }
+
+    struct WithVariants;
+
+    impl Puzzle for WithVariants {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 2;
+
+        type Params = NoParams;
+        type Parsed = Vec<u32>;
+
+        fn parse(&self, input: &str, _params: &NoParams) -> Result<Vec<u32>, PuzzleError> {
+            input.split(',').map(parse_number).collect()
+        }
+
+        fn part_1(&self, parsed: &Vec<u32>) -> Result<Answer, PuzzleError> {
+            Ok(parsed.iter().sum::<u32>().into())
+        }
+
+        fn part_2(&self, parsed: &Vec<u32>) -> Result<Answer, PuzzleError> {
+            Ok((*parsed.iter().max().unwrap()).into())
+        }
+
+        fn name(&self, part: Part) -> &'static str {
+            match part {
+                Part::One => "sum_iter",
+                Part::Two => "part_2",
+            }
+        }
+
+        fn variants(&self, part: Part) -> Vec<Variant<Vec<u32>>> {
+            match part {
+                Part::One => vec![
+                    Variant::new("sum_reversed", |numbers| {
+                        Ok(numbers.iter().rev().sum::<u32>().into())
+                    }),
+                    Variant::unchecked("sum_first_two", |numbers| {
+                        Ok((numbers[0] + numbers[1]).into())
+                    }),
+                ],
+                Part::Two => vec![Variant::new("last", |numbers| {
+                    Ok((*numbers.last().unwrap()).into())
+                })],
+            }
+        }
+    }
+
+    #[derive(Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Params {
+        threshold: u32,
+    }
+
+    impl Default for Params {
+        fn default() -> Self {
+            Self { threshold: 2 }
+        }
+    }
+
+    struct Above;
+
+    impl Puzzle for Above {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 3;
+        const HAS_PART_2: bool = false;
+
+        type Params = Params;
+        type Parsed = (Vec<u32>, u32);
+
+        fn parse(&self, input: &str, params: &Params) -> Result<(Vec<u32>, u32), PuzzleError> {
+            let numbers = input
+                .split(',')
+                .map(parse_number)
+                .collect::<Result<_, _>>()?;
+            Ok((numbers, params.threshold))
+        }
+
+        fn part_1(&self, (numbers, threshold): &(Vec<u32>, u32)) -> Result<Answer, PuzzleError> {
+            match numbers.iter().filter(|x| *x > threshold).count() {
+                0 => Err(PuzzleError::unsupported("Nothing is above the threshold")),
+                count => Ok(count.into()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_part_try_from() {
+        assert_eq!(Part::try_from(1), Ok(Part::One));
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert!(Part::try_from(3).is_err());
+    }
+
+    #[test]
+    fn test_solve_both_parts() {
+        let solver: &dyn Solver = &Lengths;
+        assert_eq!((solver.year(), solver.day()), (2000, 1));
+        assert_eq!(
+            solver.solve("ab\ncde", &Table::new(), &Part::ALL).unwrap(),
+            vec![
+                (Part::One, Answer::from("5")),
+                (Part::Two, Answer::from("3"))
+            ]
+        );
+        assert_eq!(
+            solver
+                .solve("ab\ncde", &Table::new(), &[Part::Two])
+                .unwrap(),
+            vec![(Part::Two, Answer::from("3"))]
+        );
+    }
+
+    #[test]
+    fn test_single_part_puzzle_skips_part_2() {
+        let solver: &dyn Solver = &SinglePart;
+        assert_eq!(solver.parts(), &[Part::One]);
+        assert_eq!(
+            solver.solve("merry", &Table::new(), &Part::ALL).unwrap(),
+            vec![(Part::One, Answer::from("MERRY"))]
+        );
+        assert!(solver
+            .solve("christmas", &Table::new(), &[Part::Two])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_params() {
+        let solver: &dyn Solver = &Above;
+        assert_eq!(
+            solver.solve("1,2,3,4", &Table::new(), &Part::ALL).unwrap(),
+            vec![(Part::One, Answer::from("2"))]
+        );
+        let params = Table::from_iter([parse_assignment("threshold=0").unwrap()]);
+        assert_eq!(
+            solver.solve("1,2,3,4", &params, &Part::ALL).unwrap(),
+            vec![(Part::One, Answer::from("4"))]
+        );
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let params = Table::from_iter([parse_assignment("threshold=-1").unwrap()]);
+        assert!(Above.solve("1,2", &params, &Part::ALL).is_err());
+        let params = Table::from_iter([parse_assignment("limit=1").unwrap()]);
+        assert!(Above.solve("1,2", &params, &Part::ALL).is_err());
+        assert!(Lengths.solve("ab", &params, &Part::ALL).is_err());
+        assert!(Lengths.check_params(&params).is_err());
+        assert!(Lengths.check_params(&Table::new()).is_ok());
+    }
+
+    #[test]
+    fn test_errors_are_returned() {
+        let solver: &dyn Solver = &Above;
+        assert_eq!(
+            solver.solve("1,x", &Table::new(), &Part::ALL),
+            Err(PuzzleError::malformed("Expected a number, not \"x\""))
+        );
+        assert_eq!(
+            solver.solve("1,2", &Table::new(), &Part::ALL),
+            Err(PuzzleError::unsupported("Nothing is above the threshold"))
+        );
+        let settings = Settings { warmup: 0, runs: 1 };
+        assert!(solver
+            .bench("1,2", &Table::new(), &Part::ALL, &settings)
+            .is_err());
+        assert!(SinglePart.part_2(&String::new()).is_err());
+    }
+
+    #[test]
+    fn test_bench_times_each_phase() {
+        let settings = Settings { warmup: 0, runs: 3 };
+        let phases: Vec<String> = Lengths
+            .bench("ab\ncde", &Table::new(), &Part::ALL, &settings)
+            .unwrap()
+            .into_iter()
+            .map(|timing| timing.phase)
+            .collect();
+        assert_eq!(phases, vec!["parse", "part_1", "part_2"]);
+
+        let phases: Vec<String> = SinglePart
+            .bench("merry", &Table::new(), &Part::ALL, &settings)
+            .unwrap()
+            .into_iter()
+            .map(|timing| timing.phase)
+            .collect();
+        // the raw input is passed on, so there's no parsing to time
+        assert_eq!(phases, vec!["part_1"]);
+
+        let phases: Vec<String> = WithVariants
+            .bench("1,2,3", &Table::new(), &Part::ALL, &settings)
+            .unwrap()
+            .into_iter()
+            .map(|timing| timing.phase)
+            .collect();
+        assert_eq!(
+            phases,
+            vec![
+                "parse",
+                "sum_iter",
+                "sum_reversed",
+                "sum_first_two",
+                "part_2",
+                "last"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_variant_names() {
+        let solver: &dyn Solver = &WithVariants;
+        assert_eq!(
+            solver.variants(Part::One),
+            vec!["sum_reversed", "sum_first_two"]
+        );
+        assert_eq!(solver.variants(Part::Two), vec!["last"]);
+        assert!(Solver::variants(&Lengths, Part::One).is_empty());
+    }
+
+    #[test]
+    fn test_cross_check_agreement() {
+        // sum_first_two is wrong here but isn't cross-checked
+        assert!(WithVariants
+            .cross_check("1,2,3", &Table::new(), &Part::ALL)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_cross_check_disagreement() {
+        let disagreements = WithVariants
+            .cross_check("3,2,1", &Table::new(), &Part::ALL)
+            .unwrap();
+        assert_eq!(
+            disagreements,
+            vec![Disagreement {
+                part: Part::Two,
+                default: "part_2",
+                expected: Answer::Number(3),
+                variant: "last",
+                answer: Answer::Number(1),
+            }]
+        );
+        assert_eq!(
+            disagreements[0].to_string(),
+            "part 2: last gave 1 but part_2 gave 3"
+        );
+        assert!(WithVariants
+            .cross_check("3,2,1", &Table::new(), &[Part::One])
+            .unwrap()
+            .is_empty());
+    }
+}